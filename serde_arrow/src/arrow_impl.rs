@@ -18,7 +18,10 @@ use crate::{
         array_builder::ArrayBuilder,
         deserializer::Deserializer,
         error::{fail, Error, Result},
-        schema::extensions::{Bool8Field, FixedShapeTensorField, VariableShapeTensorField},
+        schema::extensions::{
+            Bool8Field, ExtensionField, FixedShapeTensorField, JsonField, LineStringField,
+            PointField, PolygonField, UuidField, VariableShapeTensorField,
+        },
         schema::{SchemaLike, Sealed, SerdeArrowSchema, TracingOptions},
         serializer::Serializer,
     },
@@ -417,5 +420,11 @@ macro_rules! impl_try_from_ext_type {
 }
 
 impl_try_from_ext_type!(Bool8Field);
+impl_try_from_ext_type!(ExtensionField);
 impl_try_from_ext_type!(FixedShapeTensorField);
+impl_try_from_ext_type!(JsonField);
+impl_try_from_ext_type!(LineStringField);
+impl_try_from_ext_type!(PointField);
+impl_try_from_ext_type!(PolygonField);
+impl_try_from_ext_type!(UuidField);
 impl_try_from_ext_type!(VariableShapeTensorField);
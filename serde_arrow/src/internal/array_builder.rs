@@ -3,7 +3,9 @@ use serde::Serialize;
 use marrow::array::Array;
 
 use crate::internal::{
-    error::Result, schema::SerdeArrowSchema, serialization::OuterSequenceBuilder,
+    error::Result,
+    schema::{extensions::ExtensionRegistry, SerdeArrowSchema},
+    serialization::OuterSequenceBuilder,
 };
 
 /// Construct arrays by pushing individual records
@@ -11,6 +13,7 @@ use crate::internal::{
 /// It can be constructed via
 ///
 /// - [`ArrayBuilder::new`]
+/// - [`ArrayBuilder::new_with_extensions`]
 #[cfg_attr(has_arrow, doc = r"- [`ArrayBuilder::from_arrow`]")]
 #[cfg_attr(has_arrow2, doc = r"- [`ArrayBuilder::from_arrow2`]")]
 ///
@@ -59,8 +62,21 @@ pub struct ArrayBuilder {
 impl ArrayBuilder {
     /// Construct an array builder from an [`SerdeArrowSchema`]
     pub fn new(schema: SerdeArrowSchema) -> Result<Self> {
+        Self::new_with_extensions(schema, &ExtensionRegistry::default())
+    }
+
+    /// Construct an array builder that applies the given user defined extension types
+    ///
+    /// Fields with a registered extension name are validated by the extension type and their
+    /// values are passed through its hooks (see
+    /// [`ExtensionType`][crate::schema::ext::ExtensionType]).
+    pub fn new_with_extensions(
+        schema: SerdeArrowSchema,
+        extensions: &ExtensionRegistry,
+    ) -> Result<Self> {
+        extensions.validate_fields(&schema.fields)?;
         Ok(Self {
-            builder: OuterSequenceBuilder::new(&schema)?,
+            builder: OuterSequenceBuilder::new(&schema, extensions)?,
             schema,
         })
     }
@@ -107,6 +123,6 @@ impl std::convert::AsMut<ArrayBuilder> for ArrayBuilder {
 }
 
 const _: () = {
-    trait AssertSendSync: Send + Sync {}
-    impl AssertSendSync for ArrayBuilder {}
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ArrayBuilder>();
 };
@@ -1,4 +1,5 @@
 //! Support for Parsing datetime related quantities
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
 use marrow::datatypes::TimeUnit;
 
 use crate::internal::error::Result;
@@ -19,6 +20,14 @@ pub fn matches_utc_datetime(s: &str) -> bool {
     parsing::match_utc_datetime(s).matches()
 }
 
+/// Get the offset of a datetime with a fixed offset, e.g., `2024-08-09T12:15:00+05:30`
+pub fn match_offset_datetime(s: &str) -> Option<FixedOffset> {
+    let Ok(("", offset)) = parsing::match_offset_datetime(s) else {
+        return None;
+    };
+    offset.parse().ok()
+}
+
 /// Check whether `s` can be parsed as a naive date
 pub fn matches_naive_date(s: &str) -> bool {
     parsing::match_naive_date(s).matches()
@@ -46,6 +55,41 @@ impl parsing::Span<'_> {
         Self::build_duration(self.sign, second_value, nanosecond_value, unit)
     }
 
+    /// Convert the `Span` into the calendar components of an interval
+    ///
+    /// The result contains the number of months, days and nanoseconds. Years are converted to
+    /// months, weeks to days.
+    pub fn to_arrow_interval(&self) -> Result<(i64, i64, i64)> {
+        let Some(months) = checked_weighted_sum(&[
+            (get_optional_digit_value(self.year)?, 12),
+            (get_optional_digit_value(self.month)?, 1),
+        ]) else {
+            fail!("Cannot represent the months of the span as a 64 bit integer");
+        };
+        let Some(days) = checked_weighted_sum(&[
+            (get_optional_digit_value(self.week)?, 7),
+            (get_optional_digit_value(self.day)?, 1),
+        ]) else {
+            fail!("Cannot represent the days of the span as a 64 bit integer");
+        };
+        let Some(second_value) = checked_weighted_sum(&[
+            (get_optional_digit_value(self.hour)?, 60 * 60),
+            (get_optional_digit_value(self.minute)?, 60),
+            (get_optional_digit_value(self.second)?, 1),
+        ]) else {
+            fail!("Cannot represent the seconds of the span as a 64 bit integer");
+        };
+        let nanosecond_value = self.get_nanosecond_value()?;
+        let nanoseconds =
+            Self::build_duration(None, second_value, nanosecond_value, TimeUnit::Nanosecond)?;
+
+        if self.sign == Some('-') {
+            Ok((-months, -days, -nanoseconds))
+        } else {
+            Ok((months, days, nanoseconds))
+        }
+    }
+
     fn get_second_value(&self) -> Result<i64> {
         Ok(get_optional_digit_value(self.week)? * 7 * 24 * 60 * 60
             + get_optional_digit_value(self.day)? * 24 * 60 * 60
@@ -126,6 +170,144 @@ pub fn format_arrow_duration_as_span(value: i64, unit: TimeUnit) -> String {
     }
 }
 
+/// Format the components of an interval as a Span string
+///
+/// The time component `time` is given in `unit`. Intervals with components of different signs
+/// cannot be represented as a span and result in an error.
+pub fn format_arrow_interval_as_span(
+    months: i64,
+    days: i64,
+    time: i64,
+    unit: TimeUnit,
+) -> Result<String> {
+    let is_negative = months < 0 || days < 0 || time < 0;
+    let is_positive = months > 0 || days > 0 || time > 0;
+    if is_negative && is_positive {
+        fail!("Cannot format an interval with components of different signs as a span");
+    }
+
+    let mut result = String::from(if is_negative { "-P" } else { "P" });
+    if months != 0 {
+        result.push_str(&format!("{months}m", months = months.abs()));
+    }
+    if days != 0 {
+        result.push_str(&format!("{days}d", days = days.abs()));
+    }
+    if time != 0 || !is_negative && !is_positive {
+        // strip the leading `P` of the duration
+        result.push_str(&format_arrow_duration_as_span(time.abs(), unit)[1..]);
+    }
+    Ok(result)
+}
+
+/// The metadata key selecting whether deserialized strings carry the time zone in brackets
+///
+/// By default, only named time zones are annotated. With `"true"`, fixed offsets are annotated as
+/// well, e.g., as required by `jiff::Zoned`. With `"false"`, no annotation is added, e.g., as
+/// required by `chrono`.
+pub const TIME_ZONE_ANNOTATION_KEY: &str = "SERDE_ARROW:time_zone_annotation";
+
+/// The time zone of a timestamp array
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeZone {
+    /// Timestamps without time zone, represented as naive date times
+    Naive,
+    /// UTC timestamps, represented with a `Z` suffix
+    Utc,
+    /// Timestamps with a fixed offset, e.g., `+05:30`
+    Fixed(FixedOffset),
+    /// Timestamps with a named time zone of the IANA database, e.g., `Europe/Berlin`
+    #[cfg(feature = "chrono-tz")]
+    Named(chrono_tz::Tz),
+}
+
+impl TimeZone {
+    pub fn new(tz: Option<&str>) -> Result<Self> {
+        let Some(tz) = tz else {
+            return Ok(Self::Naive);
+        };
+        if tz.to_uppercase() == "UTC" {
+            return Ok(Self::Utc);
+        }
+        if tz.starts_with(['+', '-']) {
+            let Ok(offset) = tz.parse::<FixedOffset>() else {
+                fail!("Invalid fixed offset time zone {tz}");
+            };
+            return Ok(Self::Fixed(offset));
+        }
+        Self::new_named(tz)
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    fn new_named(tz: &str) -> Result<Self> {
+        match tz.parse::<chrono_tz::Tz>() {
+            Ok(tz) => Ok(Self::Named(tz)),
+            Err(_) => fail!("Timezone {tz} is not supported"),
+        }
+    }
+
+    #[cfg(not(feature = "chrono-tz"))]
+    fn new_named(tz: &str) -> Result<Self> {
+        fail!("Timezone {tz} is not supported: named time zones require the chrono-tz feature")
+    }
+
+    /// Parse a date time string into a UTC date time
+    ///
+    /// For timestamps with time zone, the string must contain an offset. A trailing time zone
+    /// annotation in square brackets, e.g., `[Europe/Berlin]`, is ignored.
+    pub fn parse(&self, s: &str) -> Result<DateTime<Utc>> {
+        if let Self::Naive = self {
+            return Ok(s.parse::<NaiveDateTime>()?.and_utc());
+        }
+
+        let s = match s.strip_suffix(']').and_then(|s| s.rsplit_once('[')) {
+            Some((s, _annotation)) => s,
+            None => s,
+        };
+        Ok(s.parse::<DateTime<FixedOffset>>()?.to_utc())
+    }
+
+    /// Whether deserialized strings carry the time zone in brackets, if not configured otherwise
+    pub fn is_annotated_by_default(&self) -> bool {
+        match self {
+            Self::Naive | Self::Utc | Self::Fixed(_) => false,
+            #[cfg(feature = "chrono-tz")]
+            Self::Named(_) => true,
+        }
+    }
+
+    /// Split a UTC date time into its local date time and the suffix describing the time zone
+    ///
+    /// Fixed offsets use the RFC 3339 suffix `{offset}`. If `annotate` is given, fixed offsets
+    /// and named time zones use the suffix `{offset}[{zone}]` of RFC 9557.
+    pub fn localize(&self, date_time: DateTime<Utc>, annotate: bool) -> (NaiveDateTime, String) {
+        match self {
+            Self::Naive => (date_time.naive_utc(), String::new()),
+            Self::Utc => (date_time.naive_utc(), String::from("Z")),
+            Self::Fixed(offset) => {
+                let date_time = date_time.with_timezone(offset);
+                if annotate {
+                    (date_time.naive_local(), format!("{offset}[{offset}]"))
+                } else {
+                    (date_time.naive_local(), offset.to_string())
+                }
+            }
+            #[cfg(feature = "chrono-tz")]
+            Self::Named(tz) => {
+                use chrono::Offset;
+
+                let date_time = date_time.with_timezone(tz);
+                let offset = date_time.offset().fix();
+                if annotate {
+                    (date_time.naive_local(), format!("{offset}[{tz}]"))
+                } else {
+                    (date_time.naive_local(), offset.to_string())
+                }
+            }
+        }
+    }
+}
+
 fn get_optional_digit_value(s: Option<&str>) -> Result<i64> {
     match s {
         Some(s) => Ok(s.parse()?),
@@ -133,6 +315,13 @@ fn get_optional_digit_value(s: Option<&str>) -> Result<i64> {
     }
 }
 
+/// Compute the sum of `value * factor`, or `None` on overflow
+fn checked_weighted_sum(parts: &[(i64, i64)]) -> Option<i64> {
+    parts.iter().try_fold(0_i64, |sum, &(value, factor)| {
+        sum.checked_add(value.checked_mul(factor)?)
+    })
+}
+
 /// Minimalistic monadic parsers for datetime objects
 ///
 /// Each parser has the the following interface:
@@ -224,6 +413,11 @@ mod parsing {
         ))
     }
 
+    pub fn match_offset_datetime(s: &str) -> Result<(&str, &str), &str> {
+        let (s, _) = match_naive_datetime_with_sep(s, &['T', ' '])?;
+        match_fixed_offset(s)
+    }
+
     pub fn match_naive_datetime(s: &str) -> Result<(&str, DateTime<'_>), &str> {
         match_naive_datetime_with_sep(s, &['T'])
     }
@@ -349,6 +543,20 @@ mod parsing {
         Err(s)
     }
 
+    /// Match a fixed offset of the form `+hh:mm` or `+hhmm`
+    pub fn match_fixed_offset(s: &str) -> Result<(&str, &str), &str> {
+        let rest = s.strip_prefix(['+', '-']).ok_or(s)?;
+        let rest = match_two_digits(rest)?;
+        let rest = rest.strip_prefix(':').unwrap_or(rest);
+        let rest = match_two_digits(rest)?;
+        Ok((rest, get_prefix(s, rest)))
+    }
+
+    fn match_two_digits(s: &str) -> Result<&str, &str> {
+        let rest = s.strip_prefix(DIGIT).ok_or(s)?;
+        rest.strip_prefix(DIGIT).ok_or(s)
+    }
+
     fn get_prefix<'a>(s: &'a str, rest: &str) -> &'a str {
         debug_assert!(s.ends_with(rest), "Invalid call to get prefix");
         let len_prefix = s.len() - rest.len();
@@ -551,6 +759,28 @@ fn test_match_utc_datetime() {
     );
 }
 
+#[test]
+fn test_match_offset_datetime() {
+    let offset = |s: &str| match_offset_datetime(s).map(|offset| offset.to_string());
+
+    assert_eq!(
+        offset("2012-12-12T12:12:12+05:30"),
+        Some(String::from("+05:30"))
+    );
+    assert_eq!(
+        offset("2012-12-12 12:12:12.5-0800"),
+        Some(String::from("-08:00"))
+    );
+    assert_eq!(
+        offset("2012-12-12T12:12:12+00:00"),
+        Some(String::from("+00:00"))
+    );
+    assert_eq!(offset("2012-12-12T12:12:12Z"), None);
+    assert_eq!(offset("2012-12-12T12:12:12"), None);
+    assert_eq!(offset("2012-12-12T12:12:12+5:30"), None);
+    assert_eq!(offset("2012-12-12T12:12:12+05:30:00"), None);
+}
+
 #[test]
 fn test_match_naive_date() {
     assert_eq!(
@@ -1002,3 +1232,33 @@ fn test_parse_and_format_duration() {
         123456789
     );
 }
+
+#[test]
+fn test_parse_and_format_interval() {
+    fn parse_as_interval(s: &str) -> (i64, i64, i64) {
+        parse_span(s).unwrap().to_arrow_interval().unwrap()
+    }
+
+    assert_eq!(parse_as_interval("P1y2m"), (14, 0, 0));
+    assert_eq!(parse_as_interval("P1w3d"), (0, 10, 0));
+    assert_eq!(parse_as_interval("PT1h2m3.5s"), (0, 0, 3_723_500_000_000));
+    assert_eq!(parse_as_interval("-P2m1dT1s"), (-2, -1, -1_000_000_000));
+
+    assert_eq!(
+        format_arrow_interval_as_span(0, 0, 0, TimeUnit::Nanosecond).unwrap(),
+        "PT0.000000000s"
+    );
+    assert_eq!(
+        format_arrow_interval_as_span(14, 0, 0, TimeUnit::Nanosecond).unwrap(),
+        "P14m"
+    );
+    assert_eq!(
+        format_arrow_interval_as_span(-2, -1, -1_500, TimeUnit::Millisecond).unwrap(),
+        "-P2m1dT1.500s"
+    );
+    assert_eq!(
+        format_arrow_interval_as_span(0, 3, 20, TimeUnit::Nanosecond).unwrap(),
+        "P3dT0.000000020s"
+    );
+    assert!(format_arrow_interval_as_span(1, -1, 0, TimeUnit::Nanosecond).is_err());
+}
@@ -1,13 +1,26 @@
+use std::collections::HashMap;
+
 use half::f16;
-use marrow::view::{BytesView, BytesViewView, View};
+use marrow::{
+    types::{DayTimeInterval, MonthDayNanoInterval},
+    view::{BytesView, BytesViewView, View},
+};
 use serde::{
     de::{Deserialize, DeserializeSeed, VariantAccess, Visitor},
     Deserializer,
 };
 
 use crate::internal::{
+    chrono::TIME_ZONE_ANNOTATION_KEY,
     error::{fail, Context, Error, Result},
-    schema::Strategy,
+    schema::{
+        extensions::{
+            has_polygon_extension, has_uuid_extension, ExtensionRegistry, TensorMetadata,
+        },
+        get_strategy_from_metadata,
+        tracer::IntegerRange,
+        Strategy, StringGuess, DATETIME_PATTERN_KEY,
+    },
 };
 
 use super::{
@@ -18,20 +31,29 @@ use super::{
     dictionary_deserializer::DictionaryDeserializer,
     duration_deserializer::DurationDeserializer,
     enum_deserializer::EnumDeserializer,
+    extension_deserializer::ExtensionDeserializer,
+    fixed_shape_tensor_deserializer::FixedShapeTensorDeserializer,
     fixed_size_binary_deserializer::FixedSizeBinaryDeserializer,
     fixed_size_list_deserializer::FixedSizeListDeserializer,
     float_deserializer::FloatDeserializer,
+    generic_dictionary_deserializer::GenericDictionaryDeserializer,
     integer_deserializer::IntegerDeserializer,
+    interval_deserializer::IntervalDeserializer,
     list_deserializer::ListDeserializer,
     map_deserializer::MapDeserializer,
     null_deserializer::NullDeserializer,
     random_access_deserializer::{PositionedDeserializer, RandomAccessDeserializer},
+    run_end_encoded_deserializer::RunEndEncodedDeserializer,
     string_deserializer::StringDeserializer,
     struct_deserializer::StructDeserializer,
     time_deserializer::TimeDeserializer,
     timestamp_deserializer::TimestampDeserializer,
+    variable_shape_tensor_deserializer::VariableShapeTensorDeserializer,
 };
 
+#[cfg(feature = "serde_json")]
+use super::json_deserializer::{JsonDeserializer, JsonView};
+
 pub enum ArrayDeserializer<'a> {
     Null(NullDeserializer),
     Bool(BoolDeserializer<'a>),
@@ -48,6 +70,9 @@ pub enum ArrayDeserializer<'a> {
     F64(FloatDeserializer<'a, f64>),
     Decimal128(DecimalDeserializer<'a>),
     Duration(DurationDeserializer<'a>),
+    YearMonthInterval(IntervalDeserializer<'a, i32>),
+    DayTimeInterval(IntervalDeserializer<'a, DayTimeInterval>),
+    MonthDayNanoInterval(IntervalDeserializer<'a, MonthDayNanoInterval>),
     Date32(DateDeserializer<'a, i32>),
     Date64(DateDeserializer<'a, i64>),
     Time32(TimeDeserializer<'a, i32>),
@@ -72,21 +97,137 @@ pub enum ArrayDeserializer<'a> {
     DictionaryI16I64(DictionaryDeserializer<'a, i16, i64>),
     DictionaryI32I64(DictionaryDeserializer<'a, i32, i64>),
     DictionaryI64I64(DictionaryDeserializer<'a, i64, i64>),
+    DictionaryU8(GenericDictionaryDeserializer<'a, u8>),
+    DictionaryU16(GenericDictionaryDeserializer<'a, u16>),
+    DictionaryU32(GenericDictionaryDeserializer<'a, u32>),
+    DictionaryU64(GenericDictionaryDeserializer<'a, u64>),
+    DictionaryI8(GenericDictionaryDeserializer<'a, i8>),
+    DictionaryI16(GenericDictionaryDeserializer<'a, i16>),
+    DictionaryI32(GenericDictionaryDeserializer<'a, i32>),
+    DictionaryI64(GenericDictionaryDeserializer<'a, i64>),
     Struct(StructDeserializer<'a>),
     List(ListDeserializer<'a, i32>),
     LargeList(ListDeserializer<'a, i64>),
     FixedSizeList(FixedSizeListDeserializer<'a>),
+    FixedShapeTensor(FixedShapeTensorDeserializer<'a>),
+    VariableShapeTensor(VariableShapeTensorDeserializer<'a>),
     Binary(BinaryDeserializer<BytesView<'a, i32>>),
     LargeBinary(BinaryDeserializer<BytesView<'a, i64>>),
     BinaryView(BinaryDeserializer<BytesViewView<'a>>),
     FixedSizeBinary(FixedSizeBinaryDeserializer<'a>),
     Map(MapDeserializer<'a>),
     Enum(EnumDeserializer<'a>),
+    RunEndEncoded(RunEndEncodedDeserializer<'a>),
+    #[cfg(feature = "serde_json")]
+    Json(JsonDeserializer<'a>),
+    Extension(ExtensionDeserializer<'a>),
 }
 
 impl<'a> ArrayDeserializer<'a> {
-    // TODO: decide whether to keep strategy parameter
-    pub fn new(path: String, _strategy: Option<&Strategy>, array: View<'a>) -> Result<Self> {
+    pub fn new(
+        path: String,
+        metadata: &HashMap<String, String>,
+        array: View<'a>,
+        extensions: &ExtensionRegistry,
+    ) -> Result<Self> {
+        if let Some(Strategy::Json) = get_strategy_from_metadata(metadata)? {
+            return Self::new_json(path, array);
+        }
+        let is_narrowed = IntegerRange::from_metadata(metadata)?.is_some();
+        let is_string_encoded = StringGuess::is_string_encoded(metadata);
+        let storage = match Self::new_storage(path.clone(), array, extensions)? {
+            Self::I8(storage) => Self::I8(
+                storage
+                    .with_floats(is_narrowed)
+                    .with_strings(is_string_encoded),
+            ),
+            Self::I16(storage) => Self::I16(
+                storage
+                    .with_floats(is_narrowed)
+                    .with_strings(is_string_encoded),
+            ),
+            Self::I32(storage) => Self::I32(
+                storage
+                    .with_floats(is_narrowed)
+                    .with_strings(is_string_encoded),
+            ),
+            Self::I64(storage) => Self::I64(
+                storage
+                    .with_floats(is_narrowed)
+                    .with_strings(is_string_encoded),
+            ),
+            Self::U8(storage) => Self::U8(
+                storage
+                    .with_floats(is_narrowed)
+                    .with_strings(is_string_encoded),
+            ),
+            Self::U16(storage) => Self::U16(
+                storage
+                    .with_floats(is_narrowed)
+                    .with_strings(is_string_encoded),
+            ),
+            Self::U32(storage) => Self::U32(
+                storage
+                    .with_floats(is_narrowed)
+                    .with_strings(is_string_encoded),
+            ),
+            Self::U64(storage) => Self::U64(
+                storage
+                    .with_floats(is_narrowed)
+                    .with_strings(is_string_encoded),
+            ),
+            Self::FixedSizeList(list) => {
+                match TensorMetadata::from_field_metadata(metadata, "arrow.fixed_shape_tensor")? {
+                    Some(tensor) => {
+                        Self::FixedShapeTensor(FixedShapeTensorDeserializer::new(list, tensor)?)
+                    }
+                    None => Self::FixedSizeList(list),
+                }
+            }
+            Self::Bool(storage) => Self::Bool(storage.with_strings(is_string_encoded)),
+            Self::List(storage) if has_polygon_extension(metadata) => {
+                Self::List(storage.with_polygon())
+            }
+            Self::LargeList(storage) if has_polygon_extension(metadata) => {
+                Self::LargeList(storage.with_polygon())
+            }
+            Self::FixedSizeBinary(storage) if has_uuid_extension(metadata) => {
+                Self::FixedSizeBinary(storage.with_uuid())
+            }
+            Self::Timestamp(storage) => {
+                let storage = match metadata.get(TIME_ZONE_ANNOTATION_KEY).map(String::as_str) {
+                    Some("true") => storage.with_annotation(true),
+                    Some("false") => storage.with_annotation(false),
+                    Some(value) => fail!(
+                        "Invalid value {value:?} of {TIME_ZONE_ANNOTATION_KEY}, expected \"true\" or \"false\""
+                    ),
+                    None => storage,
+                };
+                match metadata.get(DATETIME_PATTERN_KEY) {
+                    Some(pattern) => Self::Timestamp(storage.with_pattern(pattern.clone())),
+                    None => Self::Timestamp(storage),
+                }
+            }
+            Self::Struct(storage) => {
+                match TensorMetadata::from_field_metadata(metadata, "arrow.variable_shape_tensor")?
+                {
+                    Some(tensor) => Self::VariableShapeTensor(
+                        VariableShapeTensorDeserializer::new(storage, tensor)?,
+                    ),
+                    None => Self::Struct(storage),
+                }
+            }
+            storage => storage,
+        };
+        match extensions.get_for_metadata(metadata) {
+            Some(extension) if extension.has_value_hooks() => Ok(Self::Extension(
+                ExtensionDeserializer::new(path, extension.clone(), storage),
+            )),
+            _ => Ok(storage),
+        }
+    }
+
+    fn new_storage(path: String, array: View<'a>, extensions: &ExtensionRegistry) -> Result<Self> {
         use {ArrayDeserializer as D, View as V};
         match array {
             View::Null(_) => Ok(Self::Null(NullDeserializer::new(path))),
@@ -109,6 +250,15 @@ impl<'a> ArrayDeserializer<'a> {
             V::Time64(view) => Ok(D::Time64(TimeDeserializer::new(path, view))),
             V::Timestamp(view) => Ok(Self::Timestamp(TimestampDeserializer::new(path, view)?)),
             V::Duration(view) => Ok(D::Duration(DurationDeserializer::new(path, view))),
+            V::YearMonthInterval(view) => {
+                Ok(D::YearMonthInterval(IntervalDeserializer::new(path, view)))
+            }
+            V::DayTimeInterval(view) => {
+                Ok(D::DayTimeInterval(IntervalDeserializer::new(path, view)))
+            }
+            V::MonthDayNanoInterval(view) => Ok(D::MonthDayNanoInterval(
+                IntervalDeserializer::new(path, view),
+            )),
             V::Utf8(view) => Ok(D::Utf8(StringDeserializer::new(path, view))),
             V::LargeUtf8(view) => Ok(D::LargeUtf8(StringDeserializer::new(path, view))),
             V::Utf8View(view) => Ok(D::Utf8View(StringDeserializer::new(path, view))),
@@ -118,14 +268,17 @@ impl<'a> ArrayDeserializer<'a> {
             V::FixedSizeBinary(view) => Ok(D::FixedSizeBinary(FixedSizeBinaryDeserializer::new(
                 path, view,
             )?)),
-            V::List(view) => Ok(D::List(ListDeserializer::new(path, view)?)),
-            V::LargeList(view) => Ok(D::LargeList(ListDeserializer::new(path, view)?)),
+            V::List(view) => Ok(D::List(ListDeserializer::new(path, view, extensions)?)),
+            V::LargeList(view) => Ok(D::LargeList(ListDeserializer::new(path, view, extensions)?)),
             V::FixedSizeList(view) => Ok(D::FixedSizeList(FixedSizeListDeserializer::new(
-                path, view,
+                path, view, extensions,
+            )?)),
+            V::Struct(view) => Ok(D::Struct(StructDeserializer::new(path, view, extensions)?)),
+            V::Map(view) => Ok(D::Map(MapDeserializer::new(path, view, extensions)?)),
+            View::Union(view) => Ok(Self::Enum(EnumDeserializer::new(path, view, extensions)?)),
+            V::RunEndEncoded(view) => Ok(D::RunEndEncoded(RunEndEncodedDeserializer::new(
+                path, view, extensions,
             )?)),
-            V::Struct(view) => Ok(D::Struct(StructDeserializer::new(path, view)?)),
-            V::Map(view) => Ok(D::Map(MapDeserializer::new(path, view)?)),
-            View::Union(view) => Ok(Self::Enum(EnumDeserializer::new(path, view)?)),
             V::Dictionary(view) => match (*view.keys, *view.values) {
                 (V::Int8(keys), V::Utf8(values)) => Ok(D::DictionaryI8I32(
                     DictionaryDeserializer::new(path, keys, values)?,
@@ -175,6 +328,30 @@ impl<'a> ArrayDeserializer<'a> {
                 (V::UInt64(keys), V::LargeUtf8(values)) => Ok(D::DictionaryU64I64(
                     DictionaryDeserializer::new(path, keys, values)?,
                 )),
+                (V::Int8(keys), values) => Ok(D::DictionaryI8(GenericDictionaryDeserializer::new(
+                    path, keys, values, extensions,
+                )?)),
+                (V::Int16(keys), values) => Ok(D::DictionaryI16(
+                    GenericDictionaryDeserializer::new(path, keys, values, extensions)?,
+                )),
+                (V::Int32(keys), values) => Ok(D::DictionaryI32(
+                    GenericDictionaryDeserializer::new(path, keys, values, extensions)?,
+                )),
+                (V::Int64(keys), values) => Ok(D::DictionaryI64(
+                    GenericDictionaryDeserializer::new(path, keys, values, extensions)?,
+                )),
+                (V::UInt8(keys), values) => Ok(D::DictionaryU8(
+                    GenericDictionaryDeserializer::new(path, keys, values, extensions)?,
+                )),
+                (V::UInt16(keys), values) => Ok(D::DictionaryU16(
+                    GenericDictionaryDeserializer::new(path, keys, values, extensions)?,
+                )),
+                (V::UInt32(keys), values) => Ok(D::DictionaryU32(
+                    GenericDictionaryDeserializer::new(path, keys, values, extensions)?,
+                )),
+                (V::UInt64(keys), values) => Ok(D::DictionaryU64(
+                    GenericDictionaryDeserializer::new(path, keys, values, extensions)?,
+                )),
                 _ => fail!("Unsupported dictionary array type"),
             },
             _ => fail!("Unknown view"),
@@ -182,6 +359,26 @@ impl<'a> ArrayDeserializer<'a> {
     }
 }
 
+impl<'a> ArrayDeserializer<'a> {
+    #[cfg(feature = "serde_json")]
+    fn new_json(path: String, array: View<'a>) -> Result<Self> {
+        let view = match array {
+            View::Utf8(view) => JsonView::Utf8(view),
+            View::LargeUtf8(view) => JsonView::LargeUtf8(view),
+            View::Utf8View(view) => JsonView::Utf8View(view),
+            _ => {
+                fail!("The Json strategy is only supported for Utf8, LargeUtf8 and Utf8View arrays")
+            }
+        };
+        Ok(Self::Json(JsonDeserializer::new(path, view)))
+    }
+
+    #[cfg(not(feature = "serde_json"))]
+    fn new_json(_path: String, _array: View<'a>) -> Result<Self> {
+        fail!("The Json strategy requires the serde_json feature")
+    }
+}
+
 macro_rules! dispatch {
     ($obj:expr, $wrapper:ident($name:ident) => $expr:expr) => {
         match $obj {
@@ -200,6 +397,9 @@ macro_rules! dispatch {
             $wrapper::F64($name) => $expr,
             $wrapper::Decimal128($name) => $expr,
             $wrapper::Duration($name) => $expr,
+            $wrapper::YearMonthInterval($name) => $expr,
+            $wrapper::DayTimeInterval($name) => $expr,
+            $wrapper::MonthDayNanoInterval($name) => $expr,
             $wrapper::Date32($name) => $expr,
             $wrapper::Date64($name) => $expr,
             $wrapper::Time32($name) => $expr,
@@ -211,6 +411,8 @@ macro_rules! dispatch {
             $wrapper::Struct($name) => $expr,
             $wrapper::List($name) => $expr,
             $wrapper::FixedSizeList($name) => $expr,
+            $wrapper::FixedShapeTensor($name) => $expr,
+            $wrapper::VariableShapeTensor($name) => $expr,
             $wrapper::LargeList($name) => $expr,
             $wrapper::Binary($name) => $expr,
             $wrapper::LargeBinary($name) => $expr,
@@ -218,6 +420,10 @@ macro_rules! dispatch {
             $wrapper::FixedSizeBinary($name) => $expr,
             $wrapper::Map($name) => $expr,
             $wrapper::Enum($name) => $expr,
+            $wrapper::RunEndEncoded($name) => $expr,
+            #[cfg(feature = "serde_json")]
+            $wrapper::Json($name) => $expr,
+            $wrapper::Extension($name) => $expr,
             $wrapper::DictionaryU8I32($name) => $expr,
             $wrapper::DictionaryU16I32($name) => $expr,
             $wrapper::DictionaryU32I32($name) => $expr,
@@ -234,6 +440,14 @@ macro_rules! dispatch {
             $wrapper::DictionaryI16I64($name) => $expr,
             $wrapper::DictionaryI32I64($name) => $expr,
             $wrapper::DictionaryI64I64($name) => $expr,
+            $wrapper::DictionaryU8($name) => $expr,
+            $wrapper::DictionaryU16($name) => $expr,
+            $wrapper::DictionaryU32($name) => $expr,
+            $wrapper::DictionaryU64($name) => $expr,
+            $wrapper::DictionaryI8($name) => $expr,
+            $wrapper::DictionaryI16($name) => $expr,
+            $wrapper::DictionaryI32($name) => $expr,
+            $wrapper::DictionaryI64($name) => $expr,
         }
     };
 }
@@ -300,6 +514,14 @@ impl<'de> RandomAccessDeserializer<'de> for ArrayDeserializer<'de> {
         dispatch!(self, Self(this) => this.deserialize_i64(visitor, idx))
     }
 
+    fn deserialize_i128<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        dispatch!(self, Self(this) => this.deserialize_i128(visitor, idx))
+    }
+
+    fn deserialize_u128<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        dispatch!(self, Self(this) => this.deserialize_u128(visitor, idx))
+    }
+
     fn deserialize_u8<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
         dispatch!(self, Self(this) => this.deserialize_u8(visitor, idx))
     }
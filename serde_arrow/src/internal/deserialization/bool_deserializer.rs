@@ -8,11 +8,22 @@ use super::{random_access_deserializer::RandomAccessDeserializer, utils::bitset_
 pub struct BoolDeserializer<'a> {
     pub path: String,
     pub view: BooleanView<'a>,
+    pub strings: bool,
 }
 
 impl<'a> BoolDeserializer<'a> {
     pub fn new(path: String, view: BooleanView<'a>) -> Self {
-        Self { path, view }
+        Self {
+            path,
+            view,
+            strings: false,
+        }
+    }
+
+    /// Allow to deserialize the values as strings, e.g., for fields traced with guessed types
+    pub fn with_strings(mut self, value: bool) -> Self {
+        self.strings = value;
+        self
     }
 
     fn get(&self, idx: usize) -> Result<Option<bool>> {
@@ -57,6 +68,20 @@ impl<'de> RandomAccessDeserializer<'de> for BoolDeserializer<'de> {
         try_(|| visitor.visit_bool::<Error>(self.get_required(idx)?)).ctx(self)
     }
 
+    fn deserialize_str<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        self.deserialize_string(visitor, idx)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| {
+            if !self.strings {
+                fail!("Only boolean fields with the SERDE_ARROW:string_encoded metadata can be deserialized as strings");
+            }
+            visitor.visit_string::<Error>(self.get_required(idx)?.to_string())
+        })
+        .ctx(self)
+    }
+
     fn deserialize_u8<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
         try_(|| visitor.visit_u8::<Error>(if self.get_required(idx)? { 1 } else { 0 })).ctx(self)
     }
@@ -4,7 +4,7 @@ use serde::de::Visitor;
 
 use crate::internal::{
     error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
-    utils::array_view_ext::ViewAccess,
+    utils::{array_view_ext::ViewAccess, time_tuple},
 };
 
 use super::random_access_deserializer::RandomAccessDeserializer;
@@ -39,7 +39,7 @@ impl<'a, I: DatePrimitive> DateDeserializer<'a, I> {
         Self { path, view }
     }
 
-    pub fn get_string_repr(&self, ts: I) -> Result<String> {
+    pub fn get_date(&self, ts: I) -> Result<NaiveDate> {
         let ts = (ts / I::DAY_TO_VALUE_FACTOR)
             .try_into()
             .map_err(|_| Error::custom(format!("Cannot convert {ts} to i64")))?;
@@ -47,7 +47,11 @@ impl<'a, I: DatePrimitive> DateDeserializer<'a, I> {
         const UNIX_EPOCH: NaiveDate = NaiveDateTime::UNIX_EPOCH.date();
         #[allow(deprecated)]
         let delta = Duration::days(ts);
-        let date = UNIX_EPOCH + delta;
+        Ok(UNIX_EPOCH + delta)
+    }
+
+    pub fn get_string_repr(&self, ts: I) -> Result<String> {
+        let date = self.get_date(ts)?;
 
         // special handling of negative dates:
         //
@@ -110,6 +114,20 @@ impl<'de, I: DatePrimitive> RandomAccessDeserializer<'de> for DateDeserializer<'
         .ctx(self)
     }
 
+    fn deserialize_tuple<V: Visitor<'de>>(
+        &self,
+        _: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        try_(|| {
+            let ts = self.view.get_required(idx)?;
+            let date = self.get_date(*ts)?;
+            time_tuple::visit_tuple(visitor, time_tuple::date_to_tuple(date))
+        })
+        .ctx(self)
+    }
+
     fn deserialize_str<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
         try_(|| self.deserialize_string(visitor, idx)).ctx(self)
     }
@@ -2,7 +2,7 @@ use marrow::view::{DecimalView, PrimitiveView};
 use serde::de::Visitor;
 
 use crate::internal::{
-    error::{set_default, Context, ContextSupport, Result},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
     utils::{array_view_ext::ViewAccess, decimal},
 };
 
@@ -33,6 +33,26 @@ impl<'a> DecimalDeserializer<'a> {
 
         func(formatted)
     }
+
+    fn get_integer(&self, idx: usize) -> Result<i128> {
+        let val = *self.view.get_required(idx)?;
+        let Some(factor) = 10_i128.checked_pow(self.scale.unsigned_abs() as u32) else {
+            fail!(
+                "Cannot convert decimal with scale {scale} to an integer",
+                scale = self.scale
+            );
+        };
+        if self.scale < 0 {
+            let Some(res) = val.checked_mul(factor) else {
+                fail!("Cannot convert decimal to an integer: value overflows i128");
+            };
+            Ok(res)
+        } else if val % factor == 0 {
+            Ok(val / factor)
+        } else {
+            fail!("Cannot convert decimal with a fractional part to an integer");
+        }
+    }
 }
 
 impl Context for DecimalDeserializer<'_> {
@@ -51,6 +71,21 @@ impl<'de> RandomAccessDeserializer<'de> for DecimalDeserializer<'de> {
         self.deserialize_str(visitor, idx)
     }
 
+    fn deserialize_i128<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| visitor.visit_i128(self.get_integer(idx)?)).ctx(self)
+    }
+
+    fn deserialize_u128<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| {
+            let val = self.get_integer(idx)?;
+            let Ok(val) = u128::try_from(val) else {
+                fail!("Cannot deserialize negative value {val} as u128");
+            };
+            visitor.visit_u128(val)
+        })
+        .ctx(self)
+    }
+
     fn deserialize_str<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
         self.with_value(idx, |value| visitor.visit_str(value))
             .ctx(self)
@@ -7,7 +7,11 @@ use serde::de::Visitor;
 use crate::internal::{
     chrono,
     error::{set_default, try_, Context, ContextSupport, Result},
-    utils::array_view_ext::ViewAccess,
+    utils::{
+        array_view_ext::ViewAccess,
+        std_time::{StdTimeAccess, StdTimeParts, StdTimeType},
+        time_tuple,
+    },
 };
 
 use super::random_access_deserializer::RandomAccessDeserializer;
@@ -56,6 +60,34 @@ impl<'de> RandomAccessDeserializer<'de> for DurationDeserializer<'de> {
         try_(|| visitor.visit_i64(*self.values.get_required(idx)?)).ctx(self)
     }
 
+    fn deserialize_struct<V: Visitor<'de>>(
+        &self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        try_(|| {
+            let value = self.values.get_required(idx)?;
+            let parts = StdTimeParts::from_arrow(*value, self.unit)?;
+            visitor.visit_map(StdTimeAccess::new(StdTimeType::Duration, parts))
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        &self,
+        _: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        try_(|| {
+            let value = self.values.get_required(idx)?;
+            time_tuple::visit_tuple(visitor, time_tuple::duration_to_tuple(*value, self.unit))
+        })
+        .ctx(self)
+    }
+
     fn deserialize_str<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
         try_(|| visitor.visit_str(self.get_string_value(idx)?.as_str())).ctx(self)
     }
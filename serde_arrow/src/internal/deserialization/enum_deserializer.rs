@@ -3,7 +3,7 @@ use serde::de::{DeserializeSeed, Deserializer, EnumAccess, Visitor};
 
 use crate::internal::{
     error::{fail, set_default, Context, Error, Result},
-    schema::get_strategy_from_metadata,
+    schema::{extensions::ExtensionRegistry, get_strategy_from_metadata, Strategy},
     utils::{ChildName, Offset},
 };
 
@@ -15,44 +15,77 @@ use super::{
 pub struct EnumDeserializer<'a> {
     pub path: String,
     pub types: &'a [i8],
-    pub offsets: &'a [i32],
+    /// The offsets of dense unions, sparse unions use the index into the array
+    pub offsets: Option<&'a [i32]>,
+    /// A mapping from the type id to the index of the variant
+    pub variant_indices: Vec<Option<usize>>,
+    /// The index of the variant that stores missing values, if the union is nullable
+    pub null_variant: Option<usize>,
     pub variants: Vec<(String, ArrayDeserializer<'a>)>,
 }
 
 impl<'a> EnumDeserializer<'a> {
-    pub fn new(path: String, view: UnionView<'a>) -> Result<Self> {
-        let Some(offsets) = view.offsets else {
-            fail!("Only dense unions are supported");
-        };
-
-        if view.types.len() != offsets.len() {
-            fail!("Offsets and type ids must have the same length")
+    pub fn new(path: String, view: UnionView<'a>, extensions: &ExtensionRegistry) -> Result<Self> {
+        if let Some(offsets) = view.offsets {
+            if view.types.len() != offsets.len() {
+                fail!("Offsets and type ids must have the same length")
+            }
         }
 
+        let mut variant_indices = Vec::new();
+        let mut null_variant = None;
         let mut variants = Vec::new();
         for (idx, (type_id, field_meta, field_view)) in view.fields.into_iter().enumerate() {
-            // TODO: introduce translation table?
-            if usize::try_from(type_id) != Ok(idx) {
-                fail!("Only unions with consecutive type ids are currently supported");
+            let Ok(type_id) = usize::try_from(type_id) else {
+                fail!("Invalid type id {type_id}: type ids must be non-negative");
+            };
+            if variant_indices.len() <= type_id {
+                variant_indices.resize(type_id + 1, None);
+            }
+            if variant_indices[type_id].is_some() {
+                fail!("Duplicate type id {type_id}");
             }
+            variant_indices[type_id] = Some(idx);
+
+            if let Some(Strategy::NullVariant) = get_strategy_from_metadata(&field_meta.metadata)? {
+                null_variant = Some(idx);
+            }
+
             let child_path = format!("{path}.{child}", child = ChildName(&field_meta.name));
-            let field_deserializer = ArrayDeserializer::new(
-                child_path,
-                get_strategy_from_metadata(&field_meta.metadata)?.as_ref(),
-                field_view,
-            )?;
+            let field_deserializer =
+                ArrayDeserializer::new(child_path, &field_meta.metadata, field_view, extensions)?;
             variants.push((field_meta.name, field_deserializer))
         }
 
         Ok(Self {
             path,
             types: view.types,
-            offsets,
+            offsets: view.offsets,
+            variant_indices,
+            null_variant,
             variants,
         })
     }
 }
 
+impl EnumDeserializer<'_> {
+    /// Determine the index of the variant at `idx`, `None` for missing values
+    fn variant_index(&self, idx: usize) -> Result<Option<usize>> {
+        let type_id = self.types[idx];
+        let Some(variant_index) = usize::try_from(type_id)
+            .ok()
+            .and_then(|type_id| self.variant_indices.get(type_id).copied().flatten())
+        else {
+            fail!("Unknown type id {type_id}");
+        };
+        if Some(variant_index) == self.null_variant {
+            Ok(None)
+        } else {
+            Ok(Some(variant_index))
+        }
+    }
+}
+
 impl Context for EnumDeserializer<'_> {
     fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
         set_default(annotations, "field", &self.path);
@@ -65,7 +98,7 @@ impl<'de> RandomAccessDeserializer<'de> for EnumDeserializer<'de> {
         if idx >= self.types.len() {
             fail!("Access beyond bounds");
         }
-        Ok(true)
+        Ok(self.variant_index(idx)?.is_some())
     }
 
     fn deserialize_any_some<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
@@ -82,13 +115,18 @@ impl<'de> RandomAccessDeserializer<'de> for EnumDeserializer<'de> {
         if idx >= self.types.len() {
             fail!("Exhausted deserializer");
         }
-        let type_id = self.types[idx];
-        let offset = self.offsets[idx].try_into_usize()?;
-        let (name, variant) = &self.variants[type_id as usize];
+        let Some(variant_index) = self.variant_index(idx)? else {
+            fail!("Unexpected null value for non-nullable enum");
+        };
+        let offset = match self.offsets {
+            Some(offsets) => offsets[idx].try_into_usize()?,
+            None => idx,
+        };
+        let (name, variant) = &self.variants[variant_index];
 
         visitor.visit_enum(VariantItemDeserializer {
             deserializer: variant.at(offset),
-            type_id,
+            variant_index,
             name,
         })
     }
@@ -96,7 +134,7 @@ impl<'de> RandomAccessDeserializer<'de> for EnumDeserializer<'de> {
 
 struct VariantItemDeserializer<'this, 'a> {
     deserializer: PositionedDeserializer<'this, ArrayDeserializer<'a>>,
-    type_id: i8,
+    variant_index: usize,
     name: &'this str,
 }
 
@@ -106,7 +144,7 @@ impl<'a, 'de> EnumAccess<'de> for VariantItemDeserializer<'a, 'de> {
 
     fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self::Variant)> {
         let val = seed.deserialize(VariantIdDeserializer {
-            type_id: self.type_id,
+            variant_index: self.variant_index,
             name: self.name,
         })?;
         Ok((val, self.deserializer))
@@ -114,7 +152,7 @@ impl<'a, 'de> EnumAccess<'de> for VariantItemDeserializer<'a, 'de> {
 }
 
 struct VariantIdDeserializer<'a> {
-    type_id: i8,
+    variant_index: usize,
     name: &'a str,
 }
 
@@ -150,7 +188,7 @@ impl<'de> Deserializer<'de> for VariantIdDeserializer<'_> {
     }
 
     fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        visitor.visit_u64(u64::try_from(self.type_id)?)
+        visitor.visit_u64(u64::try_from(self.variant_index)?)
     }
 
     unimplemented!('de, deserialize_bool);
@@ -0,0 +1,173 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use serde::{
+    de::{Deserialize, Visitor},
+    Deserializer,
+};
+
+use crate::internal::{
+    error::{set_default, try_, Context, ContextSupport, Result},
+    schema::extensions::{ExtensionType, ExtensionValue},
+    utils::value::{Value, ValueDeserializer},
+};
+
+use super::{
+    array_deserializer::ArrayDeserializer, random_access_deserializer::RandomAccessDeserializer,
+};
+
+/// A deserializer that transforms values with the hooks of a user defined extension type
+///
+/// Each value is read from the storage array into a [`Value`], passed through
+/// [`ExtensionType::deserialize_value`] as an [`ExtensionValue`] and then replayed to the visitor.
+pub struct ExtensionDeserializer<'a> {
+    path: String,
+    extension: Arc<dyn ExtensionType>,
+    storage: Box<ArrayDeserializer<'a>>,
+}
+
+impl<'a> ExtensionDeserializer<'a> {
+    pub fn new(
+        path: String,
+        extension: Arc<dyn ExtensionType>,
+        storage: ArrayDeserializer<'a>,
+    ) -> Self {
+        Self {
+            path,
+            extension,
+            storage: Box::new(storage),
+        }
+    }
+
+    fn with_value<R>(
+        &self,
+        idx: usize,
+        func: impl FnOnce(ValueDeserializer<'_>) -> Result<R>,
+    ) -> Result<R> {
+        try_(|| {
+            let value = if self.storage.is_some(idx)? {
+                let value = Value::deserialize(self.storage.at(idx))?;
+                let ExtensionValue(value) =
+                    self.extension.deserialize_value(ExtensionValue(value))?;
+                value
+            } else {
+                Value::None
+            };
+            func(ValueDeserializer::new(&value))
+        })
+        .ctx(self)
+    }
+}
+
+impl Context for ExtensionDeserializer<'_> {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "extension", self.extension.name());
+    }
+}
+
+macro_rules! forward_to_value {
+    ($($method:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+                self.with_value(idx, |de| de.$method(visitor))
+            }
+        )*
+    };
+}
+
+impl<'de> RandomAccessDeserializer<'de> for ExtensionDeserializer<'de> {
+    fn is_some(&self, idx: usize) -> Result<bool> {
+        self.storage.is_some(idx)
+    }
+
+    fn deserialize_any_some<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        self.with_value(idx, |de| de.deserialize_any(visitor))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        self.with_value(idx, |de| de.deserialize_option(visitor))
+    }
+
+    forward_to_value!(
+        deserialize_ignored_any,
+        deserialize_bool,
+        deserialize_i8,
+        deserialize_i16,
+        deserialize_i32,
+        deserialize_i64,
+        deserialize_i128,
+        deserialize_u8,
+        deserialize_u16,
+        deserialize_u32,
+        deserialize_u64,
+        deserialize_u128,
+        deserialize_f32,
+        deserialize_f64,
+        deserialize_char,
+        deserialize_str,
+        deserialize_string,
+        deserialize_bytes,
+        deserialize_byte_buf,
+        deserialize_unit,
+        deserialize_seq,
+        deserialize_map,
+        deserialize_identifier,
+    );
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.with_value(idx, |de| de.deserialize_struct(name, fields, visitor))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.with_value(idx, |de| de.deserialize_enum(name, variants, visitor))
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.with_value(idx, |de| de.deserialize_newtype_struct(name, visitor))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        &self,
+        len: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.with_value(idx, |de| de.deserialize_tuple(len, visitor))
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.with_value(idx, |de| de.deserialize_tuple_struct(name, len, visitor))
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.with_value(idx, |de| de.deserialize_unit_struct(name, visitor))
+    }
+}
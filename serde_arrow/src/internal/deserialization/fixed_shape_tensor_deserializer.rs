@@ -0,0 +1,215 @@
+use serde::de::{DeserializeSeed, SeqAccess, Visitor};
+
+use crate::internal::{
+    error::{fail, set_default, Context, Error, Result},
+    schema::extensions::TensorMetadata,
+};
+
+use super::{
+    array_deserializer::ArrayDeserializer, fixed_size_list_deserializer::FixedSizeListDeserializer,
+    random_access_deserializer::RandomAccessDeserializer,
+};
+
+/// Deserialize `arrow.fixed_shape_tensor` arrays into nested sequences
+///
+/// The nesting follows the logical shape of the tensor, i.e., the physical shape reordered by the
+/// permutation. Elements are read from the flat storage of the `FixedSizeList` array.
+pub struct FixedShapeTensorDeserializer<'a> {
+    list: FixedSizeListDeserializer<'a>,
+    /// The number of entries of each logical dimension
+    logical_shape: Vec<usize>,
+    /// The distance in storage between consecutive entries of each logical dimension
+    strides: Vec<usize>,
+}
+
+impl<'a> FixedShapeTensorDeserializer<'a> {
+    pub fn new(list: FixedSizeListDeserializer<'a>, metadata: TensorMetadata) -> Result<Self> {
+        let Some(shape) = metadata.shape else {
+            fail!("Fixed shape tensors require a shape");
+        };
+        if shape.iter().product::<usize>() != list.n {
+            fail!(
+                "The shape {shape:?} of the fixed shape tensor does not match the list size {n}",
+                n = list.n,
+            );
+        }
+        let permutation = match metadata.permutation {
+            Some(permutation) if permutation.len() == shape.len() => Some(permutation),
+            Some(_) => {
+                fail!("The permutation of the fixed shape tensor must have one entry per dimension")
+            }
+            None => None,
+        };
+        let (logical_shape, strides) = logical_layout(&shape, permutation.as_deref());
+
+        Ok(Self {
+            list,
+            logical_shape,
+            strides,
+        })
+    }
+}
+
+/// Compute the logical shape and the storage strides of each logical dimension
+///
+/// The logical dimension `i` corresponds to the physical dimension `permutation[i]`.
+pub fn logical_layout(shape: &[usize], permutation: Option<&[usize]>) -> (Vec<usize>, Vec<usize>) {
+    let mut physical_strides = vec![1; shape.len()];
+    for dim in (0..shape.len().saturating_sub(1)).rev() {
+        physical_strides[dim] = physical_strides[dim + 1] * shape[dim + 1];
+    }
+    match permutation {
+        Some(permutation) => (
+            permutation.iter().map(|&dim| shape[dim]).collect(),
+            permutation
+                .iter()
+                .map(|&dim| physical_strides[dim])
+                .collect(),
+        ),
+        None => (shape.to_vec(), physical_strides),
+    }
+}
+
+impl Context for FixedShapeTensorDeserializer<'_> {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.list.path);
+        set_default(annotations, "extension", "arrow.fixed_shape_tensor");
+        set_default(annotations, "data_type", "FixedSizeList(..)");
+    }
+}
+
+impl<'de> RandomAccessDeserializer<'de> for FixedShapeTensorDeserializer<'de> {
+    fn is_some(&self, idx: usize) -> Result<bool> {
+        self.list.is_some(idx)
+    }
+
+    fn deserialize_any_some<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        self.deserialize_seq(visitor, idx)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        if idx >= self.list.len {
+            fail!("Out of bounds access");
+        }
+        if self.logical_shape.len() <= 1 {
+            return self.list.deserialize_seq(visitor, idx);
+        }
+        let tensor = NestedTensor {
+            item: &self.list.item,
+            logical_shape: &self.logical_shape,
+            strides: &self.strides,
+        };
+        tensor.deserialize_seq(visitor, idx * self.list.n)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        &self,
+        _: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor, idx)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        &self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor, idx)
+    }
+}
+
+/// A single tensor stored in a flat array, exposed as nested sequences
+#[derive(Clone, Copy)]
+pub struct NestedTensor<'this, 'de> {
+    pub item: &'this ArrayDeserializer<'de>,
+    pub logical_shape: &'this [usize],
+    pub strides: &'this [usize],
+}
+
+impl<'de> NestedTensor<'_, 'de> {
+    /// Visit the tensor whose first element is stored at `offset`
+    pub fn deserialize_seq<V: Visitor<'de>>(self, visitor: V, offset: usize) -> Result<V::Value> {
+        visitor.visit_seq(TensorSeqAccess {
+            tensor: self,
+            dim: 0,
+            offset,
+            pos: 0,
+        })
+    }
+}
+
+/// The entries of a single dimension of a tensor
+struct TensorSeqAccess<'this, 'de> {
+    tensor: NestedTensor<'this, 'de>,
+    dim: usize,
+    offset: usize,
+    pos: usize,
+}
+
+impl<'de> SeqAccess<'de> for TensorSeqAccess<'_, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.pos >= self.tensor.logical_shape[self.dim] {
+            return Ok(None);
+        }
+        let offset = self.offset + self.pos * self.tensor.strides[self.dim];
+        self.pos += 1;
+
+        if self.dim + 1 == self.tensor.logical_shape.len() {
+            Ok(Some(seed.deserialize(self.tensor.item.at(offset))?))
+        } else {
+            Ok(Some(seed.deserialize(TensorDimDeserializer {
+                tensor: self.tensor,
+                dim: self.dim + 1,
+                offset,
+            })?))
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.tensor.logical_shape[self.dim] - self.pos)
+    }
+}
+
+/// A deserializer for the nested sequence of an inner dimension
+struct TensorDimDeserializer<'this, 'de> {
+    tensor: NestedTensor<'this, 'de>,
+    dim: usize,
+    offset: usize,
+}
+
+impl<'de> serde::Deserializer<'de> for TensorDimDeserializer<'_, 'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(TensorSeqAccess {
+            tensor: self.tensor,
+            dim: self.dim,
+            offset: self.offset,
+            pos: 0,
+        })
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
@@ -1,7 +1,10 @@
 use marrow::view::FixedSizeBinaryView;
 use serde::de::Visitor;
 
-use crate::internal::error::{fail, set_default, try_, Context, ContextSupport, Result};
+use crate::internal::{
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    utils::uuid,
+};
 
 use super::{
     random_access_deserializer::RandomAccessDeserializer,
@@ -13,6 +16,8 @@ pub struct FixedSizeBinaryDeserializer<'a> {
     pub view: FixedSizeBinaryView<'a>,
     pub len: usize,
     pub n: usize,
+    /// Whether the field has the `arrow.uuid` extension and is deserialized from UUID strings
+    pub is_uuid: bool,
 }
 
 impl<'a> FixedSizeBinaryDeserializer<'a> {
@@ -34,9 +39,16 @@ impl<'a> FixedSizeBinaryDeserializer<'a> {
             len: view.data.len() / n,
             view,
             n,
+            is_uuid: false,
         })
     }
 
+    /// Format the values as UUID strings when deserializing strings
+    pub fn with_uuid(mut self) -> Self {
+        self.is_uuid = true;
+        self
+    }
+
     pub fn get(&self, idx: usize) -> Result<Option<&'a [u8]>> {
         if idx >= self.len {
             fail!("Out of bounds access")
@@ -57,6 +69,33 @@ impl<'a> FixedSizeBinaryDeserializer<'a> {
         };
         Ok(s)
     }
+
+    fn format_uuid<'b>(
+        &self,
+        buffer: &'b mut [u8; uuid::BUFFER_SIZE],
+        idx: usize,
+    ) -> Result<&'b str> {
+        if !self.is_uuid {
+            fail!("Only FixedSizeBinary(16) arrays with the arrow.uuid extension can be deserialized as strings");
+        }
+        let Ok(bytes) = <&[u8; 16]>::try_from(self.get_required(idx)?) else {
+            fail!(
+                "Only FixedSizeBinary(16) arrays can be deserialized as strings, found FixedSizeBinary({n})",
+                n = self.n,
+            );
+        };
+        Ok(uuid::format_uuid(buffer, bytes))
+    }
+
+    fn get_16_bytes(&self, idx: usize) -> Result<[u8; 16]> {
+        let Ok(bytes) = <[u8; 16]>::try_from(self.get_required(idx)?) else {
+            fail!(
+                "Cannot deserialize 128-bit integers from FixedSizeBinary({n})",
+                n = self.n,
+            );
+        };
+        Ok(bytes)
+    }
 }
 
 impl Context for FixedSizeBinaryDeserializer<'_> {
@@ -83,6 +122,30 @@ impl<'de> RandomAccessDeserializer<'de> for FixedSizeBinaryDeserializer<'de> {
         try_(|| visitor.visit_borrowed_bytes(self.get_required(idx)?)).ctx(self)
     }
 
+    fn deserialize_str<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| {
+            let mut buffer = [0; uuid::BUFFER_SIZE];
+            visitor.visit_str(self.format_uuid(&mut buffer, idx)?)
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| {
+            let mut buffer = [0; uuid::BUFFER_SIZE];
+            visitor.visit_string(self.format_uuid(&mut buffer, idx)?.to_owned())
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_i128<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| visitor.visit_i128(i128::from_le_bytes(self.get_16_bytes(idx)?))).ctx(self)
+    }
+
+    fn deserialize_u128<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| visitor.visit_u128(u128::from_le_bytes(self.get_16_bytes(idx)?))).ctx(self)
+    }
+
     fn deserialize_seq<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
         try_(|| visitor.visit_seq(U8SliceDeserializer::new(self.get_required(idx)?))).ctx(self)
     }
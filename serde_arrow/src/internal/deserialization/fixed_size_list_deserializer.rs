@@ -3,7 +3,7 @@ use serde::de::Visitor;
 
 use crate::internal::{
     error::{fail, set_default, Context, Result},
-    schema::get_strategy_from_metadata,
+    schema::extensions::{interleaved_dimensions, ExtensionRegistry},
     utils::ChildName,
 };
 
@@ -18,23 +18,29 @@ pub struct FixedSizeListDeserializer<'a> {
     pub validity: Option<BitsWithOffset<'a>>,
     pub len: usize,
     pub n: usize,
+    /// The field names of structs stored as interleaved GeoArrow coordinates
+    pub dimensions: Option<&'static [&'static str]>,
 }
 
 impl<'a> FixedSizeListDeserializer<'a> {
-    pub fn new(path: String, view: FixedSizeListView<'a>) -> Result<Self> {
+    pub fn new(
+        path: String,
+        view: FixedSizeListView<'a>,
+        extensions: &ExtensionRegistry,
+    ) -> Result<Self> {
         let child_path = format!("{path}.{child}", child = ChildName(&view.meta.name));
-        let item = ArrayDeserializer::new(
-            child_path,
-            get_strategy_from_metadata(&view.meta.metadata)?.as_ref(),
-            *view.elements,
-        )?;
+        let item =
+            ArrayDeserializer::new(child_path, &view.meta.metadata, *view.elements, extensions)?;
+        let n = usize::try_from(view.n)?;
+        let dimensions = interleaved_dimensions(&view.meta.name).filter(|dims| dims.len() == n);
 
         Ok(Self {
             path,
             item: Box::new(item),
             validity: view.validity,
             len: view.len,
-            n: view.n.try_into()?,
+            n,
+            dimensions,
         })
     }
 }
@@ -71,4 +77,40 @@ impl<'de> RandomAccessDeserializer<'de> for FixedSizeListDeserializer<'de> {
             end: (idx + 1) * self.n,
         })
     }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        &self,
+        _: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor, idx)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        &self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor, idx)
+    }
+
+    // interleaved GeoArrow coordinates are read from the list of their field values
+    fn deserialize_struct<V: Visitor<'de>>(
+        &self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        let Some(dimensions) = self.dimensions else {
+            fail!("Only interleaved GeoArrow coordinates (elements named xy, xyz, xym or xyzm) can be deserialized as structs");
+        };
+        if fields != dimensions {
+            fail!("Expected the coordinates {dimensions:?}, got the fields {fields:?}");
+        }
+        self.deserialize_seq(visitor, idx)
+    }
 }
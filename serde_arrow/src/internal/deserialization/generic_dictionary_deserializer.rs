@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+
+use marrow::view::{PrimitiveView, View};
+use serde::de::Visitor;
+
+use crate::internal::{
+    error::{set_default, try_, Context, ContextSupport, Result},
+    schema::extensions::ExtensionRegistry,
+    utils::array_view_ext::ViewAccess,
+};
+
+use super::{
+    array_deserializer::ArrayDeserializer, integer_deserializer::Integer,
+    random_access_deserializer::RandomAccessDeserializer,
+};
+
+/// Deserialize dictionary arrays with arbitrary value types
+///
+/// Each access is forwarded to the deserializer of the values at the position given by the key.
+/// Dictionaries with string values use the specialized
+/// [`DictionaryDeserializer`][super::dictionary_deserializer::DictionaryDeserializer].
+pub struct GenericDictionaryDeserializer<'a, K: Integer> {
+    path: String,
+    keys: PrimitiveView<'a, K>,
+    values: Box<ArrayDeserializer<'a>>,
+}
+
+impl<'a, K: Integer> GenericDictionaryDeserializer<'a, K> {
+    pub fn new(
+        path: String,
+        keys: PrimitiveView<'a, K>,
+        values: View<'a>,
+        extensions: &ExtensionRegistry,
+    ) -> Result<Self> {
+        let values =
+            ArrayDeserializer::new(format!("{path}.value"), &HashMap::new(), values, extensions)?;
+        Ok(Self {
+            path,
+            keys,
+            values: Box::new(values),
+        })
+    }
+
+    fn get_key(&self, idx: usize) -> Result<usize> {
+        Ok(self.keys.get_required(idx)?.into_i64()?.try_into()?)
+    }
+}
+
+impl<K: Integer> Context for GenericDictionaryDeserializer<'_, K> {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", "Dictionary(..)");
+    }
+}
+
+impl<'de, K: Integer> RandomAccessDeserializer<'de> for GenericDictionaryDeserializer<'de, K> {
+    fn is_some(&self, idx: usize) -> Result<bool> {
+        try_(|| {
+            if !self.keys.is_some(idx)? {
+                return Ok(false);
+            }
+            self.values.is_some(self.get_key(idx)?)
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_any_some<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_any_some(visitor, idx)
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_any(visitor, idx)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_option(visitor, idx)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_ignored_any(visitor, idx)
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_bool(visitor, idx)
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_i8(visitor, idx)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_i16(visitor, idx)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_i32(visitor, idx)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_i64(visitor, idx)
+    }
+
+    fn deserialize_i128<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_i128(visitor, idx)
+    }
+
+    fn deserialize_u128<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_u128(visitor, idx)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_u8(visitor, idx)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_u16(visitor, idx)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_u32(visitor, idx)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_u64(visitor, idx)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_f32(visitor, idx)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_f64(visitor, idx)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_char(visitor, idx)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_str(visitor, idx)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_string(visitor, idx)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_map(visitor, idx)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_struct(name, fields, visitor, idx)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_byte_buf(visitor, idx)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_bytes(visitor, idx)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_enum(name, variants, visitor, idx)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_identifier(visitor, idx)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_newtype_struct(name, visitor, idx)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        &self,
+        len: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_tuple(len, visitor, idx)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_seq(visitor, idx)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values
+            .deserialize_tuple_struct(name, len, visitor, idx)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_unit(visitor, idx)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        let idx = self.get_key(idx).ctx(self)?;
+        self.values.deserialize_unit_struct(name, visitor, idx)
+    }
+}
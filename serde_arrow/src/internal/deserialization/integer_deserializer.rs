@@ -2,13 +2,13 @@ use marrow::view::PrimitiveView;
 use serde::de::Visitor;
 
 use crate::internal::{
-    error::{set_default, try_, Context, ContextSupport, Result},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
     utils::{array_view_ext::ViewAccess, NamedType},
 };
 
 use super::random_access_deserializer::RandomAccessDeserializer;
 
-pub trait Integer: Sized + Copy {
+pub trait Integer: Sized + Copy + std::fmt::Display {
     fn deserialize_any_at<'de, S: RandomAccessDeserializer<'de>, V: Visitor<'de>>(
         deser: &S,
         visitor: V,
@@ -26,16 +26,45 @@ pub trait Integer: Sized + Copy {
     fn into_u16(self) -> Result<u16>;
     fn into_u32(self) -> Result<u32>;
     fn into_u64(self) -> Result<u64>;
+
+    fn into_f32(self) -> Result<f32>;
+    fn into_f64(self) -> Result<f64>;
 }
 
 pub struct IntegerDeserializer<'a, T: Integer> {
     path: String,
     view: PrimitiveView<'a, T>,
+    floats: bool,
+    strings: bool,
 }
 
 impl<'a, T: Integer> IntegerDeserializer<'a, T> {
     pub fn new(path: String, view: PrimitiveView<'a, T>) -> Self {
-        Self { path, view }
+        Self {
+            path,
+            view,
+            floats: false,
+            strings: false,
+        }
+    }
+
+    /// Allow to deserialize the values as floats, e.g., for narrowed integer fields
+    pub fn with_floats(mut self, value: bool) -> Self {
+        self.floats = value;
+        self
+    }
+
+    /// Allow to deserialize the values as strings, e.g., for fields traced with guessed types
+    pub fn with_strings(mut self, value: bool) -> Self {
+        self.strings = value;
+        self
+    }
+
+    fn check_floats(&self) -> Result<()> {
+        if !self.floats {
+            fail!("Only integer fields with the SERDE_ARROW:min and SERDE_ARROW:max metadata can be deserialized as floats");
+        }
+        Ok(())
     }
 }
 
@@ -108,4 +137,34 @@ impl<'de, T: NamedType + Integer> RandomAccessDeserializer<'de> for IntegerDeser
     fn deserialize_i64<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
         try_(|| visitor.visit_i64(self.view.get_required(idx)?.into_i64()?)).ctx(self)
     }
+
+    fn deserialize_f32<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| {
+            self.check_floats()?;
+            visitor.visit_f32(self.view.get_required(idx)?.into_f32()?)
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| {
+            self.check_floats()?;
+            visitor.visit_f64(self.view.get_required(idx)?.into_f64()?)
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        self.deserialize_string(visitor, idx)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| {
+            if !self.strings {
+                fail!("Only integer fields with the SERDE_ARROW:string_encoded metadata can be deserialized as strings");
+            }
+            visitor.visit_string(self.view.get_required(idx)?.to_string())
+        })
+        .ctx(self)
+    }
 }
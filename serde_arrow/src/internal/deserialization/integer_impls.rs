@@ -1,6 +1,6 @@
 use serde::de::Visitor;
 
-use crate::internal::error::Result;
+use crate::internal::error::{fail, Result};
 
 use super::integer_deserializer::Integer;
 
@@ -38,6 +38,22 @@ macro_rules! implement_integer_into {
             Ok(self.try_into()?)
         }
 
+        fn into_f32(self) -> Result<f32> {
+            let value = self as f32;
+            if value as i128 != self as i128 {
+                fail!("Cannot convert {self} to f32 without loss of precision");
+            }
+            Ok(value)
+        }
+
+        fn into_f64(self) -> Result<f64> {
+            let value = self as f64;
+            if value as i128 != self as i128 {
+                fail!("Cannot convert {self} to f64 without loss of precision");
+            }
+            Ok(value)
+        }
+
         fn into_bool(self) -> Result<bool> {
             Ok(self != 0)
         }
@@ -0,0 +1,177 @@
+use marrow::{
+    datatypes::TimeUnit,
+    types::{DayTimeInterval, MonthDayNanoInterval},
+    view::PrimitiveView,
+};
+use serde::de::{value::StrDeserializer, DeserializeSeed, IntoDeserializer, MapAccess, Visitor};
+
+use crate::internal::{
+    chrono,
+    error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
+    utils::array_view_ext::ViewAccess,
+};
+
+use super::random_access_deserializer::RandomAccessDeserializer;
+
+pub trait IntervalValue: Copy + 'static {
+    const DATA_TYPE_NAME: &'static str;
+    /// The unit of the time component
+    const TIME_UNIT: TimeUnit;
+    /// The names of the components in the struct form
+    const COMPONENTS: &'static [&'static str];
+
+    /// The values of the components in the order of `COMPONENTS`
+    fn component_values(&self) -> [i64; 3];
+
+    /// The months, days and time of the interval
+    fn parts(&self) -> (i64, i64, i64);
+}
+
+impl IntervalValue for i32 {
+    const DATA_TYPE_NAME: &'static str = "Interval(YearMonth)";
+    const TIME_UNIT: TimeUnit = TimeUnit::Second;
+    const COMPONENTS: &'static [&'static str] = &["months"];
+
+    fn component_values(&self) -> [i64; 3] {
+        [i64::from(*self), 0, 0]
+    }
+
+    fn parts(&self) -> (i64, i64, i64) {
+        (i64::from(*self), 0, 0)
+    }
+}
+
+impl IntervalValue for DayTimeInterval {
+    const DATA_TYPE_NAME: &'static str = "Interval(DayTime)";
+    const TIME_UNIT: TimeUnit = TimeUnit::Millisecond;
+    const COMPONENTS: &'static [&'static str] = &["days", "milliseconds"];
+
+    fn component_values(&self) -> [i64; 3] {
+        [i64::from(self.days), i64::from(self.milliseconds), 0]
+    }
+
+    fn parts(&self) -> (i64, i64, i64) {
+        (0, i64::from(self.days), i64::from(self.milliseconds))
+    }
+}
+
+impl IntervalValue for MonthDayNanoInterval {
+    const DATA_TYPE_NAME: &'static str = "Interval(MonthDayNano)";
+    const TIME_UNIT: TimeUnit = TimeUnit::Nanosecond;
+    const COMPONENTS: &'static [&'static str] = &["months", "days", "nanoseconds"];
+
+    fn component_values(&self) -> [i64; 3] {
+        [
+            i64::from(self.months),
+            i64::from(self.days),
+            self.nanoseconds,
+        ]
+    }
+
+    fn parts(&self) -> (i64, i64, i64) {
+        (
+            i64::from(self.months),
+            i64::from(self.days),
+            self.nanoseconds,
+        )
+    }
+}
+
+pub struct IntervalDeserializer<'a, I: IntervalValue> {
+    path: String,
+    values: PrimitiveView<'a, I>,
+}
+
+impl<'a, I: IntervalValue> IntervalDeserializer<'a, I> {
+    pub fn new(path: String, values: PrimitiveView<'a, I>) -> Self {
+        Self { path, values }
+    }
+
+    pub fn get_string_value(&self, idx: usize) -> Result<String> {
+        let (months, days, time) = self.values.get_required(idx)?.parts();
+        chrono::format_arrow_interval_as_span(months, days, time, I::TIME_UNIT)
+    }
+
+    fn visit_components<'de, V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let values = self.values.get_required(idx)?.component_values();
+        visitor.visit_map(IntervalComponentsDeserializer {
+            components: I::COMPONENTS,
+            values,
+            next: 0,
+        })
+    }
+}
+
+impl<I: IntervalValue> Context for IntervalDeserializer<'_, I> {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", I::DATA_TYPE_NAME);
+    }
+}
+
+impl<'de, I: IntervalValue> RandomAccessDeserializer<'de> for IntervalDeserializer<'de, I> {
+    fn is_some(&self, idx: usize) -> Result<bool> {
+        self.values.is_some(idx)
+    }
+
+    fn deserialize_any_some<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| self.visit_components(visitor, idx)).ctx(self)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| self.visit_components(visitor, idx)).ctx(self)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        &self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        try_(|| self.visit_components(visitor, idx)).ctx(self)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| visitor.visit_str(self.get_string_value(idx)?.as_str())).ctx(self)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| visitor.visit_string(self.get_string_value(idx)?)).ctx(self)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| visitor.visit_bytes(self.get_string_value(idx)?.as_bytes())).ctx(self)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| visitor.visit_byte_buf(self.get_string_value(idx)?.into_bytes())).ctx(self)
+    }
+}
+
+struct IntervalComponentsDeserializer {
+    components: &'static [&'static str],
+    values: [i64; 3],
+    next: usize,
+}
+
+impl<'de> MapAccess<'de> for IntervalComponentsDeserializer {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let Some(component) = self.components.get(self.next) else {
+            return Ok(None);
+        };
+        let key = seed.deserialize(StrDeserializer::<Error>::new(component))?;
+        Ok(Some(key))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let Some(value) = self.values.get(self.next) else {
+            fail!("Invalid state in interval deserializer");
+        };
+        let res = seed.deserialize(IntoDeserializer::<Error>::into_deserializer(*value))?;
+        self.next += 1;
+        Ok(res)
+    }
+}
@@ -0,0 +1,182 @@
+use marrow::view::{BytesView, BytesViewView};
+use serde::{de::Visitor, Deserializer};
+
+use crate::internal::{
+    error::{set_default, try_, Context, ContextSupport, Result},
+    utils::array_view_ext::ViewAccess,
+};
+
+use super::random_access_deserializer::RandomAccessDeserializer;
+
+/// The string arrays that can store JSON text
+pub enum JsonView<'a> {
+    Utf8(BytesView<'a, i32>),
+    LargeUtf8(BytesView<'a, i64>),
+    Utf8View(BytesViewView<'a>),
+}
+
+impl<'a> ViewAccess<'a, [u8]> for JsonView<'a> {
+    fn get(&self, idx: usize) -> Result<Option<&'a [u8]>> {
+        match self {
+            Self::Utf8(view) => view.get(idx),
+            Self::LargeUtf8(view) => view.get(idx),
+            Self::Utf8View(view) => view.get(idx),
+        }
+    }
+}
+
+/// A deserializer that replays JSON text through serde
+pub struct JsonDeserializer<'a> {
+    path: String,
+    view: JsonView<'a>,
+}
+
+impl<'a> JsonDeserializer<'a> {
+    pub fn new(path: String, view: JsonView<'a>) -> Self {
+        Self { path, view }
+    }
+
+    fn with_json<R>(
+        &self,
+        idx: usize,
+        func: impl FnOnce(
+            &mut serde_json::Deserializer<serde_json::de::StrRead<'a>>,
+        ) -> Result<R, serde_json::Error>,
+    ) -> Result<R> {
+        try_(|| {
+            let text: &'a str = self.view.get_required(idx)?;
+            let mut deserializer = serde_json::Deserializer::from_str(text);
+            let res = func(&mut deserializer)?;
+            deserializer.end()?;
+            Ok(res)
+        })
+        .ctx(self)
+    }
+}
+
+impl Context for JsonDeserializer<'_> {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "strategy", "Json");
+        set_default(
+            annotations,
+            "data_type",
+            match &self.view {
+                JsonView::Utf8(_) => "Utf8",
+                JsonView::LargeUtf8(_) => "LargeUtf8",
+                JsonView::Utf8View(_) => "Utf8View",
+            },
+        );
+    }
+}
+
+macro_rules! forward_to_json {
+    ($($method:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+                self.with_json(idx, |de| de.$method(visitor))
+            }
+        )*
+    };
+}
+
+impl<'de> RandomAccessDeserializer<'de> for JsonDeserializer<'de> {
+    fn is_some(&self, idx: usize) -> Result<bool> {
+        ViewAccess::<str>::is_some(&self.view, idx)
+    }
+
+    fn deserialize_any_some<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        self.with_json(idx, |de| de.deserialize_any(visitor))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        if self.is_some(idx)? {
+            self.with_json(idx, |de| de.deserialize_option(visitor))
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    forward_to_json!(
+        deserialize_ignored_any,
+        deserialize_bool,
+        deserialize_i8,
+        deserialize_i16,
+        deserialize_i32,
+        deserialize_i64,
+        deserialize_i128,
+        deserialize_u8,
+        deserialize_u16,
+        deserialize_u32,
+        deserialize_u64,
+        deserialize_u128,
+        deserialize_f32,
+        deserialize_f64,
+        deserialize_char,
+        deserialize_str,
+        deserialize_string,
+        deserialize_bytes,
+        deserialize_byte_buf,
+        deserialize_unit,
+        deserialize_seq,
+        deserialize_map,
+        deserialize_identifier,
+    );
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.with_json(idx, |de| de.deserialize_struct(name, fields, visitor))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.with_json(idx, |de| de.deserialize_enum(name, variants, visitor))
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.with_json(idx, |de| de.deserialize_newtype_struct(name, visitor))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        &self,
+        len: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.with_json(idx, |de| de.deserialize_tuple(len, visitor))
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.with_json(idx, |de| de.deserialize_tuple_struct(name, len, visitor))
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.with_json(idx, |de| de.deserialize_unit_struct(name, visitor))
+    }
+}
@@ -1,9 +1,12 @@
 use marrow::view::{BitsWithOffset, ListView};
-use serde::de::{DeserializeSeed, SeqAccess, Visitor};
+use serde::de::{
+    value::{SeqAccessDeserializer, StrDeserializer},
+    DeserializeSeed, MapAccess, SeqAccess, Visitor,
+};
 
 use crate::internal::{
     error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
-    schema::get_strategy_from_metadata,
+    schema::extensions::ExtensionRegistry,
     utils::{ChildName, NamedType, Offset},
 };
 
@@ -17,25 +20,35 @@ pub struct ListDeserializer<'a, O: Offset> {
     pub item: Box<ArrayDeserializer<'a>>,
     pub offsets: &'a [O],
     pub validity: Option<BitsWithOffset<'a>>,
+    /// Whether lists can be deserialized as GeoArrow polygon structs
+    pub polygon: bool,
 }
 
 impl<'de, O: Offset> ListDeserializer<'de, O> {
-    pub fn new(path: String, view: ListView<'de, O>) -> Result<Self> {
+    pub fn new(
+        path: String,
+        view: ListView<'de, O>,
+        extensions: &ExtensionRegistry,
+    ) -> Result<Self> {
         let child_path = format!("{path}.{child}", child = ChildName(&view.meta.name));
-        let item = ArrayDeserializer::new(
-            child_path,
-            get_strategy_from_metadata(&view.meta.metadata)?.as_ref(),
-            *view.elements,
-        )?;
+        let item =
+            ArrayDeserializer::new(child_path, &view.meta.metadata, *view.elements, extensions)?;
 
         Ok(Self {
             path,
             item: Box::new(item),
             offsets: view.offsets,
             validity: view.validity,
+            polygon: false,
         })
     }
 
+    /// Allow to deserialize lists as structs with the fields `exterior` and `interiors`
+    pub fn with_polygon(mut self) -> Self {
+        self.polygon = true;
+        self
+    }
+
     fn get<'this>(&'this self, idx: usize) -> Result<ListItemDeserializer<'this, 'de>> {
         if idx + 1 >= self.offsets.len() {
             fail!("Outs of bound access");
@@ -83,6 +96,28 @@ impl<'de, O: Offset + NamedType> RandomAccessDeserializer<'de> for ListDeseriali
         try_(|| visitor.visit_seq(self.get(idx)?)).ctx(self)
     }
 
+    fn deserialize_struct<V: Visitor<'de>>(
+        &self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        try_(|| {
+            if !self.polygon {
+                fail!(
+                    "Only lists with the geoarrow.polygon extension can be deserialized as structs"
+                );
+            }
+            let rings = self.get(idx)?;
+            if rings.start >= rings.end {
+                fail!("Polygons require an exterior ring");
+            }
+            visitor.visit_map(PolygonDeserializer { rings, next: 0 })
+        })
+        .ctx(self)
+    }
+
     fn deserialize_bytes<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
         try_(|| visitor.visit_seq(self.get(idx)?)).ctx(self)
     }
@@ -110,3 +145,38 @@ impl<'de> SeqAccess<'de> for ListItemDeserializer<'_, 'de> {
         Ok(Some(item))
     }
 }
+
+/// Deserialize the rings of a polygon as a struct with the fields `exterior` and `interiors`
+struct PolygonDeserializer<'a, 'de> {
+    rings: ListItemDeserializer<'a, 'de>,
+    next: usize,
+}
+
+impl<'de> MapAccess<'de> for PolygonDeserializer<'_, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let Some(key) = ["exterior", "interiors"].get(self.next) else {
+            return Ok(None);
+        };
+        Ok(Some(seed.deserialize(StrDeserializer::<Error>::new(key))?))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let value = match self.next {
+            0 => {
+                let value = seed.deserialize(self.rings.item.at(self.rings.start))?;
+                self.rings.start += 1;
+                value
+            }
+            1 => seed.deserialize(SeqAccessDeserializer::new(ListItemDeserializer {
+                item: self.rings.item,
+                start: self.rings.start,
+                end: self.rings.end,
+            }))?,
+            _ => fail!("Invalid state in polygon deserializer"),
+        };
+        self.next += 1;
+        Ok(value)
+    }
+}
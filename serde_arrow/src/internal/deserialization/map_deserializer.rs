@@ -3,7 +3,7 @@ use serde::de::{DeserializeSeed, MapAccess, Visitor};
 
 use crate::internal::{
     error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
-    schema::get_strategy_from_metadata,
+    schema::extensions::ExtensionRegistry,
     utils::{ChildName, Offset},
 };
 
@@ -21,17 +21,14 @@ pub struct MapDeserializer<'a> {
 }
 
 impl<'a> MapDeserializer<'a> {
-    pub fn new(path: String, view: MapView<'a>) -> Result<Self> {
+    pub fn new(path: String, view: MapView<'a>, extensions: &ExtensionRegistry) -> Result<Self> {
         let keys_path = format!(
             "{path}.{entries}.{keys}",
             entries = ChildName(&view.meta.entries_name),
             keys = ChildName(&view.meta.keys.name),
         );
-        let keys = ArrayDeserializer::new(
-            keys_path,
-            get_strategy_from_metadata(&view.meta.keys.metadata)?.as_ref(),
-            *view.keys,
-        )?;
+        let keys =
+            ArrayDeserializer::new(keys_path, &view.meta.keys.metadata, *view.keys, extensions)?;
 
         let values_path = format!(
             "{path}.{entries}.{values}",
@@ -40,8 +37,9 @@ impl<'a> MapDeserializer<'a> {
         );
         let values = ArrayDeserializer::new(
             values_path,
-            get_strategy_from_metadata(&view.meta.values.metadata)?.as_ref(),
+            &view.meta.values.metadata,
             *view.values,
+            extensions,
         )?;
 
         Ok(Self {
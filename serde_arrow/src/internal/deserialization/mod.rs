@@ -7,18 +7,26 @@ pub mod dictionary_deserializer;
 pub mod duration_deserializer;
 pub mod enum_deserializer;
 pub mod enums_as_string_impl;
+pub mod extension_deserializer;
+pub mod fixed_shape_tensor_deserializer;
 pub mod fixed_size_binary_deserializer;
 pub mod fixed_size_list_deserializer;
 pub mod float_deserializer;
 pub mod float_impls;
+pub mod generic_dictionary_deserializer;
 pub mod integer_deserializer;
 pub mod integer_impls;
+pub mod interval_deserializer;
+#[cfg(feature = "serde_json")]
+pub mod json_deserializer;
 pub mod list_deserializer;
 pub mod map_deserializer;
 pub mod null_deserializer;
 pub mod random_access_deserializer;
+pub mod run_end_encoded_deserializer;
 pub mod string_deserializer;
 pub mod struct_deserializer;
 pub mod time_deserializer;
 pub mod timestamp_deserializer;
 pub mod utils;
+pub mod variable_shape_tensor_deserializer;
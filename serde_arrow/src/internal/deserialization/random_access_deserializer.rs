@@ -69,6 +69,14 @@ pub trait RandomAccessDeserializer<'de>: Context + Sized {
         fail!(in self, "Deserializer does not implement deserialize_i64_at");
     }
 
+    fn deserialize_i128<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        fail!(in self, "Deserializer does not implement deserialize_i128_at");
+    }
+
+    fn deserialize_u128<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        fail!(in self, "Deserializer does not implement deserialize_u128_at");
+    }
+
     fn deserialize_u8<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
         fail!(in self, "Deserializer does not implement deserialize_u8_at");
     }
@@ -222,6 +230,14 @@ impl<'de, D: RandomAccessDeserializer<'de>> Deserializer<'de> for PositionedDese
         self.0.deserialize_i64(visitor, self.1)
     }
 
+    fn deserialize_i128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_i128(visitor, self.1)
+    }
+
+    fn deserialize_u128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_u128(visitor, self.1)
+    }
+
     fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         self.0.deserialize_u8(visitor, self.1)
     }
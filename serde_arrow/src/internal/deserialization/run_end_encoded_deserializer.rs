@@ -0,0 +1,282 @@
+use marrow::view::{RunEndEncodedView, View};
+use serde::de::Visitor;
+
+use crate::internal::{
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    schema::extensions::ExtensionRegistry,
+    utils::ChildName,
+};
+
+use super::{
+    array_deserializer::ArrayDeserializer, random_access_deserializer::RandomAccessDeserializer,
+};
+
+/// Deserialize run end encoded arrays by mapping logical to physical indices
+///
+/// The physical index of a logical index is found by a binary search over the run ends.
+pub struct RunEndEncodedDeserializer<'a> {
+    path: String,
+    run_ends: Vec<usize>,
+    values: Box<ArrayDeserializer<'a>>,
+}
+
+impl<'a> RunEndEncodedDeserializer<'a> {
+    pub fn new(
+        path: String,
+        view: RunEndEncodedView<'a>,
+        extensions: &ExtensionRegistry,
+    ) -> Result<Self> {
+        let run_ends = match *view.run_ends {
+            View::Int16(view) => convert_run_ends(view.values)?,
+            View::Int32(view) => convert_run_ends(view.values)?,
+            View::Int64(view) => convert_run_ends(view.values)?,
+            _ => fail!("Run ends must be of type Int16, Int32 or Int64"),
+        };
+        for window in run_ends.windows(2) {
+            if window[0] >= window[1] {
+                fail!("Run ends must be strictly increasing");
+            }
+        }
+
+        let values_path = format!("{path}.{child}", child = ChildName(&view.meta.values.name));
+        let values = ArrayDeserializer::new(
+            values_path,
+            &view.meta.values.metadata,
+            *view.values,
+            extensions,
+        )?;
+
+        Ok(Self {
+            path,
+            run_ends,
+            values: Box::new(values),
+        })
+    }
+
+    fn physical_index(&self, idx: usize) -> Result<usize> {
+        let physical_idx = self.run_ends.partition_point(|&end| end <= idx);
+        if physical_idx >= self.run_ends.len() {
+            fail!("Out of bounds access");
+        }
+        Ok(physical_idx)
+    }
+}
+
+fn convert_run_ends<I: Copy>(run_ends: &[I]) -> Result<Vec<usize>>
+where
+    usize: TryFrom<I>,
+    crate::internal::error::Error: From<<usize as TryFrom<I>>::Error>,
+{
+    let mut res = Vec::with_capacity(run_ends.len());
+    for &run_end in run_ends {
+        res.push(usize::try_from(run_end)?);
+    }
+    Ok(res)
+}
+
+impl Context for RunEndEncodedDeserializer<'_> {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", "RunEndEncoded(..)");
+    }
+}
+
+impl<'de> RandomAccessDeserializer<'de> for RunEndEncodedDeserializer<'de> {
+    fn is_some(&self, idx: usize) -> Result<bool> {
+        try_(|| self.values.is_some(self.physical_index(idx)?)).ctx(self)
+    }
+
+    fn deserialize_any_some<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_any_some(visitor, idx)
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_any(visitor, idx)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_option(visitor, idx)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_ignored_any(visitor, idx)
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_bool(visitor, idx)
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_i8(visitor, idx)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_i16(visitor, idx)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_i32(visitor, idx)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_i64(visitor, idx)
+    }
+
+    fn deserialize_i128<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_i128(visitor, idx)
+    }
+
+    fn deserialize_u128<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_u128(visitor, idx)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_u8(visitor, idx)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_u16(visitor, idx)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_u32(visitor, idx)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_u64(visitor, idx)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_f32(visitor, idx)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_f64(visitor, idx)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_char(visitor, idx)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_str(visitor, idx)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_string(visitor, idx)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_map(visitor, idx)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_struct(name, fields, visitor, idx)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_byte_buf(visitor, idx)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_bytes(visitor, idx)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_enum(name, variants, visitor, idx)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_identifier(visitor, idx)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_newtype_struct(name, visitor, idx)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        &self,
+        len: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_tuple(len, visitor, idx)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_seq(visitor, idx)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values
+            .deserialize_tuple_struct(name, len, visitor, idx)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_unit(visitor, idx)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        let idx = self.physical_index(idx).ctx(self)?;
+        self.values.deserialize_unit_struct(name, visitor, idx)
+    }
+}
@@ -3,7 +3,7 @@ use serde::de::{value::StrDeserializer, DeserializeSeed, MapAccess, SeqAccess, V
 
 use crate::internal::{
     error::{fail, set_default, Context, ContextSupport, Error, Result},
-    schema::get_strategy_from_metadata,
+    schema::extensions::ExtensionRegistry,
     utils::ChildName,
 };
 
@@ -20,15 +20,12 @@ pub struct StructDeserializer<'a> {
 }
 
 impl<'a> StructDeserializer<'a> {
-    pub fn new(path: String, view: StructView<'a>) -> Result<Self> {
+    pub fn new(path: String, view: StructView<'a>, extensions: &ExtensionRegistry) -> Result<Self> {
         let mut fields = Vec::new();
         for (field_meta, field_view) in view.fields {
             let child_path = format!("{path}.{child}", child = ChildName(&field_meta.name));
-            let field_deserializer = ArrayDeserializer::new(
-                child_path,
-                get_strategy_from_metadata(&field_meta.metadata)?.as_ref(),
-                field_view,
-            )?;
+            let field_deserializer =
+                ArrayDeserializer::new(child_path, &field_meta.metadata, field_view, extensions)?;
             let field_name = field_meta.name;
 
             fields.push((field_name, field_deserializer));
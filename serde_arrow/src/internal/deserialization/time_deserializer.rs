@@ -7,7 +7,7 @@ use serde::de::Visitor;
 
 use crate::internal::{
     error::{set_default, try_, try_opt, Context, ContextSupport, Error, Result},
-    utils::{array_view_ext::ViewAccess, NamedType},
+    utils::{array_view_ext::ViewAccess, time_tuple, NamedType},
 };
 
 use super::{integer_deserializer::Integer, random_access_deserializer::RandomAccessDeserializer};
@@ -31,6 +31,10 @@ impl<'a, T: Integer> TimeDeserializer<'a, T> {
     }
 
     pub fn get_string_repr(&self, ts: i64) -> Result<String> {
+        Ok(self.get_time(ts)?.to_string())
+    }
+
+    pub fn get_time(&self, ts: i64) -> Result<NaiveTime> {
         try_opt(|| {
             let (secs, nano) = match self.unit {
                 TimeUnit::Second => (ts, 0),
@@ -38,11 +42,10 @@ impl<'a, T: Integer> TimeDeserializer<'a, T> {
                 TimeUnit::Microsecond => (ts / 1_000_000, (ts % 1_000_000) * 1_000),
                 TimeUnit::Nanosecond => (ts / 1_000_000_000, ts % 1_000_000_000),
             };
-            let time = NaiveTime::from_num_seconds_from_midnight_opt(
+            NaiveTime::from_num_seconds_from_midnight_opt(
                 u32::try_from(secs).ok()?,
                 u32::try_from(nano).ok()?,
-            )?;
-            Some(time.to_string())
+            )
         })
         .ok_or_else(|| {
             Error::custom(format!(
@@ -85,6 +88,20 @@ impl<'de, T: Integer + NamedType> RandomAccessDeserializer<'de> for TimeDeserial
         try_(|| visitor.visit_i64(self.values.get_required(idx)?.into_i64()?)).ctx(self)
     }
 
+    fn deserialize_tuple<V: Visitor<'de>>(
+        &self,
+        _: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        try_(|| {
+            let ts = self.values.get_required(idx)?.into_i64()?;
+            let time = self.get_time(ts)?;
+            time_tuple::visit_tuple(visitor, time_tuple::time_to_tuple(time))
+        })
+        .ctx(self)
+    }
+
     fn deserialize_str<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
         try_(|| self.deserialize_string(visitor, idx)).ctx(self)
     }
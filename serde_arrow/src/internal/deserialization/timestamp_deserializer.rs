@@ -1,4 +1,4 @@
-use chrono::{DateTime, Datelike, Utc};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDateTime, Utc};
 use marrow::{
     datatypes::TimeUnit,
     view::{PrimitiveView, TimestampView},
@@ -6,8 +6,13 @@ use marrow::{
 use serde::de::Visitor;
 
 use crate::internal::{
+    chrono::TimeZone,
     error::{fail, set_default, try_, Context, ContextSupport, Result},
-    utils::array_view_ext::ViewAccess,
+    utils::{
+        array_view_ext::ViewAccess,
+        std_time::{StdTimeAccess, StdTimeParts, StdTimeType},
+        time_tuple,
+    },
 };
 
 use super::random_access_deserializer::RandomAccessDeserializer;
@@ -16,11 +21,14 @@ pub struct TimestampDeserializer<'a> {
     path: String,
     values: PrimitiveView<'a, i64>,
     unit: TimeUnit,
-    is_utc: bool,
+    tz: TimeZone,
+    pattern: Option<String>,
+    annotate: bool,
 }
 
 impl<'a> TimestampDeserializer<'a> {
     pub fn new(path: String, view: TimestampView<'a>) -> Result<Self> {
+        let tz = TimeZone::new(view.timezone.as_deref())?;
         Ok(Self {
             path,
             values: PrimitiveView {
@@ -28,11 +36,25 @@ impl<'a> TimestampDeserializer<'a> {
                 values: view.values,
             },
             unit: view.unit,
-            is_utc: is_utc_timestamp(view.timezone.as_deref())?,
+            tz,
+            pattern: None,
+            annotate: tz.is_annotated_by_default(),
         })
     }
 
-    pub fn get_string_repr(&self, ts: i64) -> Result<String> {
+    /// Format strings with the given `chrono` pattern instead of RFC 3339
+    pub fn with_pattern(mut self, pattern: String) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Select whether strings carry the time zone in brackets, e.g., `+05:30[+05:30]`
+    pub fn with_annotation(mut self, annotate: bool) -> Self {
+        self.annotate = annotate;
+        self
+    }
+
+    pub fn get_date_time(&self, ts: i64) -> Result<DateTime<Utc>> {
         let Some(date_time) = (match self.unit {
             TimeUnit::Second => DateTime::from_timestamp(ts, 0),
             TimeUnit::Millisecond => DateTime::from_timestamp_millis(ts),
@@ -41,16 +63,49 @@ impl<'a> TimestampDeserializer<'a> {
         }) else {
             fail!("Unsupported timestamp value: {ts}");
         };
+        Ok(date_time)
+    }
 
-        if self.is_utc {
-            Ok(self.format_with_suffix(date_time, "Z"))
-        } else {
-            Ok(self.format_with_suffix(date_time, ""))
+    pub fn get_string_repr(&self, ts: i64) -> Result<String> {
+        let date_time = self.get_date_time(ts)?;
+        let (local, suffix) = self.tz.localize(date_time, self.annotate);
+        let Some(pattern) = self.pattern.as_deref() else {
+            return Ok(self.format_with_suffix(local, &suffix));
+        };
+        if self.tz == TimeZone::Naive {
+            return Ok(local.format(pattern).to_string());
         }
+        let offset = (local - date_time.naive_utc()).num_seconds();
+        let Some(offset) = i32::try_from(offset).ok().and_then(FixedOffset::east_opt) else {
+            fail!("Invalid offset of {offset} seconds");
+        };
+        Ok(date_time.with_timezone(&offset).format(pattern).to_string())
     }
 
-    pub fn format_with_suffix(&self, date_time: DateTime<Utc>, suffix: &str) -> String {
-        let date_time = date_time.naive_utc();
+    /// Split a timestamp into a date time tuple
+    ///
+    /// Timestamps without time zone are returned as tuples without offset (6 components),
+    /// timestamps with time zone as tuples with the local offset (9 components).
+    pub fn get_tuple(&self, ts: i64, len: usize) -> Result<Vec<i64>> {
+        let date_time = self.get_date_time(ts)?;
+        let (local, _) = self.tz.localize(date_time, false);
+        match (len, self.tz) {
+            (6, TimeZone::Naive) => Ok(time_tuple::date_time_to_tuple(local, None)),
+            (9, tz) if tz != TimeZone::Naive => {
+                let offset = (local - date_time.naive_utc()).num_seconds();
+                Ok(time_tuple::date_time_to_tuple(local, Some(offset)))
+            }
+            (6, _) => fail!(
+                "Timestamps with time zone cannot be deserialized as date times without offset"
+            ),
+            (9, _) => fail!(
+                "Timestamps without time zone cannot be deserialized as date times with offset"
+            ),
+            (len, _) => fail!("Cannot deserialize a timestamp as a tuple with {len} components"),
+        }
+    }
+
+    pub fn format_with_suffix(&self, date_time: NaiveDateTime, suffix: &str) -> String {
         // special handling of negative dates:
         //
         // - jiff expects 6 digits years in this case
@@ -73,14 +128,6 @@ impl<'a> TimestampDeserializer<'a> {
     }
 }
 
-fn is_utc_timestamp(timezone: Option<&str>) -> Result<bool> {
-    match timezone {
-        Some(tz) if tz.to_lowercase() == "utc" => Ok(true),
-        Some(tz) => fail!("Unsupported timezone: {} is not supported", tz),
-        None => Ok(false),
-    }
-}
-
 impl Context for TimestampDeserializer<'_> {
     fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
         set_default(annotations, "field", &self.path);
@@ -101,6 +148,38 @@ impl<'de> RandomAccessDeserializer<'de> for TimestampDeserializer<'de> {
         try_(|| visitor.visit_i64(*self.values.get_required(idx)?)).ctx(self)
     }
 
+    fn deserialize_i128<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        try_(|| visitor.visit_i128(i128::from(*self.values.get_required(idx)?))).ctx(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        &self,
+        len: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        try_(|| {
+            let ts = self.values.get_required(idx)?;
+            time_tuple::visit_tuple(visitor, self.get_tuple(*ts, len)?)
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        &self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        try_(|| {
+            let value = self.values.get_required(idx)?;
+            let parts = StdTimeParts::from_arrow(*value, self.unit)?;
+            visitor.visit_map(StdTimeAccess::new(StdTimeType::SystemTime, parts))
+        })
+        .ctx(self)
+    }
+
     fn deserialize_str<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
         try_(|| self.deserialize_string(visitor, idx)).ctx(self)
     }
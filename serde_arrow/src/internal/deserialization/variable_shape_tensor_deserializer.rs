@@ -0,0 +1,157 @@
+use serde::de::{Deserialize, Visitor};
+
+use crate::internal::{
+    error::{fail, set_default, Context, Result},
+    schema::extensions::TensorMetadata,
+    utils::Offset,
+};
+
+use super::{
+    array_deserializer::ArrayDeserializer,
+    fixed_shape_tensor_deserializer::{logical_layout, NestedTensor},
+    random_access_deserializer::RandomAccessDeserializer,
+    struct_deserializer::StructDeserializer,
+};
+
+/// Deserialize `arrow.variable_shape_tensor` arrays into nested sequences
+///
+/// The shape of each tensor is read from the `shape` child, the elements from the `data` list.
+/// Structs and maps are deserialized from the storage directly, i.e., with flat data in storage
+/// order and the physical shape.
+pub struct VariableShapeTensorDeserializer<'a> {
+    storage: StructDeserializer<'a>,
+    data: usize,
+    shape: usize,
+    ndim: usize,
+    permutation: Option<Vec<usize>>,
+}
+
+impl<'a> VariableShapeTensorDeserializer<'a> {
+    pub fn new(storage: StructDeserializer<'a>, metadata: TensorMetadata) -> Result<Self> {
+        let Some(data) = storage.fields.iter().position(|(name, _)| name == "data") else {
+            fail!("Variable shape tensors require a data field");
+        };
+        let Some(shape) = storage.fields.iter().position(|(name, _)| name == "shape") else {
+            fail!("Variable shape tensors require a shape field");
+        };
+        if !matches!(
+            storage.fields[data].1,
+            ArrayDeserializer::List(_) | ArrayDeserializer::LargeList(_)
+        ) {
+            fail!("The data of a variable shape tensor must be a List or LargeList");
+        }
+        let ArrayDeserializer::FixedSizeList(shape_list) = &storage.fields[shape].1 else {
+            fail!("The shape of a variable shape tensor must be a FixedSizeList");
+        };
+        let ndim = shape_list.n;
+        if let Some(permutation) = metadata.permutation.as_ref() {
+            if permutation.len() != ndim {
+                fail!("The permutation of the variable shape tensor must have one entry per dimension");
+            }
+        }
+
+        Ok(Self {
+            storage,
+            data,
+            shape,
+            ndim,
+            permutation: metadata.permutation,
+        })
+    }
+
+    /// Get the item deserializer and the offset of the first element of the tensor
+    fn get_data(&self, idx: usize) -> Result<(&ArrayDeserializer<'a>, usize, usize)> {
+        match &self.storage.fields[self.data].1 {
+            ArrayDeserializer::List(list) => Ok((
+                &list.item,
+                list.offsets[idx].try_into_usize()?,
+                list.offsets[idx + 1].try_into_usize()?,
+            )),
+            ArrayDeserializer::LargeList(list) => Ok((
+                &list.item,
+                list.offsets[idx].try_into_usize()?,
+                list.offsets[idx + 1].try_into_usize()?,
+            )),
+            _ => fail!("The data of a variable shape tensor must be a List or LargeList"),
+        }
+    }
+}
+
+impl Context for VariableShapeTensorDeserializer<'_> {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.storage.path);
+        set_default(annotations, "extension", "arrow.variable_shape_tensor");
+        set_default(annotations, "data_type", "Struct(..)");
+    }
+}
+
+impl<'de> RandomAccessDeserializer<'de> for VariableShapeTensorDeserializer<'de> {
+    fn is_some(&self, idx: usize) -> Result<bool> {
+        self.storage.is_some(idx)
+    }
+
+    fn deserialize_any_some<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        self.deserialize_seq(visitor, idx)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        if idx >= self.storage.len {
+            fail!("Out of bounds access");
+        }
+        if self.ndim <= 1 {
+            return self.storage.fields[self.data]
+                .1
+                .deserialize_seq(visitor, idx);
+        }
+
+        let shape = Vec::<usize>::deserialize(self.storage.fields[self.shape].1.at(idx))?;
+        let (item, start, end) = self.get_data(idx)?;
+        if shape.len() != self.ndim || shape.iter().product::<usize>() != end - start {
+            fail!(
+                "The shape {shape:?} of the tensor in row {idx} does not match its {len} elements",
+                len = end - start,
+            );
+        }
+
+        let (logical_shape, strides) = logical_layout(&shape, self.permutation.as_deref());
+        let tensor = NestedTensor {
+            item,
+            logical_shape: &logical_shape,
+            strides: &strides,
+        };
+        tensor.deserialize_seq(visitor, start)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        &self,
+        _: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor, idx)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        &self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor, idx)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(&self, visitor: V, idx: usize) -> Result<V::Value> {
+        self.storage.deserialize_map(visitor, idx)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        &self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+        idx: usize,
+    ) -> Result<V::Value> {
+        self.storage.deserialize_struct(name, fields, visitor, idx)
+    }
+}
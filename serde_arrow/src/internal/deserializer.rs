@@ -7,7 +7,7 @@ use crate::internal::{
         array_deserializer::ArrayDeserializer, struct_deserializer::StructDeserializer,
     },
     error::{fail, Error, Result},
-    schema::get_strategy_from_metadata,
+    schema::extensions::ExtensionRegistry,
     utils::array_view_ext::ViewExt,
 };
 
@@ -90,6 +90,16 @@ pub struct Deserializer<'de> {
 
 impl<'de> Deserializer<'de> {
     pub(crate) fn new(fields: &[Field], views: Vec<View<'de>>) -> Result<Self> {
+        Self::new_with_extensions(fields, views, &ExtensionRegistry::default())
+    }
+
+    pub(crate) fn new_with_extensions(
+        fields: &[Field],
+        views: Vec<View<'de>>,
+        extensions: &ExtensionRegistry,
+    ) -> Result<Self> {
+        extensions.validate_fields(fields)?;
+
         let len = match views.first() {
             Some(view) => view.len()?,
             None => 0,
@@ -100,11 +110,11 @@ impl<'de> Deserializer<'de> {
             if view.len()? != len {
                 fail!("Cannot deserialize from arrays with different lengths");
             }
-            let strategy = get_strategy_from_metadata(&field.metadata)?;
             let deserializer = ArrayDeserializer::new(
                 format!("$.{child}", child = ChildName(&field.name)),
-                strategy.as_ref(),
+                &field.metadata,
                 view,
+                extensions,
             )?;
             deserializers.push((field.name.clone(), deserializer));
         }
@@ -238,6 +248,14 @@ impl<'de> serde::de::Deserializer<'de> for DeserializerItem<'_, 'de> {
         self.deserializer.at(self.idx).deserialize_i64(visitor)
     }
 
+    fn deserialize_i128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserializer.at(self.idx).deserialize_i128(visitor)
+    }
+
+    fn deserialize_u128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserializer.at(self.idx).deserialize_u128(visitor)
+    }
+
     fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         self.deserializer.at(self.idx).deserialize_u8(visitor)
     }
@@ -529,8 +547,8 @@ impl<'de> SeqAccess<'de> for Private<DeserializerIterator<'_, 'de>> {
 }
 
 const _: () = {
-    trait AssertSendSync: Send + Sync {}
-    impl AssertSendSync for Deserializer<'_> {}
-    impl AssertSendSync for DeserializerItem<'_, '_> {}
-    impl AssertSendSync for DeserializerIterator<'_, '_> {}
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Deserializer<'static>>();
+    assert_send_sync::<DeserializerItem<'static, 'static>>();
+    assert_send_sync::<DeserializerIterator<'static, 'static>>();
 };
@@ -279,6 +279,13 @@ impl From<chrono::format::ParseError> for Error {
     }
 }
 
+#[cfg(feature = "serde_json")]
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Self::custom_from(format!("serde_json::Error: {err}"), err)
+    }
+}
+
 impl From<std::char::CharTryFromError> for Error {
     fn from(err: std::char::CharTryFromError) -> Error {
         Self::custom_from(format!("CharTryFromError: {err}"), err)
@@ -350,7 +357,7 @@ fn error_can_be_converted_to_anyhow() {
 }
 
 const _: () = {
-    trait AssertSendSync: Send + Sync {}
-    impl AssertSendSync for Error {}
-    impl<T: Send + Sync> AssertSendSync for Result<T> {}
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Error>();
+    assert_send_sync::<Result<()>>();
 };
@@ -0,0 +1,422 @@
+use marrow::datatypes::{DataType, Field};
+
+use crate::internal::{
+    error::Result,
+    schema::{get_strategy_from_metadata, DataTypeDisplay, SerdeArrowSchema, Strategy},
+    utils::child_path,
+};
+
+use super::tracer::OptionalStrategyDisplay;
+
+/// The differences between two schemas, see [`SerdeArrowSchema::diff`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaDiff {
+    /// The changes of the individual fields in schema order
+    pub changes: Vec<FieldChange>,
+}
+
+impl SchemaDiff {
+    /// Whether the schemas agree
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Whether consumers of the new schema can read data of the old schema
+    pub fn is_backward_compatible(&self) -> bool {
+        self.changes
+            .iter()
+            .all(|change| change.compatibility.is_backward_compatible())
+    }
+
+    /// Whether consumers of the old schema can read data of the new schema
+    pub fn is_forward_compatible(&self) -> bool {
+        self.changes
+            .iter()
+            .all(|change| change.compatibility.is_forward_compatible())
+    }
+}
+
+/// A change of a single field
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    /// The dotted path of the field, e.g., `"a.b"` for the child `b` of the field `a`, fields
+    /// with empty names are written as `<empty>`, as in the paths of error messages
+    pub path: String,
+    /// The kind of change
+    pub kind: FieldChangeKind,
+    /// Which consumers can read the data after the change
+    pub compatibility: Compatibility,
+}
+
+/// The kind of a [`FieldChange`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldChangeKind {
+    /// The field is only present in the new schema
+    Added {
+        /// The data type of the new field
+        data_type: DataType,
+        /// The nullability of the new field
+        nullable: bool,
+        /// The strategy of the new field
+        strategy: Option<Strategy>,
+    },
+    /// The field is only present in the old schema
+    Removed {
+        /// The data type of the removed field
+        data_type: DataType,
+        /// The nullability of the removed field
+        nullable: bool,
+        /// The strategy of the removed field
+        strategy: Option<Strategy>,
+    },
+    /// The data type or the strategy of the field changed
+    Retyped {
+        /// The old data type
+        old_data_type: DataType,
+        /// The old strategy
+        old_strategy: Option<Strategy>,
+        /// The new data type
+        new_data_type: DataType,
+        /// The new strategy
+        new_strategy: Option<Strategy>,
+    },
+    /// The nullability of the field changed
+    Renullabled {
+        /// The old nullability
+        old_nullable: bool,
+        /// The new nullability
+        new_nullable: bool,
+    },
+}
+
+/// The compatibility of a schema change
+///
+/// The classification assumes that consumers deserialize into types matching their schema, that
+/// optional fields are represented as `Option<..>` and that unknown fields are ignored, i.e., the
+/// default behavior of `#[derive(Deserialize)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compatibility {
+    /// Consumers of either schema can read data of the other schema
+    Full,
+    /// Consumers of the new schema can read data of the old schema, but not the reverse
+    Backward,
+    /// Consumers of the old schema can read data of the new schema, but not the reverse
+    Forward,
+    /// Neither consumers of the old nor of the new schema can read data of the other schema
+    Breaking,
+}
+
+impl Compatibility {
+    fn new(backward: bool, forward: bool) -> Self {
+        match (backward, forward) {
+            (true, true) => Self::Full,
+            (true, false) => Self::Backward,
+            (false, true) => Self::Forward,
+            (false, false) => Self::Breaking,
+        }
+    }
+
+    /// Whether consumers of the new schema can read data of the old schema
+    pub fn is_backward_compatible(self) -> bool {
+        matches!(self, Self::Full | Self::Backward)
+    }
+
+    /// Whether consumers of the old schema can read data of the new schema
+    pub fn is_forward_compatible(self) -> bool {
+        matches!(self, Self::Full | Self::Forward)
+    }
+}
+
+impl std::fmt::Display for Compatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Full => write!(f, "fully compatible"),
+            Self::Backward => write!(f, "backward compatible"),
+            Self::Forward => write!(f, "forward compatible"),
+            Self::Breaking => write!(f, "breaking"),
+        }
+    }
+}
+
+impl std::fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = &self.path;
+        let compatibility = self.compatibility;
+        match &self.kind {
+            FieldChangeKind::Added {
+                data_type,
+                nullable,
+                strategy,
+            } => write!(
+                f,
+                "added {path}: {ty}{st} ({compatibility})",
+                ty = TypeDisplay(data_type, *nullable),
+                st = OptionalStrategyDisplay(strategy.as_ref()),
+            ),
+            FieldChangeKind::Removed {
+                data_type,
+                nullable,
+                strategy,
+            } => write!(
+                f,
+                "removed {path}: {ty}{st} ({compatibility})",
+                ty = TypeDisplay(data_type, *nullable),
+                st = OptionalStrategyDisplay(strategy.as_ref()),
+            ),
+            FieldChangeKind::Retyped {
+                old_data_type,
+                old_strategy,
+                new_data_type,
+                new_strategy,
+            } => write!(
+                f,
+                "retyped {path}: {old_ty}{old_st} -> {new_ty}{new_st} ({compatibility})",
+                old_ty = DataTypeDisplay(old_data_type),
+                old_st = OptionalStrategyDisplay(old_strategy.as_ref()),
+                new_ty = DataTypeDisplay(new_data_type),
+                new_st = OptionalStrategyDisplay(new_strategy.as_ref()),
+            ),
+            FieldChangeKind::Renullabled {
+                old_nullable,
+                new_nullable,
+            } => write!(
+                f,
+                "renullabled {path}: {old} -> {new} ({compatibility})",
+                old = NullableDisplay(*old_nullable),
+                new = NullableDisplay(*new_nullable),
+            ),
+        }
+    }
+}
+
+struct TypeDisplay<'a>(&'a DataType, bool);
+
+impl std::fmt::Display for TypeDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({})",
+            DataTypeDisplay(self.0),
+            NullableDisplay(self.1)
+        )
+    }
+}
+
+struct NullableDisplay(bool);
+
+impl std::fmt::Display for NullableDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0 {
+            write!(f, "nullable")
+        } else {
+            write!(f, "non-nullable")
+        }
+    }
+}
+
+impl SerdeArrowSchema {
+    /// Compare the schema with a newer version and classify the differences
+    ///
+    /// Fields are matched by name. Children of structs, lists and maps are compared recursively.
+    /// Each change is classified by whether the deserializers of `serde_arrow` can read data of
+    /// the old schema into types of the new schema (backward compatible) and data of the new
+    /// schema into types of the old schema (forward compatible).
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_json::json;
+    /// use serde_arrow::schema::{Compatibility, SchemaLike, SerdeArrowSchema};
+    ///
+    /// let old = SerdeArrowSchema::from_value(&json!([
+    ///     {"name": "a", "data_type": "I32"},
+    /// ]))?;
+    /// let new = SerdeArrowSchema::from_value(&json!([
+    ///     {"name": "a", "data_type": "I64"},
+    ///     {"name": "b", "data_type": "Utf8", "nullable": true},
+    /// ]))?;
+    ///
+    /// let diff = old.diff(&new)?;
+    /// assert_eq!(diff.changes.len(), 2);
+    /// assert_eq!(diff.changes[0].path, "a");
+    /// assert_eq!(diff.changes[0].compatibility, Compatibility::Backward);
+    /// assert_eq!(diff.changes[1].path, "b");
+    /// assert_eq!(diff.changes[1].compatibility, Compatibility::Full);
+    /// assert!(diff.is_backward_compatible());
+    /// assert!(!diff.is_forward_compatible());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diff(&self, new: &SerdeArrowSchema) -> Result<SchemaDiff> {
+        let mut changes = Vec::new();
+        diff_children("$", &self.fields, &new.fields, &mut changes)?;
+        Ok(SchemaDiff { changes })
+    }
+}
+
+fn diff_children(
+    path: &str,
+    old: &[Field],
+    new: &[Field],
+    changes: &mut Vec<FieldChange>,
+) -> Result<()> {
+    for old_field in old {
+        let child_path = child_path(path, &old_field.name);
+        match new.iter().find(|field| field.name == old_field.name) {
+            Some(new_field) => diff_field(child_path, old_field, new_field, changes)?,
+            None => changes.push(FieldChange {
+                path: change_path(&child_path),
+                kind: FieldChangeKind::Removed {
+                    data_type: old_field.data_type.clone(),
+                    nullable: old_field.nullable,
+                    strategy: get_strategy_from_metadata(&old_field.metadata)?,
+                },
+                compatibility: Compatibility::new(true, old_field.nullable),
+            }),
+        }
+    }
+    for new_field in new {
+        if !old.iter().any(|field| field.name == new_field.name) {
+            changes.push(FieldChange {
+                path: change_path(&child_path(path, &new_field.name)),
+                kind: FieldChangeKind::Added {
+                    data_type: new_field.data_type.clone(),
+                    nullable: new_field.nullable,
+                    strategy: get_strategy_from_metadata(&new_field.metadata)?,
+                },
+                compatibility: Compatibility::new(new_field.nullable, true),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn diff_field(
+    path: String,
+    old: &Field,
+    new: &Field,
+    changes: &mut Vec<FieldChange>,
+) -> Result<()> {
+    use DataType as D;
+
+    let old_strategy = get_strategy_from_metadata(&old.metadata)?;
+    let new_strategy = get_strategy_from_metadata(&new.metadata)?;
+
+    if old_strategy != new_strategy || !is_same_shallow_type(&old.data_type, &new.data_type) {
+        let compatibility = if old_strategy != new_strategy {
+            Compatibility::Breaking
+        } else {
+            Compatibility::new(
+                can_read_shallow(&old.data_type, &new.data_type),
+                can_read_shallow(&new.data_type, &old.data_type),
+            )
+        };
+        changes.push(FieldChange {
+            path: change_path(&path),
+            kind: FieldChangeKind::Retyped {
+                old_data_type: old.data_type.clone(),
+                old_strategy,
+                new_data_type: new.data_type.clone(),
+                new_strategy,
+            },
+            compatibility,
+        });
+    }
+
+    if old.nullable != new.nullable {
+        changes.push(FieldChange {
+            path: change_path(&path),
+            kind: FieldChangeKind::Renullabled {
+                old_nullable: old.nullable,
+                new_nullable: new.nullable,
+            },
+            compatibility: Compatibility::new(new.nullable, old.nullable),
+        });
+    }
+
+    match (&old.data_type, &new.data_type) {
+        (D::Struct(old_children), D::Struct(new_children)) => {
+            diff_children(&path, old_children, new_children, changes)?;
+        }
+        (
+            D::List(old_item) | D::LargeList(old_item) | D::FixedSizeList(old_item, _),
+            D::List(new_item) | D::LargeList(new_item) | D::FixedSizeList(new_item, _),
+        )
+        | (D::Map(old_item, _), D::Map(new_item, _)) => {
+            diff_children(
+                &path,
+                std::slice::from_ref(old_item.as_ref()),
+                std::slice::from_ref(new_item.as_ref()),
+                changes,
+            )?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// The path reported in field changes, i.e., without the `$.` prefix of the root
+fn change_path(path: &str) -> String {
+    path.strip_prefix("$.").unwrap_or(path).to_owned()
+}
+
+/// Whether both types agree, ignoring the children of structs, lists and maps
+fn is_same_shallow_type(old: &DataType, new: &DataType) -> bool {
+    use DataType as D;
+
+    match (old, new) {
+        (D::Struct(_), D::Struct(_))
+        | (D::List(_), D::List(_))
+        | (D::LargeList(_), D::LargeList(_)) => true,
+        (D::FixedSizeList(_, old_n), D::FixedSizeList(_, new_n)) => old_n == new_n,
+        (D::Map(_, old_sorted), D::Map(_, new_sorted)) => old_sorted == new_sorted,
+        (old, new) => old == new,
+    }
+}
+
+/// Whether readers of the `reader` type can read data of the `data` type, ignoring children
+fn can_read_shallow(data: &DataType, reader: &DataType) -> bool {
+    use DataType as D;
+
+    match (data, reader) {
+        (data, reader) if data == reader => true,
+        (D::Struct(_), D::Struct(_)) => true,
+        (D::List(_) | D::LargeList(_) | D::FixedSizeList(_, _), D::List(_) | D::LargeList(_)) => {
+            true
+        }
+        (D::FixedSizeList(_, data_n), D::FixedSizeList(_, reader_n)) => data_n == reader_n,
+        (D::Map(_, _), D::Map(_, _)) => true,
+        (D::Utf8 | D::LargeUtf8 | D::Utf8View, D::Utf8 | D::LargeUtf8 | D::Utf8View) => true,
+        (D::Dictionary(_, value), D::Utf8 | D::LargeUtf8 | D::Utf8View) => {
+            matches!(value.as_ref(), D::Utf8 | D::LargeUtf8 | D::Utf8View)
+        }
+        (D::Utf8 | D::LargeUtf8 | D::Utf8View, D::Dictionary(_, value)) => {
+            matches!(value.as_ref(), D::Utf8 | D::LargeUtf8 | D::Utf8View)
+        }
+        (
+            D::Binary | D::LargeBinary | D::BinaryView,
+            D::Binary | D::LargeBinary | D::BinaryView,
+        ) => true,
+        (D::Float16 | D::Float32, D::Float32 | D::Float64) => true,
+        (data, reader) => match (integer_range(data), integer_range(reader)) {
+            (Some((data_min, data_max)), Some((reader_min, reader_max))) => {
+                reader_min <= data_min && data_max <= reader_max
+            }
+            _ => false,
+        },
+    }
+}
+
+/// The range of values of integer types, values are read with checked conversions
+fn integer_range(data_type: &DataType) -> Option<(i128, i128)> {
+    match data_type {
+        DataType::Int8 => Some((i8::MIN.into(), i8::MAX.into())),
+        DataType::Int16 => Some((i16::MIN.into(), i16::MAX.into())),
+        DataType::Int32 => Some((i32::MIN.into(), i32::MAX.into())),
+        DataType::Int64 => Some((i64::MIN.into(), i64::MAX.into())),
+        DataType::UInt8 => Some((0, u8::MAX.into())),
+        DataType::UInt16 => Some((0, u16::MAX.into())),
+        DataType::UInt32 => Some((0, u32::MAX.into())),
+        DataType::UInt64 => Some((0, u64::MAX.into())),
+        _ => None,
+    }
+}
@@ -14,9 +14,14 @@ use super::utils::{check_dim_names, check_permutation, write_list, DebugRepr};
 /// See the [arrow docs][fixed-shape-tensor-docs] for details on the different
 /// fields.
 ///
-/// The Rust value must serialize to a fixed size list that contains the
-/// flattened tensor elements in C order. To support different orders, set the
-/// [`permutation`][FixedShapeTensorField::permutation].
+/// The Rust value can be given as nested sequences, e.g., `Vec<Vec<f32>>` or
+/// `[[f32; 3]; 2]`, whose lengths must match the shape. Alternatively, it can
+/// be given as a flat sequence that contains the tensor elements in storage
+/// order. When deserializing, tensors are rebuilt as nested sequences.
+///
+/// If a [`permutation`][FixedShapeTensorField::permutation] is set, nested
+/// sequences follow the logical layout: the `i`-th nesting level corresponds to
+/// the dimension `permutation[i]` of the `shape`.
 ///
 /// This struct is designed to be used with
 /// [`TracingOptions::overwrite`][crate::schema::TracingOptions::overwrite]:
@@ -162,7 +167,7 @@ fn fixed_shape_tensor_field_repr() -> crate::internal::error::PanicOnError<()> {
         vec![2, 3],
     )?;
     let field = Field::try_from(&field)?;
-    let actual = serde_json::to_value(&PrettyField(&field))?;
+    let actual = serde_json::to_value(PrettyField(&field))?;
     let expected = json!({
         "name": "hello",
         "data_type": "FixedSizeList(6)",
@@ -179,3 +184,22 @@ fn fixed_shape_tensor_field_repr() -> crate::internal::error::PanicOnError<()> {
     assert_eq!(actual, expected);
     Ok(())
 }
+
+#[test]
+fn fixed_shape_tensor_field_permutation() -> crate::internal::error::PanicOnError<()> {
+    use serde_json::json;
+
+    let field = FixedShapeTensorField::new(
+        "hello",
+        json!({"name": "element", "data_type": "F32"}),
+        vec![2, 3, 4],
+    )?;
+    assert!(field.clone().permutation(vec![1, 1, 0]).is_err());
+
+    let field = Field::try_from(&field.permutation(vec![2, 0, 1])?)?;
+    assert_eq!(
+        field.metadata["ARROW:extension:metadata"],
+        "{\"shape\":[2,3,4],\"permutation\":[2,0,1]}"
+    );
+    Ok(())
+}
@@ -0,0 +1,375 @@
+use std::collections::HashMap;
+
+use marrow::datatypes::{DataType, Field};
+
+use crate::internal::{
+    error::{Error, Result},
+    schema::PrettyField,
+};
+
+/// The coordinate layout shared by all GeoArrow fields
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Coordinates {
+    interleaved: bool,
+    z: bool,
+    m: bool,
+}
+
+impl Coordinates {
+    fn dimensions(&self) -> Vec<&'static str> {
+        let mut dimensions = vec!["x", "y"];
+        if self.z {
+            dimensions.push("z");
+        }
+        if self.m {
+            dimensions.push("m");
+        }
+        dimensions
+    }
+
+    fn field(&self, name: &str, nullable: bool) -> Result<Field> {
+        let dimensions = self.dimensions();
+        let data_type = if self.interleaved {
+            let element = Field {
+                name: dimensions.concat(),
+                data_type: DataType::Float64,
+                nullable: false,
+                metadata: HashMap::new(),
+            };
+            DataType::FixedSizeList(Box::new(element), dimensions.len().try_into()?)
+        } else {
+            DataType::Struct(
+                dimensions
+                    .into_iter()
+                    .map(|dim| Field {
+                        name: dim.to_owned(),
+                        data_type: DataType::Float64,
+                        nullable: false,
+                        metadata: HashMap::new(),
+                    })
+                    .collect(),
+            )
+        };
+
+        Ok(Field {
+            name: name.to_owned(),
+            data_type,
+            nullable,
+            metadata: HashMap::new(),
+        })
+    }
+}
+
+/// The dimensions of interleaved coordinates, given the name of the list element
+///
+/// Interleaved coordinates name their element after their dimensions, e.g., `xy` or `xyzm`.
+pub(crate) fn interleaved_dimensions(element: &str) -> Option<&'static [&'static str]> {
+    match element {
+        "xy" => Some(&["x", "y"]),
+        "xyz" => Some(&["x", "y", "z"]),
+        "xym" => Some(&["x", "y", "m"]),
+        "xyzm" => Some(&["x", "y", "z", "m"]),
+        _ => None,
+    }
+}
+
+/// Check whether the metadata marks a field with the `geoarrow.polygon` extension
+pub(crate) fn has_polygon_extension(metadata: &HashMap<String, String>) -> bool {
+    metadata
+        .get("ARROW:extension:name")
+        .is_some_and(|name| name == "geoarrow.polygon")
+}
+
+fn list_field(name: &str, nullable: bool, element: Field) -> Field {
+    Field {
+        name: name.to_owned(),
+        data_type: DataType::List(Box::new(element)),
+        nullable,
+        metadata: HashMap::new(),
+    }
+}
+
+fn with_extension(mut field: Field, extension: &str) -> Field {
+    field
+        .metadata
+        .insert("ARROW:extension:name".into(), extension.into());
+    field
+        .metadata
+        .insert("ARROW:extension:metadata".into(), "{}".into());
+    field
+}
+
+macro_rules! impl_geoarrow_field {
+    ($ty:ident) => {
+        impl $ty {
+            /// Set the nullability of the field
+            pub fn nullable(mut self, value: bool) -> Self {
+                self.nullable = value;
+                self
+            }
+
+            /// Use the interleaved encoding (`FixedSizeList`) instead of the separated encoding
+            /// (`Struct`) for coordinates
+            pub fn interleaved(mut self, value: bool) -> Self {
+                self.coordinates.interleaved = value;
+                self
+            }
+
+            /// Include a `z` dimension in the coordinates
+            pub fn with_z(mut self, value: bool) -> Self {
+                self.coordinates.z = value;
+                self
+            }
+
+            /// Include a `m` dimension in the coordinates
+            pub fn with_m(mut self, value: bool) -> Self {
+                self.coordinates.m = value;
+                self
+            }
+        }
+
+        impl serde::ser::Serialize for $ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::Error;
+                let field = Field::try_from(self).map_err(S::Error::custom)?;
+                PrettyField(&field).serialize(serializer)
+            }
+        }
+    };
+}
+
+/// A helper to construct GeoArrow point fields (`geoarrow.point`)
+///
+/// Coordinates are stored either as a struct with the fields `x`, `y` and optionally `z`, `m`
+/// (separated encoding, the default) or as a fixed size list of `Float64` values (interleaved
+/// encoding). In both cases, the Rust value can be a struct with the fields in the order of the
+/// dimensions, e.g., `struct Point { x: f64, y: f64 }`. For the interleaved encoding, sequences
+/// and tuples are supported as well.
+///
+/// This struct is designed to be used with
+/// [`TracingOptions::overwrite`][crate::schema::TracingOptions::overwrite]:
+///
+/// ```rust
+/// # use serde_arrow::{Result, schema::{SerdeArrowSchema, SchemaLike, TracingOptions, ext::PointField}};
+/// # use serde::Deserialize;
+/// # fn main() -> Result<()> {
+/// ##[derive(Deserialize)]
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// ##[derive(Deserialize)]
+/// struct Record {
+///     location: Point,
+/// }
+///
+/// let tracing_options = TracingOptions::default()
+///     .overwrite("location", PointField::new("location").interleaved(true))?;
+///
+/// let schema = SerdeArrowSchema::from_type::<Record>(tracing_options)?;
+/// # std::mem::drop(schema);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [GeoArrow specification][geoarrow-docs] for details.
+///
+/// [geoarrow-docs]: https://geoarrow.org/format.html
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointField {
+    name: String,
+    nullable: bool,
+    coordinates: Coordinates,
+}
+
+impl PointField {
+    /// Construct a new non-nullable `PointField` with separated `x`, `y` coordinates
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            nullable: false,
+            coordinates: Coordinates::default(),
+        }
+    }
+}
+
+impl_geoarrow_field!(PointField);
+
+impl TryFrom<&PointField> for Field {
+    type Error = Error;
+
+    fn try_from(value: &PointField) -> Result<Self> {
+        let field = value.coordinates.field(&value.name, value.nullable)?;
+        Ok(with_extension(field, "geoarrow.point"))
+    }
+}
+
+/// A helper to construct GeoArrow linestring fields (`geoarrow.linestring`)
+///
+/// Linestrings are stored as a list of coordinates (named `vertices`). The Rust value must
+/// serialize as a sequence of points, e.g., `struct LineString(Vec<Point>)`. See [`PointField`]
+/// for the supported coordinate encodings.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineStringField {
+    name: String,
+    nullable: bool,
+    coordinates: Coordinates,
+}
+
+impl LineStringField {
+    /// Construct a new non-nullable `LineStringField` with separated `x`, `y` coordinates
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            nullable: false,
+            coordinates: Coordinates::default(),
+        }
+    }
+}
+
+impl_geoarrow_field!(LineStringField);
+
+impl TryFrom<&LineStringField> for Field {
+    type Error = Error;
+
+    fn try_from(value: &LineStringField) -> Result<Self> {
+        let vertices = value.coordinates.field("vertices", false)?;
+        let field = list_field(&value.name, value.nullable, vertices);
+        Ok(with_extension(field, "geoarrow.linestring"))
+    }
+}
+
+/// A helper to construct GeoArrow polygon fields (`geoarrow.polygon`)
+///
+/// Polygons are stored as a list of rings (named `rings`), each a list of coordinates (named
+/// `vertices`). The first ring is the exterior ring, any further rings are interior rings. The
+/// Rust value must serialize either as a sequence of sequences of points, e.g., `struct
+/// Polygon(Vec<LineString>)`, or as a struct with the fields `exterior` and `interiors`, e.g.,
+/// `geo_types::Polygon`. See [`PointField`] for the supported coordinate encodings.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolygonField {
+    name: String,
+    nullable: bool,
+    coordinates: Coordinates,
+}
+
+impl PolygonField {
+    /// Construct a new non-nullable `PolygonField` with separated `x`, `y` coordinates
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            nullable: false,
+            coordinates: Coordinates::default(),
+        }
+    }
+}
+
+impl_geoarrow_field!(PolygonField);
+
+impl TryFrom<&PolygonField> for Field {
+    type Error = Error;
+
+    fn try_from(value: &PolygonField) -> Result<Self> {
+        let vertices = value.coordinates.field("vertices", false)?;
+        let rings = list_field("rings", false, vertices);
+        let field = list_field(&value.name, value.nullable, rings);
+        Ok(with_extension(field, "geoarrow.polygon"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    fn repr(field: impl serde::Serialize) -> serde_json::Value {
+        serde_json::to_value(field).unwrap()
+    }
+
+    #[test]
+    fn point_separated() {
+        let actual = repr(PointField::new("point").nullable(true));
+        let expected = json!({
+            "name": "point",
+            "data_type": "Struct",
+            "nullable": true,
+            "metadata": {
+                "ARROW:extension:name": "geoarrow.point",
+                "ARROW:extension:metadata": "{}",
+            },
+            "children": [
+                {"name": "x", "data_type": "F64"},
+                {"name": "y", "data_type": "F64"},
+            ],
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn point_interleaved_xyz() {
+        let actual = repr(PointField::new("point").interleaved(true).with_z(true));
+        let expected = json!({
+            "name": "point",
+            "data_type": "FixedSizeList(3)",
+            "metadata": {
+                "ARROW:extension:name": "geoarrow.point",
+                "ARROW:extension:metadata": "{}",
+            },
+            "children": [
+                {"name": "xyz", "data_type": "F64"},
+            ],
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn linestring_xym() {
+        let actual = repr(LineStringField::new("line").with_m(true));
+        let expected = json!({
+            "name": "line",
+            "data_type": "List",
+            "metadata": {
+                "ARROW:extension:name": "geoarrow.linestring",
+                "ARROW:extension:metadata": "{}",
+            },
+            "children": [{
+                "name": "vertices",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "x", "data_type": "F64"},
+                    {"name": "y", "data_type": "F64"},
+                    {"name": "m", "data_type": "F64"},
+                ],
+            }],
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn polygon_interleaved() {
+        let actual = repr(PolygonField::new("polygon").interleaved(true));
+        let expected = json!({
+            "name": "polygon",
+            "data_type": "List",
+            "metadata": {
+                "ARROW:extension:name": "geoarrow.polygon",
+                "ARROW:extension:metadata": "{}",
+            },
+            "children": [{
+                "name": "rings",
+                "data_type": "List",
+                "children": [{
+                    "name": "vertices",
+                    "data_type": "FixedSizeList(2)",
+                    "children": [{"name": "xy", "data_type": "F64"}],
+                }],
+            }],
+        });
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use marrow::datatypes::{DataType, Field};
+
+use crate::internal::{
+    error::{Error, Result},
+    schema::PrettyField,
+};
+
+/// A helper to construct new `Json` fields (`arrow.json`)
+///
+/// Values are stored as compact JSON text in `Utf8` or `LargeUtf8` arrays. Any value supported by
+/// serde can be stored, independent of its structure. When deserializing, the JSON text is replayed
+/// through serde. Requires the `serde_json` feature to serialize or deserialize values.
+///
+/// This extension type can be used with `overwrites` in schema tracing:
+///
+/// ```rust
+/// # use serde_arrow::{Result, schema::{SerdeArrowSchema, SchemaLike, TracingOptions, ext::JsonField}};
+/// # use serde::Deserialize;
+/// # fn main() -> Result<()> {
+/// ##[derive(Deserialize)]
+/// struct Record {
+///     attributes: serde_json::Value,
+///     value: i32,
+/// }
+///
+/// let tracing_options = TracingOptions::default()
+///     .overwrite("attributes", JsonField::new("attributes"))?;
+///
+/// let schema = SerdeArrowSchema::from_type::<Record>(tracing_options)?;
+/// # std::mem::drop(schema);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// It can also be converted to a `arrow` `Field` for manual schema manipulation.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonField {
+    name: String,
+    nullable: bool,
+    large: bool,
+}
+
+impl JsonField {
+    /// Construct a new non-nullable `JsonField` stored as `Utf8`
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            nullable: false,
+            large: false,
+        }
+    }
+
+    /// Set the nullability of the field
+    pub fn nullable(mut self, value: bool) -> Self {
+        self.nullable = value;
+        self
+    }
+
+    /// Store the JSON text as `LargeUtf8` instead of `Utf8`
+    pub fn large_utf8(mut self, value: bool) -> Self {
+        self.large = value;
+        self
+    }
+}
+
+impl TryFrom<&JsonField> for Field {
+    type Error = Error;
+
+    fn try_from(value: &JsonField) -> Result<Self> {
+        let mut metadata = HashMap::new();
+        metadata.insert("ARROW:extension:name".into(), "arrow.json".into());
+        metadata.insert("ARROW:extension:metadata".into(), String::new());
+
+        Ok(Field {
+            name: value.name.to_owned(),
+            nullable: value.nullable,
+            data_type: if value.large {
+                DataType::LargeUtf8
+            } else {
+                DataType::Utf8
+            },
+            metadata,
+        })
+    }
+}
+
+impl serde::ser::Serialize for JsonField {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::Error;
+        let field = Field::try_from(self).map_err(S::Error::custom)?;
+        PrettyField(&field).serialize(serializer)
+    }
+}
+
+#[test]
+fn json_repr() -> crate::internal::error::PanicOnError<()> {
+    use serde_json::json;
+
+    let field = JsonField::new("hello").large_utf8(true);
+
+    let field = Field::try_from(&field)?;
+    let actual = serde_json::to_value(PrettyField(&field))?;
+
+    let expected = json!({
+        "name": "hello",
+        "data_type": "LargeUtf8",
+        "metadata": {
+            "ARROW:extension:name": "arrow.json",
+            "ARROW:extension:metadata": "",
+        },
+    });
+
+    assert_eq!(actual, expected);
+    Ok(())
+}
@@ -1,15 +1,35 @@
 mod bool8_field;
 mod fixed_shape_tensor_field;
+mod geoarrow;
+mod json_field;
+mod registry;
 mod utils;
+mod uuid_field;
 mod variable_shape_tensor_field;
 
 pub use bool8_field::Bool8Field;
 pub use fixed_shape_tensor_field::FixedShapeTensorField;
+pub(crate) use geoarrow::{has_polygon_extension, interleaved_dimensions};
+pub use geoarrow::{LineStringField, PointField, PolygonField};
+pub use json_field::JsonField;
+pub use registry::{
+    from_value, to_value, ExtensionField, ExtensionRegistry, ExtensionType, ExtensionValue,
+};
+pub(crate) use utils::TensorMetadata;
+pub use uuid_field::UuidField;
+pub(crate) use uuid_field::{has_uuid_extension, is_uuid_field};
 pub use variable_shape_tensor_field::VariableShapeTensorField;
 
 const _: () = {
-    trait AssertSendSync: Send + Sync {}
-    impl AssertSendSync for Bool8Field {}
-    impl AssertSendSync for FixedShapeTensorField {}
-    impl AssertSendSync for VariableShapeTensorField {}
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Bool8Field>();
+    assert_send_sync::<ExtensionField>();
+    assert_send_sync::<ExtensionRegistry>();
+    assert_send_sync::<FixedShapeTensorField>();
+    assert_send_sync::<JsonField>();
+    assert_send_sync::<LineStringField>();
+    assert_send_sync::<PointField>();
+    assert_send_sync::<PolygonField>();
+    assert_send_sync::<UuidField>();
+    assert_send_sync::<VariableShapeTensorField>();
 };
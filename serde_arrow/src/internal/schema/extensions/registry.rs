@@ -0,0 +1,263 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use marrow::datatypes::{DataType, Field};
+
+use crate::internal::{
+    error::{ContextSupport, Error, Result},
+    schema::PrettyField,
+    utils::{
+        btree_map,
+        value::{Value, ValueDeserializer, ValueSerializer},
+    },
+};
+
+/// A user defined extension type
+///
+/// An extension type is identified by its name, stored in the `ARROW:extension:name` metadata
+/// entry of a field. It describes the storage field and the extension metadata
+/// (`ARROW:extension:metadata`). Optionally, it can transform the values written to or read from
+/// the storage array.
+///
+/// Extension types are used by registering them in an [`ExtensionRegistry`] and passing the
+/// registry to [`ArrayBuilder::new_with_extensions`][crate::ArrayBuilder::new_with_extensions] or
+/// [`Deserializer::from_marrow_with_extensions`][crate::Deserializer::from_marrow_with_extensions].
+/// When the builder or deserializer is constructed, all fields with the name of a registered
+/// extension type are checked with [`validate_field`][Self::validate_field]. Schemas on their own,
+/// e.g., [`SerdeArrowSchema`][crate::schema::SerdeArrowSchema], are not checked against the
+/// registry. If [`has_value_hooks`][Self::has_value_hooks] returns `true`, all values are passed
+/// through [`serialize_value`][Self::serialize_value] and
+/// [`deserialize_value`][Self::deserialize_value] as opaque [`ExtensionValue`]s.
+///
+/// ```rust
+/// # use serde_arrow::{Result, marrow::datatypes::{DataType, Field}, schema::ext::{ExtensionType, ExtensionValue}};
+/// /// Store temperatures in Kelvin, but expose them as Celsius
+/// #[derive(Debug)]
+/// struct Kelvin;
+///
+/// impl ExtensionType for Kelvin {
+///     fn name(&self) -> &str {
+///         "example.kelvin"
+///     }
+///
+///     fn storage_field(&self, name: &str) -> Result<Field> {
+///         Ok(Field {
+///             name: name.to_owned(),
+///             data_type: DataType::Float64,
+///             ..Field::default()
+///         })
+///     }
+///
+///     fn has_value_hooks(&self) -> bool {
+///         true
+///     }
+///
+///     fn serialize_value(&self, value: ExtensionValue) -> Result<ExtensionValue> {
+///         let celsius: f64 = serde_arrow::schema::ext::from_value(&value)?;
+///         serde_arrow::schema::ext::to_value(celsius + 273.15)
+///     }
+///
+///     fn deserialize_value(&self, value: ExtensionValue) -> Result<ExtensionValue> {
+///         let kelvin: f64 = serde_arrow::schema::ext::from_value(&value)?;
+///         serde_arrow::schema::ext::to_value(kelvin - 273.15)
+///     }
+/// }
+/// ```
+pub trait ExtensionType: std::fmt::Debug + Send + Sync {
+    /// The name of the extension type, as stored in `ARROW:extension:name`
+    fn name(&self) -> &str;
+
+    /// The storage field of the extension type with the given name
+    ///
+    /// The returned field should not contain the extension metadata, it is added by
+    /// [`ExtensionField`].
+    fn storage_field(&self, name: &str) -> Result<Field>;
+
+    /// The extension metadata, as stored in `ARROW:extension:metadata`
+    fn metadata(&self) -> Result<String> {
+        Ok(String::new())
+    }
+
+    /// Check that a field with the name of this extension type is valid
+    fn validate_field(&self, field: &Field) -> Result<()> {
+        let _ = field;
+        Ok(())
+    }
+
+    /// Whether values are passed through [`serialize_value`][Self::serialize_value] and
+    /// [`deserialize_value`][Self::deserialize_value]
+    fn has_value_hooks(&self) -> bool {
+        false
+    }
+
+    /// Transform a value before it is written to the storage array
+    fn serialize_value(&self, value: ExtensionValue) -> Result<ExtensionValue> {
+        Ok(value)
+    }
+
+    /// Transform a value read from the storage array
+    fn deserialize_value(&self, value: ExtensionValue) -> Result<ExtensionValue> {
+        Ok(value)
+    }
+}
+
+/// A value passed through the hooks of an [`ExtensionType`]
+///
+/// The representation is opaque. Use [`to_value`] and [`from_value`] to convert from and into
+/// Rust values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtensionValue(pub(crate) Value);
+
+/// Convert a Rust value into an [`ExtensionValue`], e.g., inside
+/// [`ExtensionType::serialize_value`]
+pub fn to_value<T: serde::Serialize>(value: T) -> Result<ExtensionValue> {
+    Ok(ExtensionValue(value.serialize(ValueSerializer)?))
+}
+
+/// Convert an [`ExtensionValue`] into a Rust value, e.g., inside
+/// [`ExtensionType::deserialize_value`]
+pub fn from_value<T: serde::de::DeserializeOwned>(value: &ExtensionValue) -> Result<T> {
+    T::deserialize(ValueDeserializer::new(&value.0))
+}
+
+/// A collection of user defined extension types, identified by their names
+///
+/// ```rust
+/// # use serde_arrow::{Result, marrow::datatypes::{DataType, Field}, schema::ext::{ExtensionType, ExtensionRegistry}};
+/// # #[derive(Debug)]
+/// # struct Kelvin;
+/// # impl ExtensionType for Kelvin {
+/// #     fn name(&self) -> &str { "example.kelvin" }
+/// #     fn storage_field(&self, name: &str) -> Result<Field> {
+/// #         Ok(Field { name: name.to_owned(), data_type: DataType::Float64, ..Field::default() })
+/// #     }
+/// # }
+/// let registry = ExtensionRegistry::new().register(Kelvin);
+/// assert!(registry.get("example.kelvin").is_some());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ExtensionRegistry(BTreeMap<String, Arc<dyn ExtensionType>>);
+
+impl ExtensionRegistry {
+    /// Construct an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an extension type, replacing any extension type with the same name
+    pub fn register<E: ExtensionType + 'static>(mut self, extension: E) -> Self {
+        self.0
+            .insert(extension.name().to_owned(), Arc::new(extension));
+        self
+    }
+
+    /// Get the extension type registered with the given name
+    pub fn get(&self, name: &str) -> Option<&Arc<dyn ExtensionType>> {
+        self.0.get(name)
+    }
+
+    pub(crate) fn get_for_metadata(
+        &self,
+        metadata: &HashMap<String, String>,
+    ) -> Option<&Arc<dyn ExtensionType>> {
+        if self.0.is_empty() {
+            return None;
+        }
+        self.get(metadata.get("ARROW:extension:name")?)
+    }
+
+    /// Validate all fields of a registered extension type, including nested fields
+    pub(crate) fn validate_fields(&self, fields: &[Field]) -> Result<()> {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        for field in fields {
+            self.validate_field(field)?;
+        }
+        Ok(())
+    }
+
+    fn validate_field(&self, field: &Field) -> Result<()> {
+        if let Some(extension) = self.get_for_metadata(&field.metadata) {
+            extension
+                .validate_field(field)
+                .ctx(&btree_map!("field" => field.name.clone(), "extension" => extension.name().to_owned()))?;
+        }
+
+        use DataType as T;
+        match &field.data_type {
+            T::List(child)
+            | T::LargeList(child)
+            | T::FixedSizeList(child, _)
+            | T::Map(child, _) => self.validate_field(child),
+            T::RunEndEncoded(_, values) => self.validate_field(values),
+            T::Struct(children) => self.validate_fields(children),
+            T::Union(children, _) => {
+                for (_, child) in children {
+                    self.validate_field(child)?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A helper to construct fields of user defined extension types
+///
+/// It combines the [storage field][ExtensionType::storage_field] with the extension metadata and
+/// is designed to be used with
+/// [`TracingOptions::overwrite`][crate::schema::TracingOptions::overwrite].
+///
+#[derive(Clone, Debug)]
+pub struct ExtensionField {
+    name: String,
+    nullable: bool,
+    extension: Arc<dyn ExtensionType>,
+}
+
+impl ExtensionField {
+    /// Construct a new non-nullable field of the given extension type
+    pub fn new<E: ExtensionType + 'static>(name: &str, extension: E) -> Self {
+        Self {
+            name: name.to_owned(),
+            nullable: false,
+            extension: Arc::new(extension),
+        }
+    }
+
+    /// Set the nullability of the field
+    pub fn nullable(mut self, value: bool) -> Self {
+        self.nullable = value;
+        self
+    }
+}
+
+impl TryFrom<&ExtensionField> for Field {
+    type Error = Error;
+
+    fn try_from(value: &ExtensionField) -> Result<Self> {
+        let mut field = value.extension.storage_field(&value.name)?;
+        field.name = value.name.clone();
+        field.nullable = value.nullable;
+        field.metadata.insert(
+            "ARROW:extension:name".into(),
+            value.extension.name().to_owned(),
+        );
+        field.metadata.insert(
+            "ARROW:extension:metadata".into(),
+            value.extension.metadata()?,
+        );
+        Ok(field)
+    }
+}
+
+impl serde::ser::Serialize for ExtensionField {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::Error;
+        let field = Field::try_from(self).map_err(S::Error::custom)?;
+        PrettyField(&field).serialize(serializer)
+    }
+}
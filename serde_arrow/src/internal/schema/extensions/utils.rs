@@ -1,5 +1,12 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
 use crate::internal::error::{fail, Result};
 
+#[cfg(any(test, not(feature = "serde_json")))]
+use crate::internal::utils::value::{HashF64, Value};
+
 pub fn check_dim_names(ndim: usize, dim_names: &[String]) -> Result<()> {
     if dim_names.len() != ndim {
         fail!("Number of dim names must be equal to the number of dimensions");
@@ -11,7 +18,7 @@ pub fn check_permutation(ndim: usize, permutation: &[usize]) -> Result<()> {
     if permutation.len() != ndim {
         fail!("Number of permutation entries must be equal to the number of dimensions");
     }
-    let seen = vec![false; permutation.len()];
+    let mut seen = vec![false; permutation.len()];
     for &i in permutation {
         if i >= seen.len() {
             fail!(
@@ -22,6 +29,7 @@ pub fn check_permutation(ndim: usize, permutation: &[usize]) -> Result<()> {
         if seen[i] {
             fail!("Invalid permutation: index {i} found multiple times");
         }
+        seen[i] = true;
     }
     for (i, seen) in seen.into_iter().enumerate() {
         if !seen {
@@ -56,3 +64,369 @@ impl<T: std::fmt::Debug> std::fmt::Display for DebugRepr<T> {
         write!(f, "{:?}", self.0)
     }
 }
+
+/// The extension metadata of the tensor extension types
+///
+/// Both `arrow.fixed_shape_tensor` and `arrow.variable_shape_tensor` use a subset of these keys.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+pub struct TensorMetadata {
+    pub shape: Option<Vec<usize>>,
+    pub permutation: Option<Vec<usize>>,
+    pub dim_names: Option<Vec<String>>,
+    pub uniform_shape: Option<Vec<Option<usize>>>,
+}
+
+impl TensorMetadata {
+    /// Read the tensor metadata of a field with the given extension name
+    ///
+    /// Returns `None`, if the field is not of the given extension type. Keys not used by the
+    /// tensor extension types are ignored, whatever their values.
+    pub fn from_field_metadata(
+        metadata: &HashMap<String, String>,
+        extension: &str,
+    ) -> Result<Option<Self>> {
+        if metadata.get("ARROW:extension:name").map(String::as_str) != Some(extension) {
+            return Ok(None);
+        }
+        let res = match metadata.get("ARROW:extension:metadata") {
+            Some(text) if !text.trim().is_empty() => Self::from_json(text)?,
+            _ => Self::default(),
+        };
+        if let Some(permutation) = res.permutation.as_ref() {
+            check_permutation(permutation.len(), permutation)?;
+        }
+        Ok(Some(res))
+    }
+
+    #[cfg(feature = "serde_json")]
+    fn from_json(text: &str) -> Result<Self> {
+        Ok(serde_json::from_str(text)?)
+    }
+
+    #[cfg(not(feature = "serde_json"))]
+    fn from_json(text: &str) -> Result<Self> {
+        use crate::internal::utils::value::ValueDeserializer;
+
+        Self::deserialize(ValueDeserializer::new(&parse_json_metadata(text)?))
+    }
+}
+
+/// Parse the JSON text of extension metadata into a [`Value`]
+///
+/// Used to read the tensor metadata without the `serde_json` feature. Integers are parsed as
+/// `U64` or `I64` values, all other numbers as `F64` values.
+#[cfg(any(test, not(feature = "serde_json")))]
+pub fn parse_json_metadata(text: &str) -> Result<Value> {
+    let mut parser = MetadataParser {
+        text: text.as_bytes(),
+        pos: 0,
+    };
+    let value = parser.parse_value()?;
+    if parser.peek().is_some() {
+        fail!(
+            "Invalid extension metadata: trailing content after position {pos}",
+            pos = parser.pos
+        );
+    }
+    Ok(value)
+}
+
+#[cfg(any(test, not(feature = "serde_json")))]
+struct MetadataParser<'a> {
+    text: &'a [u8],
+    pos: usize,
+}
+
+#[cfg(any(test, not(feature = "serde_json")))]
+impl MetadataParser<'_> {
+    fn peek(&mut self) -> Option<u8> {
+        while matches!(self.text.get(self.pos), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+        self.text.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<()> {
+        if self.peek() != Some(expected) {
+            fail!(
+                "Invalid extension metadata: expected {expected:?} at position {pos}",
+                expected = expected as char,
+                pos = self.pos,
+            );
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn expect_keyword(&mut self, keyword: &str, value: Value) -> Result<Value> {
+        if !self.text[self.pos..].starts_with(keyword.as_bytes()) {
+            fail!(
+                "Invalid extension metadata: unexpected token at position {pos}",
+                pos = self.pos
+            );
+        }
+        self.pos += keyword.len();
+        Ok(value)
+    }
+
+    /// Parse a comma separated list of items terminated by `close`
+    fn parse_items(
+        &mut self,
+        close: u8,
+        mut item: impl FnMut(&mut Self) -> Result<()>,
+    ) -> Result<()> {
+        if self.peek() == Some(close) {
+            self.pos += 1;
+            return Ok(());
+        }
+        loop {
+            item(self)?;
+            if self.peek() != Some(b',') {
+                return self.expect(close);
+            }
+            self.pos += 1;
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value> {
+        match self.peek() {
+            Some(b'{') => self.parse_object(),
+            Some(b'[') => self.parse_list(),
+            Some(b'"') => Ok(Value::String(self.parse_string()?)),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(b't') => self.expect_keyword("true", Value::Bool(true)),
+            Some(b'f') => self.expect_keyword("false", Value::Bool(false)),
+            Some(b'n') => self.expect_keyword("null", Value::None),
+            Some(_) => fail!(
+                "Invalid extension metadata: unexpected token at position {pos}",
+                pos = self.pos
+            ),
+            None => fail!("Invalid extension metadata: unexpected end of input"),
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Value> {
+        self.expect(b'{')?;
+        let mut entries = Vec::new();
+        self.parse_items(b'}', |parser| {
+            let key = parser.parse_string()?;
+            parser.expect(b':')?;
+            let value = parser.parse_value()?;
+            entries.push((Value::String(key), value));
+            Ok(())
+        })?;
+        Ok(Value::Map(entries))
+    }
+
+    fn parse_list(&mut self) -> Result<Value> {
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+        self.parse_items(b']', |parser| {
+            items.push(parser.parse_value()?);
+            Ok(())
+        })?;
+        Ok(Value::Seq(items))
+    }
+
+    fn parse_number(&mut self) -> Result<Value> {
+        let start = self.pos;
+        while matches!(
+            self.text.get(self.pos),
+            Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')
+        ) {
+            self.pos += 1;
+        }
+        let text = std::str::from_utf8(&self.text[start..self.pos])?;
+        if let Ok(value) = text.parse::<u64>() {
+            Ok(Value::U64(value))
+        } else if let Ok(value) = text.parse::<i64>() {
+            Ok(Value::I64(value))
+        } else if let Ok(value) = text.parse::<f64>() {
+            Ok(Value::F64(HashF64(value)))
+        } else {
+            fail!("Invalid extension metadata: invalid number {text:?}")
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String> {
+        self.expect(b'"')?;
+        let mut res = String::new();
+        let mut chars = std::str::from_utf8(&self.text[self.pos..])?.char_indices();
+        while let Some((offset, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += offset + 1;
+                    return Ok(res);
+                }
+                '\\' => match chars.next() {
+                    Some((_, c @ ('"' | '\\' | '/'))) => res.push(c),
+                    Some((_, 'b')) => res.push('\u{8}'),
+                    Some((_, 'f')) => res.push('\u{c}'),
+                    Some((_, 'n')) => res.push('\n'),
+                    Some((_, 'r')) => res.push('\r'),
+                    Some((_, 't')) => res.push('\t'),
+                    Some((_, 'u')) => {
+                        let mut code = parse_hex4(&mut chars)?;
+                        if (0xd800..0xdc00).contains(&code) {
+                            let (Some((_, '\\')), Some((_, 'u'))) = (chars.next(), chars.next())
+                            else {
+                                fail!("Invalid extension metadata: unpaired surrogate in string");
+                            };
+                            let low = parse_hex4(&mut chars)?;
+                            if !(0xdc00..0xe000).contains(&low) {
+                                fail!("Invalid extension metadata: unpaired surrogate in string");
+                            }
+                            code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                        }
+                        let Some(c) = char::from_u32(code) else {
+                            fail!("Invalid extension metadata: invalid unicode escape in string");
+                        };
+                        res.push(c);
+                    }
+                    _ => fail!(
+                        "Invalid extension metadata: invalid escape sequence in string at position {pos}",
+                        pos = self.pos + offset,
+                    ),
+                },
+                c => res.push(c),
+            }
+        }
+        fail!("Invalid extension metadata: unterminated string")
+    }
+}
+
+#[cfg(any(test, not(feature = "serde_json")))]
+fn parse_hex4(chars: &mut std::str::CharIndices<'_>) -> Result<u32> {
+    let mut code = 0;
+    for _ in 0..4 {
+        let Some(digit) = chars.next().and_then(|(_, c)| c.to_digit(16)) else {
+            fail!("Invalid extension metadata: invalid unicode escape in string");
+        };
+        code = code * 16 + digit;
+    }
+    Ok(code)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn valid_permutations() {
+        assert!(check_permutation(3, &[0, 1, 2]).is_ok());
+        assert!(check_permutation(3, &[2, 0, 1]).is_ok());
+        assert!(check_permutation(3, &[2, 0, 0]).is_err());
+        assert!(check_permutation(3, &[0, 1, 3]).is_err());
+        assert!(check_permutation(3, &[0, 1]).is_err());
+    }
+
+    #[test]
+    fn tensor_metadata() {
+        let metadata = HashMap::from([
+            (
+                String::from("ARROW:extension:name"),
+                String::from("arrow.variable_shape_tensor"),
+            ),
+            (
+                String::from("ARROW:extension:metadata"),
+                String::from(
+                    r#"{ "permutation": [1, 0], "dim_names": ["x", "y\"z"], "uniform_shape": [null, 3] }"#,
+                ),
+            ),
+        ]);
+
+        let actual =
+            TensorMetadata::from_field_metadata(&metadata, "arrow.variable_shape_tensor").unwrap();
+        let expected = TensorMetadata {
+            shape: None,
+            permutation: Some(vec![1, 0]),
+            dim_names: Some(vec![String::from("x"), String::from("y\"z")]),
+            uniform_shape: Some(vec![None, Some(3)]),
+        };
+        assert_eq!(actual, Some(expected));
+
+        let actual =
+            TensorMetadata::from_field_metadata(&metadata, "arrow.fixed_shape_tensor").unwrap();
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn unrelated_keys_are_ignored() {
+        let metadata = HashMap::from([
+            (
+                String::from("ARROW:extension:name"),
+                String::from("arrow.fixed_shape_tensor"),
+            ),
+            (
+                String::from("ARROW:extension:metadata"),
+                String::from(
+                    r#"{"shape": [2, 3], "dim_names": ["\u00e4", "\ud83d\ude00"], "producer": {"version": -1.5e3, "tags": [true, false, null]}}"#,
+                ),
+            ),
+        ]);
+
+        let actual =
+            TensorMetadata::from_field_metadata(&metadata, "arrow.fixed_shape_tensor").unwrap();
+        let expected = TensorMetadata {
+            shape: Some(vec![2, 3]),
+            permutation: None,
+            dim_names: Some(vec![String::from("\u{e4}"), String::from("\u{1f600}")]),
+            uniform_shape: None,
+        };
+        assert_eq!(actual, Some(expected));
+    }
+
+    #[test]
+    fn invalid_tensor_metadata() {
+        let metadata = HashMap::from([
+            (
+                String::from("ARROW:extension:name"),
+                String::from("arrow.fixed_shape_tensor"),
+            ),
+            (
+                String::from("ARROW:extension:metadata"),
+                String::from(r#"{"shape": [-1, 3]}"#),
+            ),
+        ]);
+        assert!(
+            TensorMetadata::from_field_metadata(&metadata, "arrow.fixed_shape_tensor").is_err()
+        );
+    }
+
+    #[test]
+    fn json_metadata() {
+        let actual = parse_json_metadata(
+            r#"{"a": [1, -2, 0.5], "b": {"c": "\u00e4\ud83d\ude00"}, "d": true}"#,
+        )
+        .unwrap();
+        let expected = Value::Map(vec![
+            (
+                Value::String(String::from("a")),
+                Value::Seq(vec![
+                    Value::U64(1),
+                    Value::I64(-2),
+                    Value::F64(HashF64(0.5)),
+                ]),
+            ),
+            (
+                Value::String(String::from("b")),
+                Value::Map(vec![(
+                    Value::String(String::from("c")),
+                    Value::String(String::from("\u{e4}\u{1f600}")),
+                )]),
+            ),
+            (Value::String(String::from("d")), Value::Bool(true)),
+        ]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn invalid_json_metadata() {
+        assert!(parse_json_metadata(r#"{"shape": [1, 2}"#).is_err());
+        assert!(parse_json_metadata(r#"{"shape": [1, 2]} x"#).is_err());
+        assert!(parse_json_metadata(r#"{"shape: [1, 2]}"#).is_err());
+        assert!(parse_json_metadata(r#"{"shape": [nul]}"#).is_err());
+        assert!(parse_json_metadata(r#"{"dim_names": ["\ud83d"]}"#).is_err());
+        assert!(parse_json_metadata(r#"{"dim_names": ["\x"]}"#).is_err());
+    }
+}
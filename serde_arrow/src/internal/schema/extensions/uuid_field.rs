@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use marrow::datatypes::{DataType, Field};
+
+use crate::internal::{
+    error::{Error, Result},
+    schema::PrettyField,
+};
+
+/// A helper to construct new `Uuid` fields (`arrow.uuid`)
+///
+/// UUIDs are stored as `FixedSizeBinary(16)`. Values can be serialized either as hyphenated
+/// strings or as 16 raw bytes. When deserializing, the canonical hyphenated string or the raw
+/// bytes are returned, depending on the requested type. This allows to use `uuid::Uuid` directly.
+///
+/// This extension type can be used with `overwrites` in schema tracing:
+///
+/// ```rust
+/// # use serde_arrow::{Result, schema::{SerdeArrowSchema, SchemaLike, TracingOptions, ext::UuidField}};
+/// # use serde::Deserialize;
+/// # use uuid::Uuid;
+/// # fn main() -> Result<()> {
+/// ##[derive(Deserialize)]
+/// struct Record {
+///     id: Uuid,
+///     value: i32,
+/// }
+///
+/// let tracing_options = TracingOptions::default().overwrite("id", UuidField::new("id"))?;
+///
+/// let schema = SerdeArrowSchema::from_type::<Record>(tracing_options)?;
+/// # std::mem::drop(schema);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// It can also be converted to a `arrow` `Field` for manual schema manipulation.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct UuidField {
+    name: String,
+    nullable: bool,
+}
+
+impl UuidField {
+    /// Construct a new non-nullable `UuidField`
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            nullable: false,
+        }
+    }
+
+    /// Set the nullability of the field
+    pub fn nullable(mut self, value: bool) -> Self {
+        self.nullable = value;
+        self
+    }
+}
+
+impl TryFrom<&UuidField> for Field {
+    type Error = Error;
+
+    fn try_from(value: &UuidField) -> Result<Self> {
+        let mut metadata = HashMap::new();
+        metadata.insert("ARROW:extension:name".into(), "arrow.uuid".into());
+        metadata.insert("ARROW:extension:metadata".into(), String::new());
+
+        Ok(Field {
+            name: value.name.to_owned(),
+            nullable: value.nullable,
+            data_type: DataType::FixedSizeBinary(16),
+            metadata,
+        })
+    }
+}
+
+impl serde::ser::Serialize for UuidField {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::Error;
+        let field = Field::try_from(self).map_err(S::Error::custom)?;
+        PrettyField(&field).serialize(serializer)
+    }
+}
+
+/// Check whether the field is a UUID field, i.e., whether it has the `arrow.uuid` extension
+pub(crate) fn is_uuid_field(field: &Field) -> bool {
+    matches!(field.data_type, DataType::FixedSizeBinary(16)) && has_uuid_extension(&field.metadata)
+}
+
+/// Check whether the metadata marks a field with the `arrow.uuid` extension
+pub(crate) fn has_uuid_extension(metadata: &HashMap<String, String>) -> bool {
+    metadata
+        .get("ARROW:extension:name")
+        .is_some_and(|name| name == "arrow.uuid")
+}
+
+#[test]
+fn uuid_repr() -> crate::internal::error::PanicOnError<()> {
+    use serde_json::json;
+
+    let field = UuidField::new("hello").nullable(true);
+
+    let field = Field::try_from(&field)?;
+    let actual = serde_json::to_value(PrettyField(&field))?;
+
+    let expected = json!({
+        "name": "hello",
+        "data_type": "FixedSizeBinary(16)",
+        "nullable": true,
+        "metadata": {
+            "ARROW:extension:name": "arrow.uuid",
+            "ARROW:extension:metadata": "",
+        },
+    });
+
+    assert_eq!(actual, expected);
+    Ok(())
+}
@@ -14,6 +14,37 @@ use super::utils::{check_dim_names, check_permutation, write_list, DebugRepr};
 /// See the [arrow docs][variable-shape-tensor-field-docs] for details on the
 /// different fields.
 ///
+/// The Rust value can be given as nested sequences, e.g., `Vec<Vec<f32>>`, or
+/// as a struct with the fields `data` and `shape`. For nested sequences, the
+/// shape of each tensor is computed from the sequence lengths. In the struct
+/// form, `data` can also be a flat sequence in storage order and `shape` is the
+/// physical shape. If a [`uniform_shape`][VariableShapeTensorField::uniform_shape]
+/// is set, the sizes of the fixed dimensions are checked for each tensor. When
+/// deserializing, tensors are rebuilt as nested sequences or, for structs, as
+/// flat data with the shape.
+///
+/// As for [`FixedShapeTensorField`][super::FixedShapeTensorField], nested
+/// sequences follow the logical layout defined by the
+/// [`permutation`][VariableShapeTensorField::permutation].
+///
+/// ```rust
+/// # use serde_json::json;
+/// # use serde_arrow::{Result, schema::{TracingOptions, ext::VariableShapeTensorField}};
+/// # fn main() -> Result<()> {
+/// TracingOptions::default().overwrite(
+///     "tensor",
+///     VariableShapeTensorField::new(
+///         "tensor",
+///         json!({"name": "element", "data_type": "F32"}),
+///         2,
+///     )?
+///     .uniform_shape(vec![Some(3), None])?,
+/// )?
+/// # ;
+/// # Ok(())
+/// # }
+/// ```
+///
 /// [variable-shape-tensor-field-docs]:
 ///     https://arrow.apache.org/docs/format/CanonicalExtensions.html#variable-shape-tensor
 pub struct VariableShapeTensorField {
@@ -31,7 +62,7 @@ impl VariableShapeTensorField {
     pub fn new(name: &str, element: impl serde::ser::Serialize, ndim: usize) -> Result<Self> {
         let element = transmute_field(element)?;
         if element.name != "element" {
-            fail!("The element field of FixedShapeTensorField must be named \"element\"");
+            fail!("The element field of VariableShapeTensorField must be named \"element\"");
         }
 
         Ok(Self {
@@ -85,28 +116,28 @@ impl VariableShapeTensorField {
         write!(&mut ext_metadata, "{{")?;
 
         if let Some(permutation) = self.permutation.as_ref() {
-            if first_field {
-                first_field = false;
+            if !first_field {
                 write!(&mut ext_metadata, ",")?;
             }
+            first_field = false;
             write!(&mut ext_metadata, "\"permutation\":")?;
             write_list(&mut ext_metadata, permutation.iter())?;
         }
 
         if let Some(dim_names) = self.dim_names.as_ref() {
-            if first_field {
-                first_field = false;
+            if !first_field {
                 write!(&mut ext_metadata, ",")?;
             }
+            first_field = false;
             write!(&mut ext_metadata, "\"dim_names\":")?;
             write_list(&mut ext_metadata, dim_names.iter().map(DebugRepr))?;
         }
 
         if let Some(uniform_shape) = self.uniform_shape.as_ref() {
-            if first_field {
-                first_field = false;
+            if !first_field {
                 write!(&mut ext_metadata, ",")?;
             }
+            first_field = false;
             write!(&mut ext_metadata, "\"uniform_shape\":")?;
             write_list(
                 &mut ext_metadata,
@@ -208,3 +239,37 @@ fn test_serialization() -> crate::internal::error::PanicOnError<()> {
     assert_eq!(actual, expected);
     Ok(())
 }
+
+#[test]
+fn test_ext_metadata_with_multiple_keys() -> crate::internal::error::PanicOnError<()> {
+    use serde_json::json;
+
+    let field =
+        VariableShapeTensorField::new("foo", json!({"name": "element", "data_type": "Bool"}), 2)?
+            .permutation(vec![1, 0])?
+            .uniform_shape(vec![None, Some(3)])?;
+
+    assert_eq!(
+        field.get_ext_metadata()?,
+        r#"{"permutation":[1,0],"uniform_shape":[null,3]}"#,
+    );
+    Ok(())
+}
+
+#[test]
+fn test_permutation() -> crate::internal::error::PanicOnError<()> {
+    use serde_json::json;
+
+    let field = VariableShapeTensorField::new(
+        "foo bar",
+        json!({"name": "element", "data_type": "Bool"}),
+        2,
+    )?
+    .permutation(vec![1, 0])?;
+    let field = Field::try_from(&field)?;
+    assert_eq!(
+        field.metadata["ARROW:extension:metadata"],
+        "{\"permutation\":[1,0]}"
+    );
+    Ok(())
+}
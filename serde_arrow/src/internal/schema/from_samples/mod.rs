@@ -4,13 +4,17 @@ mod test_error_messages;
 
 use std::{collections::BTreeMap, sync::Arc};
 
-use marrow::datatypes::{DataType, TimeUnit};
+use marrow::datatypes::DataType;
 use serde::{ser::Impossible, Serialize};
 
 use crate::internal::{
-    chrono,
     error::{fail, try_, Context, ContextSupport, Error, Result},
-    schema::{TracingMode, TracingOptions},
+    schema::{StringGuess, TracingMode, TracingOptions},
+    utils::{
+        integral_float_value,
+        std_time::StdTimeType,
+        value::{Value, ValueSerializer},
+    },
 };
 
 use super::tracer::{
@@ -20,8 +24,13 @@ use super::tracer::{
 impl Tracer {
     pub fn from_samples<T: Serialize>(samples: T, options: TracingOptions) -> Result<Self> {
         let options = options.tracing_mode(TracingMode::FromSamples);
-        let mut tracer = Tracer::new(String::from("$"), String::from("$"), Arc::new(options));
-        samples.serialize(OuterSequenceSerializer(&mut tracer))?;
+        let mut tracer = Tracer::new(
+            String::from("$"),
+            String::from("$"),
+            Arc::new(options),
+            false,
+        );
+        trace_samples(&mut tracer, &samples)?;
         tracer.finish()?;
         tracer.check()?;
 
@@ -29,7 +38,26 @@ impl Tracer {
     }
 }
 
-struct OuterSequenceSerializer<'a>(&'a mut Tracer);
+/// A target for the individual samples of a sequence of samples
+pub trait SampleSink: Context {
+    fn trace_sample<T: Serialize + ?Sized>(&mut self, sample: &T) -> Result<()>;
+}
+
+impl SampleSink for Tracer {
+    fn trace_sample<T: Serialize + ?Sized>(&mut self, sample: &T) -> Result<()> {
+        sample.serialize(TracerSerializer(self))
+    }
+}
+
+/// Pass each sample of a sequence to the sink
+pub fn trace_samples<S: SampleSink, T: Serialize + ?Sized>(
+    sink: &mut S,
+    samples: &T,
+) -> Result<()> {
+    samples.serialize(OuterSequenceSerializer(sink))
+}
+
+struct OuterSequenceSerializer<'a, S>(&'a mut S);
 
 mod impl_outer_sequence_serializer {
     use super::*;
@@ -40,13 +68,13 @@ mod impl_outer_sequence_serializer {
         };
     }
 
-    impl Context for OuterSequenceSerializer<'_> {
+    impl<S: SampleSink> Context for OuterSequenceSerializer<'_, S> {
         fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
             self.0.annotate(annotations)
         }
     }
 
-    impl serde::ser::Serializer for OuterSequenceSerializer<'_> {
+    impl<S: SampleSink> serde::ser::Serializer for OuterSequenceSerializer<'_, S> {
         type Ok = ();
         type Error = Error;
 
@@ -203,12 +231,12 @@ mod impl_outer_sequence_serializer {
     }
 }
 
-impl serde::ser::SerializeSeq for OuterSequenceSerializer<'_> {
+impl<S: SampleSink> serde::ser::SerializeSeq for OuterSequenceSerializer<'_, S> {
     type Ok = ();
     type Error = Error;
 
     fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
-        try_(|| value.serialize(TracerSerializer(&mut *self.0))).ctx(self)
+        try_(|| self.0.trace_sample(value)).ctx(self)
     }
 
     fn end(self) -> Result<Self::Ok> {
@@ -216,12 +244,12 @@ impl serde::ser::SerializeSeq for OuterSequenceSerializer<'_> {
     }
 }
 
-impl serde::ser::SerializeTuple for OuterSequenceSerializer<'_> {
+impl<S: SampleSink> serde::ser::SerializeTuple for OuterSequenceSerializer<'_, S> {
     type Ok = ();
     type Error = Error;
 
     fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
-        try_(|| value.serialize(TracerSerializer(&mut *self.0))).ctx(self)
+        try_(|| self.0.trace_sample(value)).ctx(self)
     }
 
     fn end(self) -> Result<Self::Ok> {
@@ -229,12 +257,12 @@ impl serde::ser::SerializeTuple for OuterSequenceSerializer<'_> {
     }
 }
 
-impl serde::ser::SerializeTupleVariant for OuterSequenceSerializer<'_> {
+impl<S: SampleSink> serde::ser::SerializeTupleVariant for OuterSequenceSerializer<'_, S> {
     type Ok = ();
     type Error = Error;
 
     fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
-        try_(|| value.serialize(TracerSerializer(&mut *self.0))).ctx(self)
+        try_(|| self.0.trace_sample(value)).ctx(self)
     }
 
     fn end(self) -> Result<Self::Ok> {
@@ -242,6 +270,16 @@ impl serde::ser::SerializeTupleVariant for OuterSequenceSerializer<'_> {
     }
 }
 
+/// Trace a value of a child tracer
+///
+/// Fields stored as JSON text are not traced, as their structure is not reflected in the schema.
+fn trace_value<T: Serialize + ?Sized>(tracer: &mut Tracer, value: &T) -> Result<()> {
+    if tracer.is_in_json_field() {
+        return tracer.ensure_primitive(DataType::Null);
+    }
+    value.serialize(TracerSerializer(tracer))
+}
+
 struct TracerSerializer<'a>(&'a mut Tracer);
 
 impl Context for TracerSerializer<'_> {
@@ -253,9 +291,11 @@ impl Context for TracerSerializer<'_> {
 impl<'a> TracerSerializer<'a> {
     fn ensure_union_variant(
         self,
+        name: &str,
         variant_name: &str,
         variant_index: u32,
     ) -> Result<&'a mut UnionVariant> {
+        self.0.apply_type_overwrite(name);
         self.0.ensure_union(&[])?;
         let Tracer::Union(tracer) = self.0 else {
             unreachable!();
@@ -273,7 +313,7 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
     type Ok = ();
     type Error = Error;
 
-    type SerializeStruct = StructSerializer<'a>;
+    type SerializeStruct = StructOrStdTimeSerializer<'a>;
     type SerializeMap = MapSerializer<'a>;
     type SerializeSeq = ListSerializer<'a>;
     type SerializeTuple = TupleSerializer<'a>;
@@ -285,44 +325,68 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
         try_(|| self.0.ensure_primitive(DataType::Boolean)).ctx(&self)
     }
 
-    fn serialize_i8(self, _: i8) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::Int8)).ctx(&self)
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_number(DataType::Int8, Some(i128::from(v)))).ctx(&self)
     }
 
-    fn serialize_i16(self, _: i16) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::Int16)).ctx(&self)
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_number(DataType::Int16, Some(i128::from(v)))).ctx(&self)
     }
 
-    fn serialize_i32(self, _: i32) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::Int32)).ctx(&self)
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_number(DataType::Int32, Some(i128::from(v)))).ctx(&self)
     }
 
-    fn serialize_i64(self, _: i64) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::Int64)).ctx(&self)
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_number(DataType::Int64, Some(i128::from(v)))).ctx(&self)
     }
 
-    fn serialize_u8(self, _: u8) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::UInt8)).ctx(&self)
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_number(DataType::UInt8, Some(i128::from(v)))).ctx(&self)
     }
 
-    fn serialize_u16(self, _: u16) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::UInt16)).ctx(&self)
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_number(DataType::UInt16, Some(i128::from(v)))).ctx(&self)
     }
 
-    fn serialize_u32(self, _: u32) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::UInt32)).ctx(&self)
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_number(DataType::UInt32, Some(i128::from(v)))).ctx(&self)
     }
 
-    fn serialize_u64(self, _: u64) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::UInt64)).ctx(&self)
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok> {
+        try_(|| self.0.ensure_number(DataType::UInt64, Some(i128::from(v)))).ctx(&self)
     }
 
-    fn serialize_f32(self, _: f32) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::Float32)).ctx(&self)
+    fn serialize_i128(self, _: i128) -> Result<Self::Ok> {
+        try_(|| {
+            let ty = self.0.get_options().int128_type();
+            self.0.ensure_primitive(ty)
+        })
+        .ctx(&self)
     }
 
-    fn serialize_f64(self, _: f64) -> Result<Self::Ok> {
-        try_(|| self.0.ensure_number(DataType::Float64)).ctx(&self)
+    fn serialize_u128(self, _: u128) -> Result<Self::Ok> {
+        try_(|| {
+            let ty = self.0.get_options().int128_type();
+            self.0.ensure_primitive(ty)
+        })
+        .ctx(&self)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok> {
+        try_(|| {
+            self.0
+                .ensure_number(DataType::Float32, integral_float_value(f64::from(v)))
+        })
+        .ctx(&self)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok> {
+        try_(|| {
+            self.0
+                .ensure_number(DataType::Float64, integral_float_value(v))
+        })
+        .ctx(&self)
     }
 
     fn serialize_char(self, _: char) -> Result<Self::Ok> {
@@ -335,26 +399,8 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
 
     fn serialize_str(self, s: &str) -> Result<Self::Ok> {
         try_(|| {
-            #[allow(clippy::collapsible_else_if)]
-            let (ty, st) = if !self.0.get_options().guess_dates {
-                (self.0.get_options().string_type(), None)
-            } else {
-                if chrono::matches_naive_datetime(s) {
-                    (DataType::Timestamp(TimeUnit::Millisecond, None), None)
-                } else if chrono::matches_utc_datetime(s) {
-                    (
-                        DataType::Timestamp(TimeUnit::Millisecond, Some(String::from("UTC"))),
-                        None,
-                    )
-                } else if chrono::matches_naive_time(s) {
-                    (DataType::Time64(TimeUnit::Nanosecond), None)
-                } else if chrono::matches_naive_date(s) {
-                    (DataType::Date32, None)
-                } else {
-                    (self.0.get_options().string_type(), None)
-                }
-            };
-            self.0.ensure_primitive_with_strategy(ty, st)
+            let guess = StringGuess::guess(s, self.0.get_options());
+            self.0.ensure_string_guess(guess)
         })
         .ctx(&self)
     }
@@ -379,16 +425,24 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
         .ctx(&self)
     }
 
-    fn serialize_unit_struct(self, _: &'static str) -> Result<Self::Ok> {
-        try_(|| TracerSerializer(&mut *self.0).serialize_unit()).ctx(&self)
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok> {
+        try_(|| {
+            self.0.apply_type_overwrite(name);
+            TracerSerializer(&mut *self.0).serialize_unit()
+        })
+        .ctx(&self)
     }
 
     fn serialize_newtype_struct<T: Serialize + ?Sized>(
         self,
-        _: &'static str,
+        name: &'static str,
         value: &T,
     ) -> Result<Self::Ok> {
-        try_(|| value.serialize(TracerSerializer(&mut *self.0))).ctx(&self)
+        try_(|| {
+            self.0.apply_type_overwrite(name);
+            trace_value(self.0, value)
+        })
+        .ctx(&self)
     }
 
     fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap> {
@@ -427,16 +481,25 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
         .ctx(&ctx)
     }
 
-    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeStruct> {
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
         let mut ctx = BTreeMap::new();
         self.annotate(&mut ctx);
 
         try_(move || {
+            self.0.apply_type_overwrite(name);
+            if let Some(ty) = StdTimeType::from_struct_len(name, len) {
+                return Ok(StructOrStdTimeSerializer::StdTime(
+                    self.0,
+                    ty,
+                    Vec::with_capacity(len),
+                ));
+            }
+
             self.0.ensure_struct::<&str>(&[], StructMode::Struct)?;
             let Tracer::Struct(tracer) = self.0 else {
                 unreachable!();
             };
-            Ok(StructSerializer(tracer))
+            Ok(StructOrStdTimeSerializer::Struct(StructSerializer(tracer)))
         })
         .ctx(&ctx)
     }
@@ -457,13 +520,14 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
 
     fn serialize_tuple_struct(
         self,
-        _: &'static str,
+        name: &'static str,
         len: usize,
     ) -> Result<Self::SerializeTupleStruct> {
         let mut ctx = BTreeMap::new();
         self.annotate(&mut ctx);
 
         try_(move || {
+            self.0.apply_type_overwrite(name);
             self.0.ensure_tuple(len)?;
             let Tracer::Tuple(tracer) = self.0 else {
                 unreachable!();
@@ -475,7 +539,7 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
 
     fn serialize_unit_variant(
         self,
-        _: &'static str,
+        name: &'static str,
         variant_index: u32,
         variant_name: &'static str,
     ) -> Result<Self::Ok> {
@@ -483,7 +547,7 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
         self.annotate(&mut ctx);
 
         try_(|| {
-            let variant = self.ensure_union_variant(variant_name, variant_index)?;
+            let variant = self.ensure_union_variant(name, variant_name, variant_index)?;
             variant.tracer.ensure_primitive(DataType::Null)
         })
         .ctx(&ctx)
@@ -491,7 +555,7 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
 
     fn serialize_newtype_variant<T: Serialize + ?Sized>(
         self,
-        _: &'static str,
+        name: &'static str,
         variant_index: u32,
         variant_name: &'static str,
         value: &T,
@@ -500,15 +564,15 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
         self.annotate(&mut ctx);
 
         try_(|| {
-            let variant = self.ensure_union_variant(variant_name, variant_index)?;
-            value.serialize(TracerSerializer(&mut variant.tracer))
+            let variant = self.ensure_union_variant(name, variant_name, variant_index)?;
+            trace_value(&mut variant.tracer, value)
         })
         .ctx(&ctx)
     }
 
     fn serialize_struct_variant(
         self,
-        _: &'static str,
+        name: &'static str,
         variant_index: u32,
         variant_name: &'static str,
         _: usize,
@@ -517,7 +581,7 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
         self.annotate(&mut ctx);
 
         try_(|| {
-            let variant = self.ensure_union_variant(variant_name, variant_index)?;
+            let variant = self.ensure_union_variant(name, variant_name, variant_index)?;
             variant
                 .tracer
                 .ensure_struct::<&str>(&[], StructMode::Struct)?;
@@ -531,7 +595,7 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
 
     fn serialize_tuple_variant(
         self,
-        _: &'static str,
+        name: &'static str,
         variant_index: u32,
         variant_name: &'static str,
         len: usize,
@@ -540,7 +604,7 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
         self.annotate(&mut ctx);
 
         try_(|| {
-            let variant = self.ensure_union_variant(variant_name, variant_index)?;
+            let variant = self.ensure_union_variant(name, variant_name, variant_index)?;
             variant.tracer.ensure_tuple(len)?;
             let Tracer::Tuple(tracer) = &mut variant.tracer else {
                 unreachable!();
@@ -573,7 +637,7 @@ impl serde::ser::SerializeStruct for StructSerializer<'_> {
             let Some(field_tracer) = self.0.get_field_tracer_mut(field_idx) else {
                 unreachable!();
             };
-            value.serialize(TracerSerializer(field_tracer))
+            trace_value(field_tracer, value)
         })
         .ctx(self)
     }
@@ -597,7 +661,7 @@ impl serde::ser::SerializeStructVariant for StructSerializer<'_> {
             let Some(field_tracer) = self.0.get_field_tracer_mut(field_idx) else {
                 unreachable!();
             };
-            value.serialize(TracerSerializer(field_tracer))
+            trace_value(field_tracer, value)
         })
         .ctx(self)
     }
@@ -607,6 +671,71 @@ impl serde::ser::SerializeStructVariant for StructSerializer<'_> {
     }
 }
 
+/// Serialize structs, recognizing `std::time::Duration` and `std::time::SystemTime`
+///
+/// Potential std time values are recognized by their name and number of fields. Their fields are
+/// collected, and if they do not match the expected layout, they are traced as a struct.
+enum StructOrStdTimeSerializer<'a> {
+    Struct(StructSerializer<'a>),
+    StdTime(&'a mut Tracer, StdTimeType, Vec<(&'static str, Value)>),
+}
+
+impl Context for StructOrStdTimeSerializer<'_> {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        match self {
+            Self::Struct(serializer) => serializer.annotate(annotations),
+            Self::StdTime(tracer, _, _) => tracer.annotate(annotations),
+        }
+    }
+}
+
+impl serde::ser::SerializeStruct for StructOrStdTimeSerializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        match self {
+            Self::Struct(serializer) => serializer.serialize_field(key, value),
+            Self::StdTime(_, _, fields) => {
+                fields.push((key, value.serialize(ValueSerializer)?));
+                Ok(())
+            }
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        let (tracer, ty, fields) = match self {
+            Self::Struct(serializer) => return serializer.end(),
+            Self::StdTime(tracer, ty, fields) => (tracer, ty, fields),
+        };
+
+        if fields
+            .iter()
+            .map(|(key, _)| *key)
+            .eq(ty.fields().iter().copied())
+        {
+            return try_(|| tracer.ensure_primitive(ty.data_type())).ctx(tracer);
+        }
+
+        try_(|| {
+            tracer.ensure_struct::<&str>(&[], StructMode::Struct)?;
+            let Tracer::Struct(tracer) = &mut *tracer else {
+                unreachable!();
+            };
+            let mut serializer = StructSerializer(tracer);
+            for (key, value) in &fields {
+                serde::ser::SerializeStruct::serialize_field(&mut serializer, key, value)?;
+            }
+            serde::ser::SerializeStruct::end(serializer)
+        })
+        .ctx(tracer)
+    }
+}
+
 struct ListSerializer<'a>(&'a mut ListTracer);
 
 impl Context for ListSerializer<'_> {
@@ -620,7 +749,7 @@ impl serde::ser::SerializeSeq for ListSerializer<'_> {
     type Error = Error;
 
     fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
-        try_(|| value.serialize(TracerSerializer(&mut self.0.item_tracer))).ctx(self)
+        try_(|| trace_value(&mut self.0.item_tracer, value)).ctx(self)
     }
 
     fn end(self) -> Result<Self::Ok> {
@@ -649,7 +778,7 @@ impl serde::ser::SerializeTuple for TupleSerializer<'_> {
     fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
         try_(|| {
             let pos = self.1;
-            value.serialize(TracerSerializer(self.0.field_tracer(pos)))?;
+            trace_value(self.0.field_tracer(pos), value)?;
             self.1 += 1;
             Ok(())
         })
@@ -668,7 +797,7 @@ impl serde::ser::SerializeTupleStruct for TupleSerializer<'_> {
     fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
         try_(|| {
             let pos = self.1;
-            value.serialize(TracerSerializer(self.0.field_tracer(pos)))?;
+            trace_value(self.0.field_tracer(pos), value)?;
             self.1 += 1;
             Ok(())
         })
@@ -687,7 +816,7 @@ impl serde::ser::SerializeTupleVariant for TupleSerializer<'_> {
     fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> where {
         try_(|| {
             let pos = self.1;
-            value.serialize(TracerSerializer(self.0.field_tracer(pos)))?;
+            trace_value(self.0.field_tracer(pos), value)?;
             self.1 += 1;
             Ok(())
         })
@@ -723,7 +852,7 @@ impl serde::ser::SerializeMap for MapSerializer<'_> {
                 *next_key = Some(key.serialize(SerializeToString)?);
                 Ok(())
             }
-            Self::AsMap(tracer) => key.serialize(TracerSerializer(&mut tracer.key_tracer)),
+            Self::AsMap(tracer) => trace_value(&mut tracer.key_tracer, key),
         })
         .ctx(self)
     }
@@ -738,9 +867,9 @@ impl serde::ser::SerializeMap for MapSerializer<'_> {
                 let Some(field_tracer) = tracer.get_field_tracer_mut(field_idx) else {
                     unreachable!();
                 };
-                value.serialize(TracerSerializer(field_tracer))
+                trace_value(field_tracer, value)
             }
-            Self::AsMap(tracer) => value.serialize(TracerSerializer(&mut tracer.value_tracer)),
+            Self::AsMap(tracer) => trace_value(&mut tracer.value_tracer, value),
         })
         .ctx(self)
     }
@@ -13,7 +13,11 @@ use marrow::datatypes::DataType;
 
 use crate::internal::{
     error::{fail, try_, Context, ContextSupport, Error, Result},
-    schema::{TracingMode, TracingOptions},
+    schema::{extensions::is_uuid_field, TracingMode, TracingOptions},
+    utils::{
+        std_time::{StdTimeAccess, StdTimeParts, StdTimeType},
+        time_tuple,
+    },
 };
 
 use super::tracer::{StructField, StructMode, Tracer};
@@ -21,7 +25,12 @@ use super::tracer::{StructField, StructMode, Tracer};
 impl Tracer {
     pub fn from_type<'de, T: Deserialize<'de>>(options: TracingOptions) -> Result<Self> {
         let options = options.tracing_mode(TracingMode::FromType);
-        let mut tracer = Tracer::new(String::from("$"), String::from("$"), Arc::new(options));
+        let mut tracer = Tracer::new(
+            String::from("$"),
+            String::from("$"),
+            Arc::new(options),
+            false,
+        );
 
         let mut budget = tracer.get_options().from_type_budget;
         while !tracer.is_complete() {
@@ -86,10 +95,40 @@ impl Context for TraceAny<'_> {
     }
 }
 
+impl TraceAny<'_> {
+    /// The placeholder for string values
+    ///
+    /// Overwritten UUID and date time fields require valid UUIDs and date time strings,
+    /// respectively.
+    fn default_str(&self) -> &'static str {
+        let Some(overwrite) = self.0.get_overwrite() else {
+            return "";
+        };
+        if is_uuid_field(overwrite) {
+            return "00000000-0000-0000-0000-000000000000";
+        }
+        match &overwrite.data_type {
+            DataType::Timestamp(_, None) => "1970-01-01T00:00:00",
+            DataType::Timestamp(_, Some(_)) => "1970-01-01T00:00:00Z",
+            DataType::Date32 | DataType::Date64 => "1970-01-01",
+            DataType::Time32(_) | DataType::Time64(_) => "00:00:00",
+            _ => "",
+        }
+    }
+}
+
 impl<'de> serde::de::Deserializer<'de> for TraceAny<'_> {
     type Error = Error;
 
-    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.0.is_in_json_field() {
+            // the structure of fields stored as JSON text is not relevant for the schema
+            return try_(|| {
+                self.0.ensure_primitive(DataType::Null)?;
+                visitor.visit_unit()
+            })
+            .ctx(&self);
+        }
         fail!(
             in self,
             concat!(
@@ -140,6 +179,15 @@ impl<'de> serde::de::Deserializer<'de> for TraceAny<'_> {
         .ctx(&self)
     }
 
+    fn deserialize_i128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            let ty = self.0.get_options().int128_type();
+            self.0.ensure_primitive(ty)?;
+            visitor.visit_i128(Default::default())
+        })
+        .ctx(&self)
+    }
+
     fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         try_(|| {
             self.0.ensure_primitive(DataType::UInt8)?;
@@ -172,6 +220,15 @@ impl<'de> serde::de::Deserializer<'de> for TraceAny<'_> {
         .ctx(&self)
     }
 
+    fn deserialize_u128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            let ty = self.0.get_options().int128_type();
+            self.0.ensure_primitive(ty)?;
+            visitor.visit_u128(Default::default())
+        })
+        .ctx(&self)
+    }
+
     fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         try_(|| {
             self.0.ensure_primitive(DataType::Float32)?;
@@ -200,7 +257,7 @@ impl<'de> serde::de::Deserializer<'de> for TraceAny<'_> {
         try_(|| {
             self.0
                 .ensure_utf8(self.0.get_options().string_type(), None)?;
-            visitor.visit_borrowed_str("")
+            visitor.visit_borrowed_str(self.default_str())
         })
         .ctx(&self)
     }
@@ -209,7 +266,7 @@ impl<'de> serde::de::Deserializer<'de> for TraceAny<'_> {
         try_(|| {
             self.0
                 .ensure_utf8(self.0.get_options().string_type(), None)?;
-            visitor.visit_string(Default::default())
+            visitor.visit_string(self.default_str().to_owned())
         })
         .ctx(&self)
     }
@@ -248,10 +305,11 @@ impl<'de> serde::de::Deserializer<'de> for TraceAny<'_> {
 
     fn deserialize_unit_struct<V: Visitor<'de>>(
         self,
-        _name: &'static str,
+        name: &'static str,
         visitor: V,
     ) -> Result<V::Value> {
         try_(|| {
+            self.0.apply_type_overwrite(name);
             self.0.ensure_primitive(DataType::Null)?;
             visitor.visit_unit()
         })
@@ -260,10 +318,14 @@ impl<'de> serde::de::Deserializer<'de> for TraceAny<'_> {
 
     fn deserialize_newtype_struct<V: Visitor<'de>>(
         self,
-        _name: &'static str,
+        name: &'static str,
         visitor: V,
     ) -> Result<V::Value> {
-        try_(|| visitor.visit_newtype_struct(TraceAny(&mut *self.0))).ctx(&self)
+        try_(|| {
+            self.0.apply_type_overwrite(name);
+            visitor.visit_newtype_struct(TraceAny(&mut *self.0))
+        })
+        .ctx(&self)
     }
 
     fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
@@ -280,6 +342,14 @@ impl<'de> serde::de::Deserializer<'de> for TraceAny<'_> {
 
     fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
         try_(|| {
+            // date time types of the time crate are serialized as tuples of their components
+            if let Some(data_type) = self.0.get_overwrite().map(|f| f.data_type.clone()) {
+                if let Some(placeholder) = time_tuple::placeholder(&data_type, len) {
+                    self.0.ensure_primitive(data_type)?;
+                    return time_tuple::visit_tuple(visitor, placeholder);
+                }
+            }
+
             self.0.ensure_tuple(len)?;
             let Tracer::Tuple(tracer) = self.0 else {
                 unreachable!();
@@ -295,16 +365,20 @@ impl<'de> serde::de::Deserializer<'de> for TraceAny<'_> {
 
     fn deserialize_tuple_struct<V: Visitor<'de>>(
         self,
-        _name: &'static str,
+        name: &'static str,
         len: usize,
         visitor: V,
     ) -> Result<V::Value> {
-        try_(|| TraceAny(&mut *self.0).deserialize_tuple(len, visitor)).ctx(&self)
+        try_(|| {
+            self.0.apply_type_overwrite(name);
+            TraceAny(&mut *self.0).deserialize_tuple(len, visitor)
+        })
+        .ctx(&self)
     }
 
     fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         try_(|| {
-            if self.0.get_options().map_as_struct {
+            if self.0.get_options().map_as_struct && !self.0.is_in_json_field() {
                 fail!(concat!(
                     "Cannot trace maps as structs with `from_type`. ",
                     "The struct fields cannot be known from the type alone.",
@@ -327,11 +401,17 @@ impl<'de> serde::de::Deserializer<'de> for TraceAny<'_> {
 
     fn deserialize_struct<V: Visitor<'de>>(
         self,
-        _name: &'static str,
+        name: &'static str,
         fields: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value> {
         try_(|| {
+            self.0.apply_type_overwrite(name);
+            if let Some(ty) = StdTimeType::from_struct_fields(name, fields) {
+                self.0.ensure_primitive(ty.data_type())?;
+                return visitor.visit_map(StdTimeAccess::new(ty, StdTimeParts::default()));
+            }
+
             self.0.ensure_struct(fields, StructMode::Struct)?;
             let Tracer::Struct(tracer) = self.0 else {
                 unreachable!()
@@ -348,11 +428,12 @@ impl<'de> serde::de::Deserializer<'de> for TraceAny<'_> {
 
     fn deserialize_enum<V: Visitor<'de>>(
         self,
-        _name: &'static str,
+        name: &'static str,
         variants: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value> {
         try_(|| {
+            self.0.apply_type_overwrite(name);
             self.0.ensure_union(variants)?;
             let Tracer::Union(tracer) = self.0 else {
                 unreachable!();
@@ -0,0 +1,262 @@
+use std::collections::{BTreeMap, HashMap};
+
+use marrow::datatypes::{DataType, Field};
+
+use crate::internal::{
+    error::{fail, Result},
+    schema::{
+        get_strategy_from_metadata, DataTypeDisplay, SerdeArrowSchema, TracingOptions, STRATEGY_KEY,
+    },
+    utils::{btree_map, child_path},
+};
+
+use super::tracer::{
+    is_numeric, try_coerce_primitive_type, IntegerRange, OptionalStrategyDisplay, MAX_KEY, MIN_KEY,
+};
+
+impl SerdeArrowSchema {
+    /// Merge two schemas into a schema that can hold the data of both
+    ///
+    /// Fields are matched by name. Fields that are only present in one of the schemas are marked
+    /// nullable, the children of structs are merged recursively. Primitive types are coerced
+    /// with the same rules as in schema tracing, e.g., different numeric types are only widened
+    /// with [`coerce_numbers`][TracingOptions::coerce_numbers]. Incompatible types result in an
+    /// error that names the path of the field. Integer fields narrowed with
+    /// [`narrow_integers`][TracingOptions::narrow_integers] on both sides are narrowed to the
+    /// combined range.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_json::json;
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema, TracingOptions};
+    ///
+    /// let left = SerdeArrowSchema::from_value(&json!([
+    ///     {"name": "a", "data_type": "I32"},
+    ///     {"name": "b", "data_type": "Utf8"},
+    /// ]))?;
+    /// let right = SerdeArrowSchema::from_value(&json!([
+    ///     {"name": "a", "data_type": "I64", "nullable": true},
+    /// ]))?;
+    ///
+    /// let merged = left.merge(&right, TracingOptions::default().coerce_numbers(true))?;
+    /// assert_eq!(
+    ///     merged,
+    ///     SerdeArrowSchema::from_value(&json!([
+    ///         {"name": "a", "data_type": "I64", "nullable": true},
+    ///         {"name": "b", "data_type": "Utf8", "nullable": true},
+    ///     ]))?,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn merge(&self, other: &SerdeArrowSchema, options: TracingOptions) -> Result<Self> {
+        Ok(Self {
+            fields: merge_children("$", &self.fields, &other.fields, &options)?,
+        })
+    }
+}
+
+fn merge_children(
+    path: &str,
+    prev: &[Field],
+    curr: &[Field],
+    options: &TracingOptions,
+) -> Result<Vec<Field>> {
+    let mut fields = Vec::new();
+    for prev_field in prev {
+        match curr.iter().find(|field| field.name == prev_field.name) {
+            Some(curr_field) => fields.push(merge_field(
+                &child_path(path, &prev_field.name),
+                prev_field,
+                curr_field,
+                options,
+            )?),
+            None => fields.push(nullable_field(prev_field)),
+        }
+    }
+    for curr_field in curr {
+        if !prev.iter().any(|field| field.name == curr_field.name) {
+            fields.push(nullable_field(curr_field));
+        }
+    }
+    Ok(fields)
+}
+
+fn merge_field(path: &str, prev: &Field, curr: &Field, options: &TracingOptions) -> Result<Field> {
+    use DataType as D;
+
+    let ctx = btree_map!("field" => path.to_owned());
+    let nullable = prev.nullable || curr.nullable;
+
+    let data_type = match (&prev.data_type, &curr.data_type) {
+        (D::Null, D::Null) => D::Null,
+        (D::Null, _) => return Ok(nullable_field(curr)),
+        (_, D::Null) => return Ok(nullable_field(prev)),
+        (D::Struct(prev_children), D::Struct(curr_children)) => {
+            check_metadata(prev, curr, &ctx)?;
+            D::Struct(merge_children(path, prev_children, curr_children, options)?)
+        }
+        (D::List(prev_item), D::List(curr_item)) => {
+            check_metadata(prev, curr, &ctx)?;
+            D::List(Box::new(merge_item(path, prev_item, curr_item, options)?))
+        }
+        (
+            D::List(prev_item) | D::LargeList(prev_item),
+            D::List(curr_item) | D::LargeList(curr_item),
+        ) => {
+            check_metadata(prev, curr, &ctx)?;
+            D::LargeList(Box::new(merge_item(path, prev_item, curr_item, options)?))
+        }
+        (D::FixedSizeList(prev_item, prev_n), D::FixedSizeList(curr_item, curr_n))
+            if prev_n == curr_n =>
+        {
+            check_metadata(prev, curr, &ctx)?;
+            D::FixedSizeList(
+                Box::new(merge_item(path, prev_item, curr_item, options)?),
+                *prev_n,
+            )
+        }
+        (D::Map(prev_entries, prev_sorted), D::Map(curr_entries, curr_sorted))
+            if prev_sorted == curr_sorted =>
+        {
+            check_metadata(prev, curr, &ctx)?;
+            D::Map(
+                Box::new(merge_item(path, prev_entries, curr_entries, options)?),
+                *prev_sorted,
+            )
+        }
+        (prev_ty, curr_ty) if is_nested(prev_ty) || is_nested(curr_ty) => {
+            if prev_ty != curr_ty {
+                fail!(
+                    in ctx,
+                    "Cannot merge incompatible types {prev_ty} and {curr_ty}",
+                    prev_ty = DataTypeDisplay(prev_ty),
+                    curr_ty = DataTypeDisplay(curr_ty),
+                );
+            }
+            check_metadata(prev, curr, &ctx)?;
+            prev_ty.clone()
+        }
+        (prev_ty, curr_ty) => {
+            check_metadata(prev, curr, &ctx)?;
+
+            if let (Some(prev_range), Some(curr_range)) = (
+                IntegerRange::from_metadata(&prev.metadata)?,
+                IntegerRange::from_metadata(&curr.metadata)?,
+            ) {
+                let range = prev_range.union(curr_range);
+                if let Some(data_type) = range.data_type() {
+                    let mut metadata = prev.metadata.clone();
+                    range.insert_into(&mut metadata);
+                    return Ok(Field {
+                        name: prev.name.clone(),
+                        data_type,
+                        nullable,
+                        metadata,
+                    });
+                }
+            }
+
+            let prev_strategy = get_strategy_from_metadata(&prev.metadata)?;
+            let curr_strategy = get_strategy_from_metadata(&curr.metadata)?;
+            let Some((data_type, _, strategy)) = try_coerce_primitive_type(
+                (prev_ty, nullable, prev_strategy.as_ref()),
+                (curr_ty.clone(), curr_strategy.clone()),
+                options,
+            ) else {
+                let extra = if is_numeric(prev_ty) && is_numeric(curr_ty) {
+                    ": consider setting `coerce_numbers` to `true` to coerce different numeric types"
+                } else {
+                    ""
+                };
+                fail!(
+                    in ctx,
+                    "Cannot merge incompatible types {prev_ty}{prev_st} and {curr_ty}{curr_st}{extra}",
+                    prev_ty = DataTypeDisplay(prev_ty),
+                    curr_ty = DataTypeDisplay(curr_ty),
+                    prev_st = OptionalStrategyDisplay(prev_strategy.as_ref()),
+                    curr_st = OptionalStrategyDisplay(curr_strategy.as_ref()),
+                );
+            };
+
+            let mut metadata = prev.metadata.clone();
+            metadata.remove(STRATEGY_KEY);
+            // the range is only known, if both fields were narrowed
+            metadata.remove(MIN_KEY);
+            metadata.remove(MAX_KEY);
+            if let Some(strategy) = strategy {
+                metadata.insert(STRATEGY_KEY.to_string(), strategy.to_string());
+            }
+            return Ok(Field {
+                name: prev.name.clone(),
+                data_type,
+                nullable,
+                metadata,
+            });
+        }
+    };
+
+    Ok(Field {
+        name: prev.name.clone(),
+        data_type,
+        nullable,
+        metadata: prev.metadata.clone(),
+    })
+}
+
+/// Merge the item fields of lists or the entries of maps
+fn merge_item(path: &str, prev: &Field, curr: &Field, options: &TracingOptions) -> Result<Field> {
+    if prev.name != curr.name {
+        fail!(
+            in btree_map!("field" => path.to_owned()),
+            "Cannot merge item fields with different names {prev:?} and {curr:?}",
+            prev = prev.name,
+            curr = curr.name,
+        );
+    }
+    merge_field(&child_path(path, &prev.name), prev, curr, options)
+}
+
+/// Check that the metadata of both fields agrees, ignoring the strategy and the observed range
+/// of primitive fields
+fn check_metadata(prev: &Field, curr: &Field, ctx: &BTreeMap<String, String>) -> Result<()> {
+    let without_strategy = |metadata: &HashMap<String, String>| {
+        let mut metadata = metadata.clone();
+        if !is_nested(&prev.data_type) {
+            metadata.remove(STRATEGY_KEY);
+            metadata.remove(MIN_KEY);
+            metadata.remove(MAX_KEY);
+        }
+        metadata
+    };
+    if without_strategy(&prev.metadata) != without_strategy(&curr.metadata) {
+        fail!(
+            in ctx,
+            "Cannot merge fields with different metadata {prev:?} and {curr:?}",
+            prev = prev.metadata,
+            curr = curr.metadata,
+        );
+    }
+    Ok(())
+}
+
+fn nullable_field(field: &Field) -> Field {
+    Field {
+        nullable: true,
+        ..field.clone()
+    }
+}
+
+fn is_nested(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Struct(_)
+            | DataType::List(_)
+            | DataType::LargeList(_)
+            | DataType::FixedSizeList(_, _)
+            | DataType::Map(_, _)
+            | DataType::Union(_, _)
+            | DataType::Dictionary(_, _)
+            | DataType::RunEndEncoded(_, _)
+    )
+}
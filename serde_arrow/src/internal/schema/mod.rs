@@ -1,14 +1,20 @@
+mod diff;
 pub mod extensions;
 mod from_samples;
 mod from_type;
+mod merge;
+mod schema_tracer;
 mod serde;
 mod strategy;
+mod string_guess;
 pub mod tracer;
 mod tracing_options;
 
 #[cfg(test)]
 mod test;
 
+use std::collections::{HashMap, HashSet};
+
 use crate::internal::{
     error::{fail, Result},
     utils::value,
@@ -17,7 +23,10 @@ use crate::internal::{
 use ::serde::{Deserialize, Serialize};
 
 pub use self::serde::serialize::PrettyField;
-pub use strategy::{get_strategy_from_metadata, Strategy, STRATEGY_KEY};
+pub use diff::{Compatibility, FieldChange, FieldChangeKind, SchemaDiff};
+pub use schema_tracer::SchemaTracer;
+pub use strategy::{get_strategy_from_metadata, Strategy, NULL_VARIANT_NAME, STRATEGY_KEY};
+pub(crate) use string_guess::{StringGuess, DATETIME_PATTERN_KEY};
 use tracer::Tracer;
 pub use tracing_options::{Overwrites, TracingMode, TracingOptions};
 
@@ -112,19 +121,27 @@ pub trait SchemaLike: Sized + Sealed {
     /// - date objects: `"Date32"`, `"Date64"`
     /// - date time objects: `"Timestamp(unit, optional_timezone)"` with `unit` being one of
     ///   `Second`, `Millisecond`, `Microsecond`, `Nanosecond` and `optional_timezone` being either
-    ///   `None` or `Some("Utc")`.
+    ///   `None`, `Some("Utc")`, a fixed offset such as `Some("+05:30")` or, with the `chrono-tz`
+    ///   feature, an IANA time zone such as `Some("Europe/Berlin")`.
     /// - time objects: `"Time32(unit)"`, `"Time64(unit)"` with unit being one of `Second`,
     ///   `Millisecond`, `Microsecond`, `Nanosecond`.
     /// - durations: `"Duration(unit)"` with unit being one of `Second`, `Millisecond`,
     ///   `Microsecond`, `Nanosecond`.
+    /// - intervals: `"Interval(unit)"` with unit being one of `YearMonth`, `DayTime`,
+    ///   `MonthDayNano`.
     /// - lists: `"List"`, `"LargeList"`. `"children"` must contain a single field named `"element"`
     ///   that describes the element type
     /// - structs: `"Struct"`. `"children"` must contain the child fields
     /// - maps: `"Map"`. `"children"` must contain two fields, named `"key"` and `"value"` that
     ///   encode the key and value types
-    /// - unions: `"Union"`. `"children"` must contain the different variants
+    /// - unions: `"Union"` for dense unions or `"Union(mode)"` with mode being one of `Dense`,
+    ///   `Sparse`. `"children"` must contain the different variants. By default, the type ids
+    ///   `0, 1, ...` are used. Other type ids can be given after the mode, e.g.,
+    ///   `"Union(Dense, 0, 2, 5)"`
     /// - dictionaries: `"Dictionary"`. `"children"` must contain two different fields, named
-    ///   `"key"` of integer type and named `"value"` of string type
+    ///   `"key"` of integer type and named `"value"` of any other non-dictionary type
+    /// - run end encoded arrays: `"RunEndEncoded"`. `"children"` must contain two fields, the
+    ///   non-nullable run ends of type `I16`, `I32` or `I64` and the values
     ///
     fn from_value<T: Serialize>(value: T) -> Result<Self>;
 
@@ -333,15 +350,14 @@ pub fn validate_field(field: &Field) -> Result<()> {
         | DataType::Float16
         | DataType::Float32
         | DataType::Float64
-        | DataType::Utf8
-        | DataType::LargeUtf8
-        | DataType::Utf8View
         | DataType::Decimal128(_, _)
         | DataType::Date32
         | DataType::Binary
         | DataType::LargeBinary
         | DataType::BinaryView
-        | DataType::Duration(_) => validate_primitive_field(field),
+        | DataType::Duration(_)
+        | DataType::Interval(_) => validate_primitive_field(field),
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => validate_utf8_field(field),
         DataType::FixedSizeBinary(n) => validate_fixed_size_binary_field(field, *n),
         DataType::Date64 => validate_date64_field(field),
         DataType::Timestamp(unit, tz) => validate_timestamp_field(field, *unit, tz.as_deref()),
@@ -358,13 +374,19 @@ pub fn validate_field(field: &Field) -> Result<()> {
         DataType::Dictionary(key, values) => {
             validate_dictionary_field(field, key.as_ref(), values.as_ref())
         }
+        DataType::RunEndEncoded(run_ends, values) => {
+            validate_run_end_encoded_field(field, run_ends.as_ref(), values.as_ref())
+        }
         dt => fail!("Unsupported data type {dt:?}"),
     }
 }
 
 fn validate_null_field(field: &Field) -> Result<()> {
     match get_strategy_from_metadata(&field.metadata)? {
-        None | Some(Strategy::InconsistentTypes) | Some(Strategy::UnknownVariant) => Ok(()),
+        None
+        | Some(Strategy::InconsistentTypes)
+        | Some(Strategy::UnknownVariant)
+        | Some(Strategy::NullVariant) => Ok(()),
         Some(strategy) => fail!("invalid strategy for Null field: {strategy}"),
     }
 }
@@ -379,6 +401,16 @@ fn validate_primitive_field(field: &Field) -> Result<()> {
     Ok(())
 }
 
+fn validate_utf8_field(field: &Field) -> Result<()> {
+    match get_strategy_from_metadata(&field.metadata)? {
+        None | Some(Strategy::Json) => Ok(()),
+        Some(strategy) => fail!(
+            "invalid strategy for {data_type}: {strategy}",
+            data_type = DataTypeDisplay(&field.data_type),
+        ),
+    }
+}
+
 fn validate_fixed_size_binary_field(field: &Field, n: i32) -> Result<()> {
     if n < 0 {
         fail!("Invalid FixedSizedBinary with negative number of elements");
@@ -420,13 +452,34 @@ fn validate_dictionary_field(field: &Field, key: &DataType, value: &DataType) ->
             key = DataTypeDisplay(key),
         );
     }
-    if !matches!(value, DataType::Utf8 | DataType::LargeUtf8) {
+    if matches!(value, DataType::Dictionary(_, _)) {
+        fail!("invalid child for Dictionary. Nested dictionaries are not supported");
+    }
+    validate_field(&Field {
+        name: String::from("value"),
+        data_type: value.clone(),
+        nullable: false,
+        metadata: HashMap::new(),
+    })
+}
+
+fn validate_run_end_encoded_field(field: &Field, run_ends: &Field, values: &Field) -> Result<()> {
+    if let Some(strategy) = get_strategy_from_metadata(&field.metadata)? {
+        fail!("invalid strategy for RunEndEncoded field: {strategy}");
+    }
+    if !matches!(
+        run_ends.data_type,
+        DataType::Int16 | DataType::Int32 | DataType::Int64
+    ) {
         fail!(
-            "invalid child for Dictionary. Expected string values, found: {value}",
-            value = DataTypeDisplay(value)
+            "invalid child for RunEndEncoded. Expected Int16, Int32 or Int64 run ends, found: {run_ends}",
+            run_ends = DataTypeDisplay(&run_ends.data_type),
         );
     }
-    Ok(())
+    if run_ends.nullable {
+        fail!("invalid child for RunEndEncoded. Run ends must not be nullable");
+    }
+    validate_field(values)
 }
 
 fn validate_date64_field(field: &Field) -> Result<()> {
@@ -495,7 +548,30 @@ fn validate_union_field(field: &Field, children: &[(i8, Field)], _mode: UnionMod
     if let Some(strategy) = get_strategy_from_metadata(&field.metadata)? {
         fail!("invalid strategy for Union field: {strategy}");
     }
-    for (_, child) in children {
+    let mut seen_type_ids = HashSet::new();
+    for (idx, (type_id, child)) in children.iter().enumerate() {
+        if *type_id < 0 {
+            fail!("Invalid type id {type_id} for Union field: type ids must be non-negative");
+        }
+        if !seen_type_ids.insert(*type_id) {
+            fail!("Duplicate type id {type_id} in Union field");
+        }
+        if matches!(
+            get_strategy_from_metadata(&child.metadata)?,
+            Some(Strategy::NullVariant)
+        ) {
+            if !field.nullable {
+                fail!("Only nullable Union fields can contain a NullVariant child");
+            }
+            if idx + 1 != children.len() {
+                fail!("The NullVariant child must be the last child of a Union field");
+            }
+            if child.name != NULL_VARIANT_NAME {
+                fail!("The NullVariant child must be named {NULL_VARIANT_NAME:?}");
+            }
+        } else if child.name == NULL_VARIANT_NAME {
+            fail!("The name {NULL_VARIANT_NAME:?} is reserved for the NullVariant child");
+        }
         validate_field(child)?;
     }
     Ok(())
@@ -530,6 +606,7 @@ impl std::fmt::Display for DataTypeDisplay<'_> {
             DataType::Time64(unit) => write!(f, "Time64({unit})"),
             DataType::Timestamp(unit, tz) => write!(f, "Timestamp({unit}, {tz:?})"),
             DataType::Duration(unit) => write!(f, "Duration({unit})"),
+            DataType::Interval(unit) => write!(f, "Interval({unit})"),
             DataType::List(_) => write!(f, "List"),
             DataType::LargeList(_) => write!(f, "LargeList"),
             DataType::FixedSizeList(_, n) => write!(f, "FixedSizeList({n})"),
@@ -543,15 +620,21 @@ impl std::fmt::Display for DataTypeDisplay<'_> {
                 value = DataTypeDisplay(value),
             ),
             DataType::Union(_, mode) => write!(f, "Union({mode})"),
+            DataType::RunEndEncoded(run_ends, values) => write!(
+                f,
+                "RunEndEncoded({run_ends}, {values})",
+                run_ends = DataTypeDisplay(&run_ends.data_type),
+                values = DataTypeDisplay(&values.data_type),
+            ),
             _ => write!(f, "<unknown marrow data type>"),
         }
     }
 }
 
 const _: () = {
-    trait AssertSendSync: Send + Sync {}
-    impl AssertSendSync for SerdeArrowSchema {}
-    impl AssertSendSync for TracingOptions {}
-    impl AssertSendSync for Strategy {}
-    impl AssertSendSync for Overwrites {}
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SerdeArrowSchema>();
+    assert_send_sync::<TracingOptions>();
+    assert_send_sync::<Strategy>();
+    assert_send_sync::<Overwrites>();
 };
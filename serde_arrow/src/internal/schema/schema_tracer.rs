@@ -0,0 +1,212 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use serde::Serialize;
+
+use crate::internal::{
+    error::{fail, try_, Context, ContextSupport, Result},
+    utils::value::{Value, ValueSerializer},
+};
+
+use super::{
+    from_samples::{trace_samples, SampleSink},
+    tracer::Tracer,
+    SerdeArrowSchema, TracingMode, TracingOptions,
+};
+
+/// Determine the schema from samples that are given incrementally
+///
+/// In contrast to [`SchemaLike::from_samples`][crate::schema::SchemaLike::from_samples], the
+/// samples do not have to be available at once. They can be traced in batches, e.g., while
+/// reading a large file, and the schema of the samples seen so far can be queried at any time.
+/// The same requirements as for `from_samples` apply to the samples.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_json::json;
+/// use serde_arrow::schema::{SchemaLike, SchemaTracer, SerdeArrowSchema, TracingOptions};
+///
+/// let mut tracer = SchemaTracer::new(TracingOptions::default());
+/// tracer.trace_samples(&json!([{"a": 1}, {"a": 2}]))?;
+/// tracer.trace_sample(&json!({"a": 3, "b": "hello"}))?;
+///
+/// assert_eq!(
+///     tracer.to_schema()?,
+///     SerdeArrowSchema::from_value(&json!([
+///         {"name": "a", "data_type": "U64"},
+///         {"name": "b", "data_type": "LargeUtf8", "nullable": true},
+///     ]))?,
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// To bound the cost of tracing, only a subset of the samples can be traced, either the first
+/// samples ([`with_sample_limit`][SchemaTracer::with_sample_limit]) or a uniform random subset
+/// ([`with_reservoir_sampling`][SchemaTracer::with_reservoir_sampling]).
+#[derive(Debug, Clone)]
+pub struct SchemaTracer {
+    options: Arc<TracingOptions>,
+    tracer: Tracer,
+    sampling: Sampling,
+    num_samples: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Sampling {
+    All,
+    Limit(usize),
+    Reservoir {
+        capacity: usize,
+        state: u64,
+        samples: Vec<Value>,
+    },
+}
+
+impl SchemaTracer {
+    /// Construct a new tracer that traces all samples
+    pub fn new(options: TracingOptions) -> Self {
+        let options = Arc::new(options.tracing_mode(TracingMode::FromSamples));
+        Self {
+            tracer: Tracer::new(String::from("$"), String::from("$"), options.clone(), false),
+            options,
+            sampling: Sampling::All,
+            num_samples: 0,
+        }
+    }
+
+    /// Only trace the first `limit` samples, later samples are ignored
+    ///
+    /// This method must be called before any samples are traced.
+    pub fn with_sample_limit(mut self, limit: usize) -> Result<Self> {
+        self.check_no_samples()?;
+        self.sampling = Sampling::Limit(limit);
+        Ok(self)
+    }
+
+    /// Trace a uniform random subset of at most `capacity` samples
+    ///
+    /// The selected samples are kept in memory and only traced when the schema is requested.
+    /// Therefore, errors in the selected samples are reported by
+    /// [`to_schema`][SchemaTracer::to_schema]. The `seed` determines the selected samples. This
+    /// method must be called before any samples are traced.
+    pub fn with_reservoir_sampling(mut self, capacity: usize, seed: u64) -> Result<Self> {
+        self.check_no_samples()?;
+        self.sampling = Sampling::Reservoir {
+            capacity,
+            state: seed,
+            samples: Vec::new(),
+        };
+        Ok(self)
+    }
+
+    /// Trace a single sample
+    ///
+    /// If the sample cannot be traced, the tracer is left unchanged.
+    pub fn trace_sample<T: Serialize + ?Sized>(&mut self, sample: &T) -> Result<()> {
+        try_(|| self.add_sample(sample)).ctx(self)
+    }
+
+    /// Trace a sequence of samples, e.g., a `Vec` of records
+    ///
+    /// As for [`SchemaLike::from_samples`][crate::schema::SchemaLike::from_samples], the samples
+    /// must serialize as a sequence.
+    pub fn trace_samples<T: Serialize + ?Sized>(&mut self, samples: &T) -> Result<()> {
+        trace_samples(self, samples)
+    }
+
+    /// The number of samples seen so far, including samples that were not traced
+    pub fn num_samples(&self) -> usize {
+        self.num_samples
+    }
+
+    /// Build the schema of the samples traced so far
+    ///
+    /// The tracer is not modified and more samples can be traced afterwards.
+    pub fn to_schema(&self) -> Result<SerdeArrowSchema> {
+        let mut tracer = match &self.sampling {
+            Sampling::All | Sampling::Limit(_) => self.tracer.clone(),
+            Sampling::Reservoir { samples, .. } => {
+                let mut tracer = Tracer::new(
+                    String::from("$"),
+                    String::from("$"),
+                    self.options.clone(),
+                    false,
+                );
+                for sample in samples {
+                    try_(|| tracer.trace_sample(sample)).ctx(&tracer)?;
+                }
+                tracer
+            }
+        };
+        tracer.finish()?;
+        tracer.check()?;
+        tracer.to_schema()
+    }
+
+    fn check_no_samples(&self) -> Result<()> {
+        if self.num_samples != 0 {
+            fail!("The sampling strategy must be configured before samples are traced");
+        }
+        Ok(())
+    }
+
+    fn add_sample<T: Serialize + ?Sized>(&mut self, sample: &T) -> Result<()> {
+        let num_samples = self.num_samples + 1;
+        match &mut self.sampling {
+            Sampling::All => trace_atomically(&mut self.tracer, sample)?,
+            Sampling::Limit(limit) => {
+                if num_samples <= *limit {
+                    trace_atomically(&mut self.tracer, sample)?;
+                }
+            }
+            Sampling::Reservoir {
+                capacity,
+                state,
+                samples,
+            } => {
+                // Algorithm R: the n-th sample replaces a random entry with probability k / n
+                if samples.len() < *capacity {
+                    samples.push(sample.serialize(ValueSerializer)?);
+                } else {
+                    let mut next_state = *state;
+                    let idx = (next_random(&mut next_state) % num_samples as u64) as usize;
+                    if idx < *capacity {
+                        samples[idx] = sample.serialize(ValueSerializer)?;
+                    }
+                    *state = next_state;
+                }
+            }
+        }
+        self.num_samples = num_samples;
+        Ok(())
+    }
+}
+
+/// Trace a sample into a copy of the tracer and keep the copy only if tracing succeeds
+fn trace_atomically<T: Serialize + ?Sized>(tracer: &mut Tracer, sample: &T) -> Result<()> {
+    let mut updated = tracer.clone();
+    updated.trace_sample(sample)?;
+    *tracer = updated;
+    Ok(())
+}
+
+/// The SplitMix64 generator
+fn next_random(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+impl Context for SchemaTracer {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        self.tracer.annotate(annotations)
+    }
+}
+
+impl SampleSink for SchemaTracer {
+    fn trace_sample<T: Serialize + ?Sized>(&mut self, sample: &T) -> Result<()> {
+        self.add_sample(sample)
+    }
+}
@@ -167,6 +167,7 @@ fn build_data_type(data_type: String, children: Vec<Field>) -> Result<DataType>
         ("Time32", [unit]) => T::Time32(unit.as_ident()?.parse()?),
         ("Time64", [unit]) => T::Time64(unit.as_ident()?.parse()?),
         ("Duration", [unit]) => T::Duration(unit.as_ident()?.parse()?),
+        ("Interval", [unit]) => T::Interval(unit.as_ident()?.parse()?),
         ("Decimal128", [precision, scale]) => {
             T::Decimal128(precision.as_ident()?.parse()?, scale.as_ident()?.parse()?)
         }
@@ -201,12 +202,32 @@ fn build_data_type(data_type: String, children: Vec<Field>) -> Result<DataType>
             };
             T::Map(Box::new(child), false)
         }
-        ("Union", []) => {
+        ("RunEndEncoded", []) => {
+            let Ok([run_ends, values]) = <[_; 2]>::try_from(children) else {
+                fail!("Invalid children for RunEndEncoded: expected two children");
+            };
+            T::RunEndEncoded(Box::new(run_ends), Box::new(values))
+        }
+        ("Union", args) => {
+            let (mode, type_ids) = match args {
+                [] => (UnionMode::Dense, None),
+                [mode] => (mode.as_ident()?.parse()?, None),
+                [mode, type_ids @ ..] => (mode.as_ident()?.parse()?, Some(type_ids)),
+            };
             let mut children_with_type_ids = Vec::new();
-            for (idx, child) in children.into_iter().enumerate() {
-                children_with_type_ids.push((idx.try_into()?, child));
+            if let Some(type_ids) = type_ids {
+                if type_ids.len() != children.len() {
+                    fail!("Invalid arguments for Union: expected one type id per child");
+                }
+                for (type_id, child) in type_ids.iter().zip(children) {
+                    children_with_type_ids.push((type_id.as_ident()?.parse()?, child));
+                }
+            } else {
+                for (idx, child) in children.into_iter().enumerate() {
+                    children_with_type_ids.push((idx.try_into()?, child));
+                }
             }
-            T::Union(children_with_type_ids, UnionMode::Dense)
+            T::Union(children_with_type_ids, mode)
         }
         _ => fail!("invalid data type {data_type}"),
     };
@@ -2,7 +2,7 @@
 
 use std::collections::HashMap;
 
-use marrow::datatypes::{DataType, Field};
+use marrow::datatypes::{DataType, Field, UnionMode};
 use serde::ser::{Error, SerializeSeq, SerializeStruct};
 
 use crate::internal::schema::{SerdeArrowSchema, STRATEGY_KEY};
@@ -106,6 +106,7 @@ impl serde::Serialize for PrettyFieldDataType<'_> {
                 format!("Decimal128({precision}, {scale})").serialize(serializer)
             }
             T::Duration(unit) => format!("Duration({unit})").serialize(serializer),
+            T::Interval(unit) => format!("Interval({unit})").serialize(serializer),
             T::Time32(unit) => format!("Time32({unit})").serialize(serializer),
             T::Time64(unit) => format!("Time64({unit})").serialize(serializer),
             T::Timestamp(unit, tz) => format!("Timestamp({unit}, {tz:?})").serialize(serializer),
@@ -113,8 +114,27 @@ impl serde::Serialize for PrettyFieldDataType<'_> {
             T::FixedSizeList(_, n) => format!("FixedSizeList({n})").serialize(serializer),
             T::Struct(_) => "Struct".serialize(serializer),
             T::Map(_, _) => "Map".serialize(serializer),
-            T::Union(_, _) => "Union".serialize(serializer),
+            T::Union(fields, mode) => {
+                let has_consecutive_type_ids = fields
+                    .iter()
+                    .enumerate()
+                    .all(|(idx, (type_id, _))| usize::try_from(*type_id) == Ok(idx));
+                if has_consecutive_type_ids {
+                    match mode {
+                        UnionMode::Dense => "Union".serialize(serializer),
+                        mode => format!("Union({mode})").serialize(serializer),
+                    }
+                } else {
+                    let mut res = format!("Union({mode}");
+                    for (type_id, _) in fields {
+                        res.push_str(&format!(", {type_id}"));
+                    }
+                    res.push(')');
+                    res.serialize(serializer)
+                }
+            }
             T::Dictionary(_, _) => "Dictionary".serialize(serializer),
+            T::RunEndEncoded(_, _) => "RunEndEncoded".serialize(serializer),
             T::LargeList(_) => "LargeList".serialize(serializer),
             T::List(_) => "List".serialize(serializer),
             dt => Err(S::Error::custom(format!("unknown marrow data type {dt:?}"))),
@@ -157,6 +177,12 @@ impl serde::Serialize for PrettyFieldChildren<'_> {
                 s.serialize_element(&DictionaryField("value", value))?;
                 s.end()
             }
+            T::RunEndEncoded(run_ends, values) => {
+                let mut s = serializer.serialize_seq(Some(2))?;
+                s.serialize_element(&PrettyField(run_ends.as_ref()))?;
+                s.serialize_element(&PrettyField(values.as_ref()))?;
+                s.end()
+            }
             _ => serializer.serialize_seq(Some(0))?.end(),
         }
     }
@@ -182,6 +208,7 @@ fn is_data_type_with_children(data_type: &DataType) -> bool {
             | T::Map(_, _)
             | T::Union(_, _)
             | T::Dictionary(_, _)
+            | T::RunEndEncoded(_, _)
             | T::LargeList(_)
             | T::List(_)
     )
@@ -13,6 +13,12 @@ use crate::internal::error::{fail, Error, Result};
 ///
 pub const STRATEGY_KEY: &str = "SERDE_ARROW:strategy";
 
+/// The reserved name of the union child with the [`NullVariant`][Strategy::NullVariant] strategy
+///
+/// No other child of a union may use this name.
+///
+pub const NULL_VARIANT_NAME: &str = "SERDE_ARROW:null";
+
 /// Strategies for handling types without direct match between arrow and serde
 ///
 /// For the correct strategy both the field type and the field metadata must be
@@ -56,6 +62,21 @@ pub enum Strategy {
     /// serialization or deserialization of such a field is attempted, it will
     /// result in an error.
     UnknownVariant,
+    /// Mark a variant as the storage of missing values in nullable unions
+    ///
+    /// This strategy applies only to fields with DataType Null that are the
+    /// last child of a nullable union and are named
+    /// [`NULL_VARIANT_NAME`][crate::schema::NULL_VARIANT_NAME]. Serializing
+    /// `None` or default values selects this variant and deserializing rows of
+    /// this variant results in `None`.
+    NullVariant,
+    /// Store arbitrary values as JSON text
+    ///
+    /// This strategy applies only to fields with DataType `Utf8`, `LargeUtf8` or `Utf8View`. Any
+    /// value is serialized as compact JSON and deserialized by replaying the JSON through serde.
+    /// Fields with the `arrow.json` extension type use this strategy by default. Requires the
+    /// `serde_json` feature.
+    Json,
 }
 
 impl std::fmt::Display for Strategy {
@@ -65,6 +86,8 @@ impl std::fmt::Display for Strategy {
             Self::TupleAsStruct => write!(f, "TupleAsStruct"),
             Self::MapAsStruct => write!(f, "MapAsStruct"),
             Self::UnknownVariant => write!(f, "UnknownVariant"),
+            Self::NullVariant => write!(f, "NullVariant"),
+            Self::Json => write!(f, "Json"),
         }
     }
 }
@@ -92,6 +115,8 @@ impl FromStr for Strategy {
             "TupleAsStruct" => Ok(Self::TupleAsStruct),
             "MapAsStruct" => Ok(Self::MapAsStruct),
             "UnknownVariant" => Ok(Self::UnknownVariant),
+            "NullVariant" => Ok(Self::NullVariant),
+            "Json" => Ok(Self::Json),
             _ => fail!("Unknown strategy {s}"),
         }
     }
@@ -115,6 +140,13 @@ impl From<Strategy> for HashMap<String, String> {
 
 pub fn get_strategy_from_metadata(metadata: &HashMap<String, String>) -> Result<Option<Strategy>> {
     let Some(strategy) = metadata.get(STRATEGY_KEY) else {
+        // the canonical JSON extension type implies the JSON strategy
+        if metadata
+            .get("ARROW:extension:name")
+            .is_some_and(|name| name == "arrow.json")
+        {
+            return Ok(Some(Strategy::Json));
+        }
         return Ok(None);
     };
     Ok(Some(strategy.parse()?))
@@ -0,0 +1,230 @@
+//! Guess the type of string values in `from_samples`
+use std::collections::HashMap;
+
+use ::chrono::{DateTime, NaiveDateTime};
+use marrow::datatypes::{DataType, TimeUnit};
+
+use crate::internal::{chrono, schema::TracingOptions, utils::uuid};
+
+/// The metadata key of the `chrono` pattern used to parse and format timestamps as strings
+pub const DATETIME_PATTERN_KEY: &str = "SERDE_ARROW:datetime_pattern";
+
+/// The metadata key marking integer and boolean fields whose values are given as strings
+pub const STRING_ENCODED_KEY: &str = "SERDE_ARROW:string_encoded";
+
+/// The maximum precision of `Decimal128` fields
+const MAX_DECIMAL_PRECISION: u8 = 38;
+
+/// The type guessed for the string values of a field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StringGuess {
+    /// Strings without a more specific type
+    String,
+    Boolean,
+    /// Integers that fit into an `i64`, with their number of digits
+    Integer {
+        digits: u8,
+    },
+    /// Decimal numbers with their number of integer and fractional digits
+    Decimal {
+        integer_digits: u8,
+        scale: u8,
+    },
+    Uuid,
+    Date,
+    Time,
+    NaiveDateTime,
+    UtcDateTime,
+    /// Datetimes with the given fixed offset, e.g., `+05:30`
+    OffsetDateTime(String),
+    /// Datetimes matching the custom pattern with the given index, with or without offset
+    Pattern {
+        index: usize,
+        offset: bool,
+    },
+}
+
+impl StringGuess {
+    /// Guess the type of a single string using the guessers enabled in the options
+    pub fn guess(s: &str, options: &TracingOptions) -> Self {
+        if options.guess_booleans && matches!(s, "true" | "false") {
+            return Self::Boolean;
+        }
+        if let Some(guess) = guess_number(s, options) {
+            return guess;
+        }
+        if options.guess_uuids && uuid::parse_uuid(s).is_ok() {
+            return Self::Uuid;
+        }
+        if options.guess_dates {
+            if let Some(guess) = guess_date(s) {
+                return guess;
+            }
+        }
+        for (index, pattern) in options.datetime_patterns.iter().enumerate() {
+            // patterns with offset also parse as naive datetimes, check them first
+            if DateTime::parse_from_str(s, pattern).is_ok() {
+                return Self::Pattern {
+                    index,
+                    offset: true,
+                };
+            }
+            if NaiveDateTime::parse_from_str(s, pattern).is_ok() {
+                return Self::Pattern {
+                    index,
+                    offset: false,
+                };
+            }
+        }
+        Self::String
+    }
+
+    /// Combine the guesses of two values of the same field
+    ///
+    /// Integers and decimals are combined into decimals covering both, datetimes with different
+    /// offsets into UTC datetimes. All other combinations of different guesses result in strings.
+    pub fn union(self, other: Self) -> Self {
+        use StringGuess as G;
+
+        if self == other {
+            return self;
+        }
+        match (self, other) {
+            (G::Integer { digits: a }, G::Integer { digits: b }) => G::Integer { digits: a.max(b) },
+            (G::UtcDateTime | G::OffsetDateTime(_), G::UtcDateTime | G::OffsetDateTime(_)) => {
+                G::UtcDateTime
+            }
+            (a, b) => match (a.decimal_digits(), b.decimal_digits()) {
+                (Some((a_integer, a_scale)), Some((b_integer, b_scale))) => {
+                    Self::decimal(a_integer.max(b_integer), a_scale.max(b_scale))
+                        .unwrap_or(G::String)
+                }
+                _ => G::String,
+            },
+        }
+    }
+
+    /// The data type used for fields with this guess
+    pub fn data_type(&self, options: &TracingOptions) -> DataType {
+        match self {
+            Self::String => options.string_type(),
+            Self::Boolean => DataType::Boolean,
+            Self::Integer { .. } => DataType::Int64,
+            Self::Decimal {
+                integer_digits,
+                scale,
+            } => DataType::Decimal128(integer_digits + scale, *scale as i8),
+            Self::Uuid => DataType::FixedSizeBinary(16),
+            Self::Date => DataType::Date32,
+            Self::Time => DataType::Time64(TimeUnit::Nanosecond),
+            Self::NaiveDateTime | Self::Pattern { offset: false, .. } => {
+                DataType::Timestamp(TimeUnit::Millisecond, None)
+            }
+            Self::UtcDateTime | Self::Pattern { offset: true, .. } => {
+                DataType::Timestamp(TimeUnit::Millisecond, Some(String::from("UTC")))
+            }
+            Self::OffsetDateTime(offset) => {
+                DataType::Timestamp(TimeUnit::Millisecond, Some(offset.clone()))
+            }
+        }
+    }
+
+    /// Add the metadata required to serialize the strings into fields with this guess
+    pub fn insert_into(&self, metadata: &mut HashMap<String, String>, options: &TracingOptions) {
+        match self {
+            Self::Uuid => {
+                metadata.insert("ARROW:extension:name".into(), "arrow.uuid".into());
+                metadata.insert("ARROW:extension:metadata".into(), String::new());
+            }
+            Self::Integer { .. } | Self::Boolean => {
+                metadata.insert(STRING_ENCODED_KEY.to_string(), String::from("true"));
+            }
+            Self::Pattern { index, .. } => {
+                if let Some(pattern) = options.datetime_patterns.get(*index) {
+                    metadata.insert(DATETIME_PATTERN_KEY.to_string(), pattern.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Whether the field was traced from strings with a guessed integer or boolean type
+    pub fn is_string_encoded(metadata: &HashMap<String, String>) -> bool {
+        metadata.get(STRING_ENCODED_KEY).map(String::as_str) == Some("true")
+    }
+
+    fn decimal(integer_digits: u8, scale: u8) -> Option<Self> {
+        if u16::from(integer_digits) + u16::from(scale) > u16::from(MAX_DECIMAL_PRECISION) {
+            return None;
+        }
+        Some(Self::Decimal {
+            integer_digits,
+            scale,
+        })
+    }
+
+    fn decimal_digits(&self) -> Option<(u8, u8)> {
+        match self {
+            Self::Integer { digits } => Some((*digits, 0)),
+            Self::Decimal {
+                integer_digits,
+                scale,
+            } => Some((*integer_digits, *scale)),
+            _ => None,
+        }
+    }
+}
+
+fn guess_number(s: &str, options: &TracingOptions) -> Option<StringGuess> {
+    if !options.guess_integers && !options.guess_decimals {
+        return None;
+    }
+
+    let unsigned = s.strip_prefix('-').unwrap_or(s);
+    let (integer, fraction) = match unsigned.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (unsigned, None),
+    };
+    if !is_digits(integer) || fraction.is_some_and(|fraction| !is_digits(fraction)) {
+        return None;
+    }
+    // keep values with leading zeros as strings, e.g., zip codes
+    if integer.len() > 1 && integer.starts_with('0') {
+        return None;
+    }
+    let integer_digits = u8::try_from(integer.len()).ok()?;
+
+    match fraction {
+        // only canonical integers, to keep the string representation
+        None if options.guess_integers && s != "-0" && s.parse::<i64>().is_ok() => {
+            Some(StringGuess::Integer {
+                digits: integer_digits,
+            })
+        }
+        None if options.guess_decimals => StringGuess::decimal(integer_digits, 0),
+        Some(fraction) if options.guess_decimals => {
+            StringGuess::decimal(integer_digits, u8::try_from(fraction.len()).ok()?)
+        }
+        _ => None,
+    }
+}
+
+fn guess_date(s: &str) -> Option<StringGuess> {
+    if chrono::matches_naive_datetime(s) {
+        Some(StringGuess::NaiveDateTime)
+    } else if chrono::matches_utc_datetime(s) {
+        Some(StringGuess::UtcDateTime)
+    } else if let Some(offset) = chrono::match_offset_datetime(s) {
+        Some(StringGuess::OffsetDateTime(offset.to_string()))
+    } else if chrono::matches_naive_time(s) {
+        Some(StringGuess::Time)
+    } else if chrono::matches_naive_date(s) {
+        Some(StringGuess::Date)
+    } else {
+        None
+    }
+}
+
+fn is_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use marrow::datatypes::{DataType, Field, TimeUnit};
+use marrow::datatypes::{DataType, Field, IntervalUnit, TimeUnit, UnionMode};
 
 use serde_json::{json, Value};
 
@@ -11,7 +11,7 @@ use crate::internal::{
 };
 
 fn type_from_str(s: &str) -> DataType {
-    let schema = SerdeArrowSchema::from_value(&json!([{"name": "item", "data_type": s}])).unwrap();
+    let schema = SerdeArrowSchema::from_value(json!([{"name": "item", "data_type": s}])).unwrap();
     schema.fields[0].data_type.clone()
 }
 
@@ -192,6 +192,155 @@ fn map_field_complex() -> PanicOnError<()> {
     Ok(())
 }
 
+#[test]
+fn run_end_encoded_field_complex() -> PanicOnError<()> {
+    let schema = SerdeArrowSchema {
+        fields: vec![Field {
+            name: String::from("my_field_name"),
+            data_type: DataType::RunEndEncoded(
+                Box::new(Field {
+                    name: String::from("run_ends"),
+                    data_type: DataType::Int32,
+                    metadata: hash_map!(),
+                    nullable: false,
+                }),
+                Box::new(Field {
+                    name: String::from("values"),
+                    data_type: DataType::LargeUtf8,
+                    metadata: hash_map!(),
+                    nullable: true,
+                }),
+            ),
+            metadata: hash_map!(),
+            nullable: true,
+        }],
+    };
+    let expected = json!({
+        "fields": [{
+            "name": "my_field_name",
+            "data_type": "RunEndEncoded",
+            "nullable": true,
+            "children": [
+                {"name": "run_ends", "data_type": "I32"},
+                {"name": "values", "data_type": "LargeUtf8", "nullable": true},
+            ],
+        }],
+    });
+
+    let actual = serde_json::to_value(&schema)?;
+    assert_eq!(actual, expected);
+
+    let roundtripped = SerdeArrowSchema::from_value(&actual)?;
+    assert_eq!(roundtripped, schema);
+
+    Ok(())
+}
+
+#[test]
+fn union_modes() -> PanicOnError<()> {
+    for (mode, data_type) in [
+        (UnionMode::Dense, "Union"),
+        (UnionMode::Sparse, "Union(Sparse)"),
+    ] {
+        let schema = SerdeArrowSchema {
+            fields: vec![Field {
+                name: String::from("item"),
+                data_type: DataType::Union(
+                    vec![(
+                        0,
+                        Field {
+                            name: String::from("A"),
+                            data_type: DataType::Boolean,
+                            metadata: hash_map!(),
+                            nullable: false,
+                        },
+                    )],
+                    mode,
+                ),
+                metadata: hash_map!(),
+                nullable: false,
+            }],
+        };
+        let expected = json!({
+            "fields": [{
+                "name": "item",
+                "data_type": data_type,
+                "children": [{"name": "A", "data_type": "Bool"}],
+            }],
+        });
+
+        let actual = serde_json::to_value(&schema)?;
+        assert_eq!(actual, expected);
+
+        let roundtripped = SerdeArrowSchema::from_value(&actual)?;
+        assert_eq!(roundtripped, schema);
+    }
+
+    let res = SerdeArrowSchema::from_value(json!([{
+        "name": "item",
+        "data_type": "Union(Foo)",
+        "children": [{"name": "A", "data_type": "Bool"}],
+    }]));
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn union_type_ids() {
+    let data_type = DataType::Union(
+        vec![
+            (0, Field::default()),
+            (3, Field::default()),
+            (5, Field::default()),
+        ],
+        UnionMode::Dense,
+    );
+    assert_eq!(pretty_str_from_type(&data_type), "Union(Dense, 0, 3, 5)");
+
+    let schema = SerdeArrowSchema::from_value(json!([{
+        "name": "item",
+        "data_type": "Union(Sparse, 1, 0)",
+        "children": [
+            {"name": "A", "data_type": "Bool"},
+            {"name": "B", "data_type": "I32"},
+        ],
+    }]))
+    .unwrap();
+    let DataType::Union(fields, mode) = &schema.fields[0].data_type else {
+        panic!("Expected union");
+    };
+    let type_ids = fields
+        .iter()
+        .map(|(type_id, _)| *type_id)
+        .collect::<Vec<_>>();
+    assert_eq!(type_ids, vec![1, 0]);
+    assert_eq!(*mode, UnionMode::Sparse);
+}
+
+#[test]
+fn run_end_encoded_requires_integer_run_ends() {
+    let res = SerdeArrowSchema::from_value(json!([{
+        "name": "item",
+        "data_type": "RunEndEncoded",
+        "children": [
+            {"name": "run_ends", "data_type": "U32"},
+            {"name": "values", "data_type": "I64"},
+        ],
+    }]));
+    assert_error_contains(&res, "Expected Int16, Int32 or Int64 run ends");
+
+    let res = SerdeArrowSchema::from_value(json!([{
+        "name": "item",
+        "data_type": "RunEndEncoded",
+        "children": [
+            {"name": "run_ends", "data_type": "I32", "nullable": true},
+            {"name": "values", "data_type": "I64"},
+        ],
+    }]));
+    assert_error_contains(&res, "Run ends must not be nullable");
+}
+
 #[test]
 fn null_fields_are_nullable_implicitly() -> PanicOnError<()> {
     let expected = SerdeArrowSchema {
@@ -266,7 +415,7 @@ fn example_without_wrapper() {
     };
 
     let input = r#"[{"name":"foo","data_type":"U8"},{"name":"bar","data_type":"Utf8"}]"#;
-    let actual: SerdeArrowSchema = serde_json::from_str(&input).unwrap();
+    let actual: SerdeArrowSchema = serde_json::from_str(input).unwrap();
     assert_eq!(actual, expected);
 }
 
@@ -305,7 +454,7 @@ fn doc_schema() {
         ]
     "#;
 
-    let actual: SerdeArrowSchema = serde_json::from_str(&schema).unwrap();
+    let actual: SerdeArrowSchema = serde_json::from_str(schema).unwrap();
 
     let expected = SerdeArrowSchema {
         fields: vec![
@@ -329,7 +478,7 @@ fn doc_schema() {
 
 #[test]
 fn test_metadata_strategy_from_explicit() {
-    let schema = SerdeArrowSchema::from_value(&json!([
+    let schema = SerdeArrowSchema::from_value(json!([
         {
             "name": "example",
             "data_type": "Null",
@@ -368,7 +517,7 @@ fn test_metadata_strategy_from_explicit() {
 
 #[test]
 fn test_metadata_strategy_from_metadata() {
-    let schema = SerdeArrowSchema::from_value(&json!([
+    let schema = SerdeArrowSchema::from_value(json!([
         {
             "name": "example",
             "data_type": "Null",
@@ -409,7 +558,7 @@ fn test_metadata_strategy_from_metadata() {
 #[test]
 fn test_invalid_metadata() {
     // strategies cannot be given both in metadata and strategy field
-    let res = SerdeArrowSchema::from_value(&json!([
+    let res = SerdeArrowSchema::from_value(json!([
         {
             "name": "example",
             "data_type": "Null",
@@ -447,6 +596,26 @@ fn test_long_form_types() {
     );
 }
 
+#[test]
+fn test_nested_dictionaries_are_rejected() {
+    let res = SerdeArrowSchema::from_value(json!([{
+        "name": "item",
+        "data_type": "Dictionary",
+        "children": [
+            {"name": "key", "data_type": "U32"},
+            {
+                "name": "value",
+                "data_type": "Dictionary",
+                "children": [
+                    {"name": "key", "data_type": "U32"},
+                    {"name": "value", "data_type": "Utf8"},
+                ],
+            },
+        ],
+    }]));
+    assert_error_contains(&res, "Nested dictionaries are not supported");
+}
+
 macro_rules! test_short_form_type {
     ($name:ident, $data_type:expr, $s:expr) => {
         #[test]
@@ -535,3 +704,19 @@ test_short_form_type!(
     DataType::Duration(TimeUnit::Nanosecond),
     "Duration(Nanosecond)"
 );
+
+test_short_form_type!(
+    test_interval_year_month,
+    DataType::Interval(IntervalUnit::YearMonth),
+    "Interval(YearMonth)"
+);
+test_short_form_type!(
+    test_interval_day_time,
+    DataType::Interval(IntervalUnit::DayTime),
+    "Interval(DayTime)"
+);
+test_short_form_type!(
+    test_interval_month_day_nano,
+    DataType::Interval(IntervalUnit::MonthDayNano),
+    "Interval(MonthDayNano)"
+);
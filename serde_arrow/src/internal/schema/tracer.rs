@@ -3,13 +3,14 @@ use std::{
     sync::Arc,
 };
 
-use marrow::datatypes::{DataType, Field, UnionMode};
+use marrow::datatypes::{DataType, Field};
 
 use crate::internal::{
     error::{fail, set_default, Context, Result},
     schema::{
-        DataTypeDisplay, Overwrites, SerdeArrowSchema, Strategy, TracingMode, TracingOptions,
-        STRATEGY_KEY,
+        tracing_options::{is_json_field, matches_any_name, overwrite_matches},
+        DataTypeDisplay, Overwrites, SerdeArrowSchema, Strategy, StringGuess, TracingMode,
+        TracingOptions, NULL_VARIANT_NAME, STRATEGY_KEY,
     },
 };
 
@@ -27,6 +28,28 @@ fn default_dictionary_field(name: &str, nullable: bool, string_type: DataType) -
     }
 }
 
+/// Wrap the field into a `RunEndEncoded` field with `Int32` run ends
+fn run_end_encoded_field(field: Field) -> Field {
+    let run_ends = Field {
+        name: String::from("run_ends"),
+        data_type: DataType::Int32,
+        nullable: false,
+        metadata: HashMap::new(),
+    };
+    Field {
+        name: field.name.clone(),
+        nullable: field.nullable,
+        metadata: HashMap::new(),
+        data_type: DataType::RunEndEncoded(
+            Box::new(run_ends),
+            Box::new(Field {
+                name: String::from("values"),
+                ..field
+            }),
+        ),
+    }
+}
+
 fn unknown_variant_field() -> Field {
     let mut metadata = HashMap::new();
     metadata.insert(STRATEGY_KEY.into(), Strategy::UnknownVariant.into());
@@ -38,6 +61,18 @@ fn unknown_variant_field() -> Field {
     }
 }
 
+/// The child of nullable unions that stores missing values
+fn null_variant_field() -> Field {
+    let mut metadata = HashMap::new();
+    metadata.insert(STRATEGY_KEY.into(), Strategy::NullVariant.into());
+    Field {
+        name: String::from(NULL_VARIANT_NAME),
+        nullable: true,
+        data_type: DataType::Null,
+        metadata,
+    }
+}
+
 struct NullFieldMessage<'a>(&'a str);
 
 impl std::fmt::Display for NullFieldMessage<'_> {
@@ -98,8 +133,13 @@ defined_tracer!(
 );
 
 impl Tracer {
-    pub fn new(name: String, path: String, options: Arc<TracingOptions>) -> Self {
-        Self::Unknown(UnknownTracer::new(name, path, options))
+    pub fn new(
+        name: String,
+        path: String,
+        options: Arc<TracingOptions>,
+        in_json_field: bool,
+    ) -> Self {
+        Self::Unknown(UnknownTracer::new(name, path, options, in_json_field))
     }
 
     /// Convert the traced schema into a schema object
@@ -145,18 +185,37 @@ impl Tracer {
 
     pub fn to_field(&self) -> Result<Field> {
         let path = dispatch_tracer!(self, tracer => &tracer.path);
-        if let Some(overwrite) =
-            dispatch_tracer!(self, tracer => tracer.options.get_overwrite(path))
-        {
+        let options = self.get_options();
+        let field = if let Some((key, overwrite)) = options.find_overwrite(path) {
             let overwrite_name = &overwrite.name;
             let tracer_name = dispatch_tracer!(self, tracer => &tracer.name);
-            if *overwrite_name != *tracer_name {
+            if *overwrite_name == *tracer_name {
+                overwrite.clone()
+            } else if matches_any_name(key) {
+                Field {
+                    name: tracer_name.clone(),
+                    ..overwrite.clone()
+                }
+            } else {
                 let path = path.strip_prefix("$.").unwrap_or(path);
                 fail!("Invalid name for overwritten field {path:?}: found {overwrite_name:?}, expected {tracer_name:?}");
             }
-            Ok(overwrite.clone())
+        } else if let Some(overwrite) =
+            dispatch_tracer!(self, tracer => &tracer.overwrite.type_overwrite)
+        {
+            let nullable = dispatch_tracer!(self, tracer => tracer.nullable);
+            Field {
+                nullable: overwrite.nullable || nullable,
+                ..overwrite.clone()
+            }
+        } else {
+            dispatch_tracer!(self, tracer => tracer.to_field())?
+        };
+
+        if options.is_run_end_encoded(path) {
+            Ok(run_end_encoded_field(field))
         } else {
-            dispatch_tracer!(self, tracer => tracer.to_field())
+            Ok(field)
         }
     }
 
@@ -164,6 +223,44 @@ impl Tracer {
         dispatch_tracer!(self, tracer => &tracer.options)
     }
 
+    pub fn get_overwrite(&self) -> Option<&Field> {
+        let (path, overwrite) = dispatch_tracer!(self, tracer => (&tracer.path, &tracer.overwrite));
+        match self.get_options().get_overwrite(path) {
+            Some(overwrite) => Some(overwrite),
+            None => overwrite.type_overwrite.as_ref(),
+        }
+    }
+
+    /// Overwrite the field, if a type overwrite is registered for the given type name
+    ///
+    /// The type overwrite is stored in the tracer and carried over, when the tracer changes its
+    /// type. Overwrites of the path and type overwrites applied before, e.g., for an outer newtype,
+    /// take precedence.
+    pub fn apply_type_overwrite(&mut self, type_name: &str) {
+        let (name, path, options, state) = dispatch_tracer!(
+            self,
+            tracer => (&tracer.name, &tracer.path, &tracer.options, &mut tracer.overwrite)
+        );
+        if path == "$" || options.find_overwrite(path).is_some() || state.type_overwrite.is_some() {
+            return;
+        }
+        let Some(overwrite) = options.get_type_overwrite(type_name) else {
+            return;
+        };
+
+        state.in_json_field |= is_json_field(overwrite);
+        state.type_overwrite = Some(Field {
+            name: name.clone(),
+            ..overwrite.clone()
+        });
+    }
+
+    /// Whether the tracer belongs to a field, or the child of a field, stored as JSON text
+    pub fn is_in_json_field(&self) -> bool {
+        let (path, state) = dispatch_tracer!(self, tracer => (&tracer.path, &tracer.overwrite));
+        state.in_json_field || self.get_options().is_in_json_field(path)
+    }
+
     pub fn finish(&mut self) -> Result<()> {
         dispatch_tracer!(self, tracer => tracer.finish())
     }
@@ -186,9 +283,13 @@ impl Tracer {
 
         let mut missing = Vec::new();
         for key in overwrites.0.keys() {
+            if !paths.iter().any(|path| overwrite_matches(key, path)) {
+                missing.push(key.strip_prefix("$.").unwrap_or(key));
+            }
+        }
+        for key in &self.get_options().run_end_encoded {
             if !paths.contains(key) {
-                let key = key.strip_prefix("$.").unwrap_or(key);
-                missing.push(key);
+                missing.push(key.strip_prefix("$.").unwrap_or(key));
             }
         }
 
@@ -286,6 +387,7 @@ impl Tracer {
                     name: tracer.name.clone(),
                     path: tracer.path.clone(),
                     options: tracer.options.clone(),
+                    overwrite: tracer.overwrite.clone(),
                     fields: fields
                         .iter()
                         .map(|field| {
@@ -295,6 +397,7 @@ impl Tracer {
                                     field.to_owned(),
                                     format!("{}.{}", tracer.path, &field),
                                     tracer.options.clone(),
+                                    tracer.overwrite.in_json_field,
                                 ),
                                 name: field,
                                 last_seen_in_sample: 0,
@@ -329,12 +432,14 @@ impl Tracer {
                     name: tracer.name.clone(),
                     path: tracer.path.clone(),
                     options: tracer.options.clone(),
+                    overwrite: tracer.overwrite.clone(),
                     field_tracers: (0..num_fields)
                         .map(|i| {
                             Tracer::new(
                                 i.to_string(),
                                 format!("{}.{}", tracer.path, i),
                                 tracer.options.clone(),
+                                tracer.overwrite.in_json_field,
                             )
                         })
                         .collect(),
@@ -363,6 +468,7 @@ impl Tracer {
                     name: tracer.name.clone(),
                     path: tracer.path.clone(),
                     options: tracer.options.clone(),
+                    overwrite: tracer.overwrite.clone(),
                     variants: variants
                         .iter()
                         .map(|variant| {
@@ -372,6 +478,7 @@ impl Tracer {
                                     variant.to_string(),
                                     format!("{}.{}", tracer.path, variant),
                                     tracer.options.clone(),
+                                    tracer.overwrite.in_json_field,
                                 ),
                             })
                         })
@@ -401,11 +508,13 @@ impl Tracer {
                     name: tracer.name.clone(),
                     path: tracer.path.clone(),
                     options: tracer.options.clone(),
+                    overwrite: tracer.overwrite.clone(),
                     nullable: tracer.nullable,
                     item_tracer: Box::new(Tracer::new(
                         String::from("element"),
                         format!("{}.element", tracer.path),
                         tracer.options.clone(),
+                        tracer.overwrite.in_json_field,
                     )),
                 });
                 *this = Self::List(tracer);
@@ -430,16 +539,19 @@ impl Tracer {
                     name: tracer.name.clone(),
                     path: tracer.path.clone(),
                     options: tracer.options.clone(),
+                    overwrite: tracer.overwrite.clone(),
                     nullable: tracer.nullable,
                     key_tracer: Box::new(Tracer::new(
                         String::from("key"),
                         format!("{}.key", tracer.get_path()),
                         tracer.options.clone(),
+                        tracer.overwrite.in_json_field,
                     )),
                     value_tracer: Box::new(Tracer::new(
                         String::from("value"),
                         format!("{}.value", tracer.get_path()),
                         tracer.options.clone(),
+                        tracer.overwrite.in_json_field,
                     )),
                 });
                 *this = Self::Map(tracer);
@@ -461,8 +573,42 @@ impl Tracer {
         self.ensure_primitive_with_strategy(item_type, None)
     }
 
-    pub fn ensure_number(&mut self, item_type: DataType) -> Result<()> {
-        self.ensure_primitive_with_strategy(item_type, None)
+    /// Ensure a numeric type and record the value, if it is integral
+    ///
+    /// The observed values are used to narrow integer types, if enabled in the options.
+    pub fn ensure_number(&mut self, item_type: DataType, value: Option<i128>) -> Result<()> {
+        self.ensure_primitive_with_strategy(item_type, None)?;
+        if let Self::Primitive(tracer) = self {
+            if tracer.options.narrow_integers {
+                tracer.observe_number(value);
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensure the type guessed for a string value
+    ///
+    /// The guesses of all strings of a field are combined, see [`StringGuess::union`]. Fields that
+    /// also contain non-string values keep the guess, if the common type matches it.
+    pub fn ensure_string_guess(&mut self, guess: StringGuess) -> Result<()> {
+        let guess = match self {
+            Self::Primitive(tracer) => match tracer.string_guess.take() {
+                Some(prev) => prev.union(guess),
+                None => guess,
+            },
+            _ => guess,
+        };
+        match self {
+            Self::Primitive(tracer) if !tracer.non_string => {
+                tracer.item_type = guess.data_type(&tracer.options);
+                tracer.strategy = None;
+            }
+            _ => self.merge_primitive(guess.data_type(self.get_options()), None)?,
+        }
+        if let Self::Primitive(tracer) = self {
+            tracer.string_guess = Some(guess);
+        }
+        Ok(())
     }
 
     pub fn ensure_primitive_with_strategy(
@@ -470,6 +616,15 @@ impl Tracer {
         item_type: DataType,
         strategy: Option<Strategy>,
     ) -> Result<()> {
+        let is_null_type = matches!(item_type, DataType::Null);
+        self.merge_primitive(item_type, strategy)?;
+        if let (false, Self::Primitive(tracer)) = (is_null_type, self) {
+            tracer.non_string = true;
+        }
+        Ok(())
+    }
+
+    fn merge_primitive(&mut self, item_type: DataType, strategy: Option<Strategy>) -> Result<()> {
         match self {
             this @ Self::Unknown(_) => {
                 let is_null_type = matches!(item_type, DataType::Null);
@@ -477,9 +632,14 @@ impl Tracer {
                     name: tracer.name.clone(),
                     path: tracer.path.clone(),
                     options: tracer.options.clone(),
+                    overwrite: tracer.overwrite.clone(),
                     nullable: tracer.nullable || is_null_type,
                     item_type,
                     strategy,
+                    integer_range: None,
+                    non_integral: false,
+                    string_guess: None,
+                    non_string: false,
                 });
                 *this = Self::Primitive(tracer);
             }
@@ -524,6 +684,32 @@ fn coerce_primitive_type(
     curr: (DataType, Option<Strategy>),
     options: &TracingOptions,
 ) -> Result<(DataType, bool, Option<Strategy>)> {
+    if let Some(res) = try_coerce_primitive_type(prev, (curr.0.clone(), curr.1.clone()), options) {
+        return Ok(res);
+    }
+
+    let (prev_ty, _, prev_st) = prev;
+    let (curr_ty, curr_st) = curr;
+    let extra = if is_numeric(prev_ty) && is_numeric(&curr_ty) {
+        ": consider setting `coerce_numbers` to `true` to coerce different numeric types."
+    } else {
+        ""
+    };
+    fail!(
+        "Cannot accept {curr_ty:?} {curr_st} for tracer of primitive type {prev_ty:?} {prev_st}{extra}",
+        curr_st = OptionalStrategyDisplay(curr_st.as_ref()),
+        prev_st = OptionalStrategyDisplay(prev_st),
+    )
+}
+
+/// Determine the common type of two primitive types, returns `None` if they are incompatible
+///
+/// The result contains the data type, the nullability and the strategy of the common type.
+pub fn try_coerce_primitive_type(
+    prev: (&DataType, bool, Option<&Strategy>),
+    curr: (DataType, Option<Strategy>),
+    options: &TracingOptions,
+) -> Option<(DataType, bool, Option<Strategy>)> {
     use DataType::{
         Float32, Float64, Int16, Int32, Int64, Int8, LargeUtf8, Null, Timestamp, UInt16, UInt32,
         UInt64, UInt8, Utf8,
@@ -584,23 +770,12 @@ fn coerce_primitive_type(
         {
             (options.string_type(), nullable, None)
         }
-        ((prev_ty, _, prev_st), (curr_ty, curr_st)) => {
-            let extra = if is_numeric(prev_ty) && is_numeric(&curr_ty) {
-                ": consider setting `coerce_numbers` to `true` to coerce different numeric types."
-            } else {
-                ""
-            };
-            fail!(
-                "Cannot accept {curr_ty:?} {curr_st} for tracer of primitive type {prev_ty:?} {prev_st}{extra}",
-                curr_st = OptionalStrategyDisplay(curr_st.as_ref()),
-                prev_st = OptionalStrategyDisplay(prev_st),
-            )
-        }
+        _ => return None,
     };
-    Ok(res)
+    Some(res)
 }
 
-struct OptionalStrategyDisplay<'a>(Option<&'a Strategy>);
+pub struct OptionalStrategyDisplay<'a>(pub Option<&'a Strategy>);
 
 impl std::fmt::Display for OptionalStrategyDisplay<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -611,7 +786,81 @@ impl std::fmt::Display for OptionalStrategyDisplay<'_> {
     }
 }
 
-fn is_numeric(dt: &DataType) -> bool {
+/// The metadata key of the minimum observed value of narrowed integer fields
+pub const MIN_KEY: &str = "SERDE_ARROW:min";
+
+/// The metadata key of the maximum observed value of narrowed integer fields
+pub const MAX_KEY: &str = "SERDE_ARROW:max";
+
+/// The range of integral values observed during tracing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegerRange {
+    pub min: i128,
+    pub max: i128,
+}
+
+impl IntegerRange {
+    pub fn new(value: i128) -> Self {
+        Self {
+            min: value,
+            max: value,
+        }
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// The smallest integer type covering the range, unsigned types are preferred
+    pub fn data_type(self) -> Option<DataType> {
+        let covers = |min: i128, max: i128| min <= self.min && self.max <= max;
+
+        if self.min >= 0 {
+            if covers(0, u8::MAX.into()) {
+                Some(DataType::UInt8)
+            } else if covers(0, u16::MAX.into()) {
+                Some(DataType::UInt16)
+            } else if covers(0, u32::MAX.into()) {
+                Some(DataType::UInt32)
+            } else if covers(0, u64::MAX.into()) {
+                Some(DataType::UInt64)
+            } else {
+                None
+            }
+        } else if covers(i8::MIN.into(), i8::MAX.into()) {
+            Some(DataType::Int8)
+        } else if covers(i16::MIN.into(), i16::MAX.into()) {
+            Some(DataType::Int16)
+        } else if covers(i32::MIN.into(), i32::MAX.into()) {
+            Some(DataType::Int32)
+        } else if covers(i64::MIN.into(), i64::MAX.into()) {
+            Some(DataType::Int64)
+        } else {
+            None
+        }
+    }
+
+    /// Read the range from the metadata of a field, if present
+    pub fn from_metadata(metadata: &HashMap<String, String>) -> Result<Option<Self>> {
+        let (Some(min), Some(max)) = (metadata.get(MIN_KEY), metadata.get(MAX_KEY)) else {
+            return Ok(None);
+        };
+        Ok(Some(Self {
+            min: min.parse()?,
+            max: max.parse()?,
+        }))
+    }
+
+    pub fn insert_into(self, metadata: &mut HashMap<String, String>) {
+        metadata.insert(MIN_KEY.to_string(), self.min.to_string());
+        metadata.insert(MAX_KEY.to_string(), self.max.to_string());
+    }
+}
+
+pub fn is_numeric(dt: &DataType) -> bool {
     matches!(
         dt,
         DataType::UInt8
@@ -628,11 +877,21 @@ fn is_numeric(dt: &DataType) -> bool {
     )
 }
 
+/// The overwrite state of a tracer, see [`Tracer::apply_type_overwrite`]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct OverwriteState {
+    /// The type overwrite applied to this tracer
+    pub type_overwrite: Option<Field>,
+    /// Whether the tracer or one of its ancestors is stored as JSON text due to a type overwrite
+    pub in_json_field: bool,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct UnknownTracer {
     pub name: String,
     pub path: String,
     pub options: Arc<TracingOptions>,
+    pub overwrite: OverwriteState,
     pub nullable: bool,
 }
 
@@ -644,11 +903,20 @@ impl Context for UnknownTracer {
 }
 
 impl UnknownTracer {
-    pub fn new(name: String, path: String, options: Arc<TracingOptions>) -> Self {
+    pub fn new(
+        name: String,
+        path: String,
+        options: Arc<TracingOptions>,
+        in_json_field: bool,
+    ) -> Self {
         Self {
             name,
             path,
             options,
+            overwrite: OverwriteState {
+                type_overwrite: None,
+                in_json_field,
+            },
             nullable: false,
         }
     }
@@ -687,6 +955,7 @@ pub struct MapTracer {
     pub name: String,
     pub path: String,
     pub options: Arc<TracingOptions>,
+    pub overwrite: OverwriteState,
     pub nullable: bool,
     pub key_tracer: Box<Tracer>,
     pub value_tracer: Box<Tracer>,
@@ -743,6 +1012,7 @@ pub struct ListTracer {
     pub name: String,
     pub path: String,
     pub options: Arc<TracingOptions>,
+    pub overwrite: OverwriteState,
     pub nullable: bool,
     pub item_tracer: Box<Tracer>,
 }
@@ -791,6 +1061,7 @@ pub struct TupleTracer {
     pub name: String,
     pub path: String,
     pub options: Arc<TracingOptions>,
+    pub overwrite: OverwriteState,
     pub nullable: bool,
     pub field_tracers: Vec<Tracer>,
 }
@@ -848,6 +1119,7 @@ impl TupleTracer {
                 idx.to_string(),
                 format!("{path}.{idx}", path = self.path),
                 self.options.clone(),
+                self.overwrite.in_json_field,
             ));
         }
         &mut self.field_tracers[idx]
@@ -859,6 +1131,7 @@ pub struct StructTracer {
     pub name: String,
     pub path: String,
     pub options: Arc<TracingOptions>,
+    pub overwrite: OverwriteState,
     pub nullable: bool,
     pub fields: Vec<StructField>,
     pub index: HashMap<String, usize>,
@@ -906,6 +1179,7 @@ impl StructTracer {
                     key.to_string(),
                     format!("{path}.{key}", path = self.path),
                     self.options.clone(),
+                    self.overwrite.in_json_field,
                 ),
                 name: key.to_owned(),
                 last_seen_in_sample: self.seen_samples,
@@ -980,6 +1254,7 @@ pub struct UnionTracer {
     pub name: String,
     pub path: String,
     pub options: Arc<TracingOptions>,
+    pub overwrite: OverwriteState,
     pub nullable: bool,
     pub variants: Vec<Option<UnionVariant>>,
 }
@@ -1030,6 +1305,7 @@ impl UnionTracer {
                 variant.as_ref().to_string(),
                 format!("{path}.{key}", path = self.path, key = variant.as_ref()),
                 self.options.clone(),
+                self.overwrite.in_json_field,
             );
             let name = variant.into();
 
@@ -1076,10 +1352,13 @@ impl UnionTracer {
                 fields.push((i8::try_from(idx)?, unknown_variant_field()));
             };
         }
+        if self.nullable {
+            fields.push((i8::try_from(fields.len())?, null_variant_field()));
+        }
 
         Ok(Field {
             name: self.name.to_owned(),
-            data_type: DataType::Union(fields, UnionMode::Dense),
+            data_type: DataType::Union(fields, self.options.union_mode),
             nullable: self.nullable,
             metadata: HashMap::new(),
         })
@@ -1111,9 +1390,18 @@ pub struct PrimitiveTracer {
     pub name: String,
     pub path: String,
     pub options: Arc<TracingOptions>,
+    pub overwrite: OverwriteState,
     pub nullable: bool,
     pub strategy: Option<Strategy>,
     pub item_type: DataType,
+    /// The range of the observed integral values, used to narrow integer types
+    pub integer_range: Option<IntegerRange>,
+    /// Whether a non-integral number was observed
+    pub non_integral: bool,
+    /// The combined type guessed from the observed string values
+    pub string_guess: Option<StringGuess>,
+    /// Whether a value that is neither a string nor null was observed
+    pub non_string: bool,
 }
 
 impl Context for PrimitiveTracer {
@@ -1128,6 +1416,31 @@ impl PrimitiveTracer {
         Ok(())
     }
 
+    fn observe_number(&mut self, value: Option<i128>) {
+        let Some(value) = value else {
+            self.non_integral = true;
+            return;
+        };
+        self.integer_range = Some(match self.integer_range {
+            Some(range) => range.union(IntegerRange::new(value)),
+            None => IntegerRange::new(value),
+        });
+    }
+
+    /// The narrowed integer type and the observed range, if narrowing applies
+    fn narrowed_type(&self) -> Option<(DataType, IntegerRange)> {
+        // the observed range does not cover integers encoded as strings
+        if !self.options.narrow_integers
+            || self.non_integral
+            || self.string_guess.is_some()
+            || !is_numeric(&self.item_type)
+        {
+            return None;
+        }
+        let range = self.integer_range?;
+        Some((range.data_type()?, range))
+    }
+
     pub fn to_field(&self) -> Result<Field> {
         type D = DataType;
 
@@ -1163,9 +1476,25 @@ impl PrimitiveTracer {
                 if let Some(strategy) = self.strategy.as_ref() {
                     metadata.insert(STRATEGY_KEY.to_string(), strategy.to_string());
                 }
+                if let Some(guess) = self.string_guess.as_ref() {
+                    let guess_type = guess.data_type(&self.options);
+                    if *dt != guess_type {
+                        fail!(
+                            "Cannot combine strings guessed as {guess_type:?} with non-string values in a field of type {dt:?}"
+                        );
+                    }
+                    guess.insert_into(&mut metadata, &self.options);
+                }
+                let data_type = match self.narrowed_type() {
+                    Some((data_type, range)) => {
+                        range.insert_into(&mut metadata);
+                        data_type
+                    }
+                    None => dt.clone(),
+                };
                 Ok(Field {
                     name: self.name.to_owned(),
-                    data_type: dt.clone(),
+                    data_type,
                     nullable: self.nullable,
                     metadata,
                 })
@@ -1,10 +1,16 @@
-use std::collections::HashMap;
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
 
 use serde::Serialize;
 
-use marrow::datatypes::{DataType, Field};
+use marrow::datatypes::{DataType, Field, UnionMode};
 
-use crate::internal::{error::Result, schema::transmute_field};
+use crate::internal::{
+    error::Result,
+    schema::{get_strategy_from_metadata, transmute_field, Strategy},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TracingMode {
@@ -27,7 +33,7 @@ pub enum TracingMode {
 /// The defaults are:
 ///
 /// ```rust
-/// # use serde_arrow::schema::TracingOptions;
+/// # use serde_arrow::{marrow::datatypes::UnionMode, schema::TracingOptions};
 /// assert_eq!(
 ///     TracingOptions::default(),
 ///     TracingOptions::new()
@@ -37,8 +43,15 @@ pub enum TracingMode {
 ///         .strings_as_large_utf8(true)
 ///         .string_dictionary_encoding(false)
 ///         .coerce_numbers(false)
+///         .narrow_integers(false)
 ///         .guess_dates(false)
-///         .from_type_budget(100),
+///         .guess_integers(false)
+///         .guess_decimals(false)
+///         .guess_booleans(false)
+///         .guess_uuids(false)
+///         .from_type_budget(100)
+///         .union_mode(UnionMode::Dense)
+///         .int128_as_fixed_size_binary(false),
 /// );
 /// ```
 #[derive(Debug, Clone, PartialEq)]
@@ -85,15 +98,79 @@ pub struct TracingOptions {
     /// - signed  + float -> f64
     pub coerce_numbers: bool,
 
+    /// If `true`, choose the smallest integer type that covers the values observed in
+    /// `from_samples`. The default is `false`.
+    ///
+    /// The minimum and maximum of each numeric field are recorded during tracing. Unsigned types
+    /// are used for fields without negative values, e.g., a field with values in `0..=200` is
+    /// traced as `UInt8` and a field with values in `-1000..=1000` as `Int16`. Float fields, whose
+    /// values were all integral, are narrowed in the same way. The observed range is reported in
+    /// the `SERDE_ARROW:min` and `SERDE_ARROW:max` metadata entries of the field.
+    ///
+    /// Only the observed values are considered. Later values outside this range cannot be
+    /// serialized with the traced schema. For data with varying numeric types, e.g., JSON,
+    /// combine this option with [`coerce_numbers`][TracingOptions::coerce_numbers].
+    pub narrow_integers: bool,
+
     /// If `true`, try to auto detect datetimes in string columns. The default is `false`.
     ///
-    /// Currently the naive datetime (`YYYY-MM-DDThh:mm:ss`) and UTC datetimes
-    /// (`YYYY-MM-DDThh:mm:ssZ`) are understood.
+    /// The following formats are understood:
+    ///
+    /// - naive datetimes (`YYYY-MM-DDThh:mm:ss`), traced as `Timestamp(Millisecond, None)`
+    /// - UTC datetimes (`YYYY-MM-DDThh:mm:ssZ`), traced as `Timestamp(Millisecond, Some("UTC"))`
+    /// - datetimes with a fixed offset (`YYYY-MM-DDThh:mm:ss+hh:mm`), traced as
+    ///   `Timestamp(Millisecond, Some("+hh:mm"))`. Fields with different offsets are traced with
+    ///   the time zone `"UTC"`
+    /// - naive dates (`YYYY-MM-DD`), traced as `Date32`
+    /// - naive times (`hh:mm:ss`), traced as `Time64(Nanosecond)`
     ///
-    /// For string fields where all values are either missing or conform to one of the format the
-    /// data type is set as `Timestamp(Millisecond, tz)` with timezone `tz` `Some("UTC")` or `None`.
+    /// For string fields where all values are either missing or conform to the same format the
+    /// data type is set accordingly. Fields mixing different formats are traced as strings.
+    ///
+    /// Values of fields with a fixed offset are deserialized without the zone annotation in
+    /// brackets, e.g., `2024-07-02T13:30:00+05:30`. To deserialize them as `jiff::Zoned`, set the
+    /// `SERDE_ARROW:time_zone_annotation` metadata entry of the field to `"true"`, see the
+    /// [`schema` module][crate::schema].
     pub guess_dates: bool,
 
+    /// If `true`, trace string columns of integers as `Int64`. The default is `false`.
+    ///
+    /// Only integers in canonical form that fit into an `i64` are recognized. In particular,
+    /// strings with leading zeros (e.g., `"007"`) are kept as strings. If
+    /// [`guess_decimals`][TracingOptions::guess_decimals] is enabled, fields mixing integers and
+    /// decimals are traced as decimals. The field is marked with the `SERDE_ARROW:string_encoded`
+    /// metadata entry, which allows to serialize and deserialize its values as strings.
+    pub guess_integers: bool,
+
+    /// If `true`, trace string columns of decimal numbers (e.g., `"-12.50"`) as `Decimal128`. The
+    /// default is `false`.
+    ///
+    /// The precision and scale are chosen to cover all observed values. Fields that require a
+    /// precision above 38 are traced as strings.
+    pub guess_decimals: bool,
+
+    /// If `true`, trace string columns of `"true"` and `"false"` values as `Boolean`. The default
+    /// is `false`.
+    ///
+    /// As for integers, the field is marked with the `SERDE_ARROW:string_encoded` metadata entry.
+    pub guess_booleans: bool,
+
+    /// If `true`, trace string columns of hyphenated UUIDs as `FixedSizeBinary(16)` fields with
+    /// the `arrow.uuid` extension type. The default is `false`.
+    pub guess_uuids: bool,
+
+    /// Custom datetime patterns to try for string columns
+    ///
+    /// Patterns use the `chrono` format syntax (e.g., `"%d/%m/%Y %H:%M"`) and must describe both
+    /// the date and the time. They are tried in order after the other guessers. Fields whose
+    /// values match the same pattern are traced as `Timestamp(Millisecond, None)`, or as
+    /// `Timestamp(Millisecond, Some("UTC"))` for patterns with offset. The pattern is stored in
+    /// the `SERDE_ARROW:datetime_pattern` metadata entry of the field and used to parse and
+    /// format the values.
+    ///
+    /// Patterns can be added with `options.datetime_pattern(pattern)`.
+    pub datetime_patterns: Vec<String>,
+
     /// How many tracing iterations to perform in `from_type`.
     ///
     /// The default value (`100`) may be too conservative for deeply nested types or enums with many
@@ -133,6 +210,21 @@ pub struct TracingOptions {
     /// ```
     pub enums_without_data_as_strings: bool,
 
+    /// The mode of unions traced for enums. The default is `UnionMode::Dense`.
+    ///
+    /// In dense unions, the children only store the values of the rows of the corresponding
+    /// variant. In sparse unions, each child stores a value for every row and inactive rows are
+    /// filled with default values. Sparse unions require more memory, but are required by some
+    /// consumers.
+    pub union_mode: UnionMode,
+
+    /// If `true`, trace `i128` and `u128` values as `FixedSizeBinary(16)`. The default is `false`.
+    ///
+    /// By default, 128-bit integers are traced as `Decimal128(38, 0)`. As the decimal type is
+    /// limited to 38 digits, it cannot represent all `u128` and `i128` values. With this option
+    /// enabled, the values are stored as their 16-byte little-endian representation instead.
+    pub int128_as_fixed_size_binary: bool,
+
     /// A mapping of field paths to field definitions
     ///
     /// Overwrites can be added with `options.overwrite(path, field)`. The `field` parameter must
@@ -141,6 +233,21 @@ pub struct TracingOptions {
     /// `arrow::datatypes::Field`. Nested fields can be overwritten by using dotted paths, e.g.m
     /// `"foo.bar"`.
     ///
+    /// Paths can contain glob-style wildcards to overwrite multiple fields at once:
+    ///
+    /// - `*` matches a single path segment, e.g., `"*.created_at"` matches `"a.created_at"`, but
+    ///   not `"created_at"` or `"a.b.created_at"`. Within a segment, `*` matches any sequence of
+    ///   characters, e.g., `"created_*"` matches `"created_at"` and `"created_by"`
+    /// - `**` matches any number of path segments, including none, e.g., `"**.ts"` matches
+    ///   `"ts"`, `"a.ts"` and `"a.b.ts"`
+    ///
+    /// If multiple paths match a field, the most specific one is used: exact paths take precedence
+    /// over patterns. Among patterns, the pattern with more segments without wildcards wins, then
+    /// the pattern with fewer `**` segments, then the pattern with more characters besides
+    /// wildcards. Remaining ties are resolved by choosing the lexicographically smallest pattern.
+    /// If the last segment of a pattern contains a wildcard, the name of the overwrite is replaced
+    /// by the name of the matched field. Otherwise, the names must agree.
+    ///
     /// Overwrites can be used to change the data type of field, e.g., to ensure a field is a
     /// `Timestamp`:
     ///
@@ -216,6 +323,89 @@ pub struct TracingOptions {
     ///
     pub overwrites: Overwrites,
 
+    /// A mapping of Rust type names to field definitions
+    ///
+    /// Type overwrites can be added with `options.overwrite_type(type_name, field)`. They apply to
+    /// every field of the given type, as if an overwrite was given for each of their paths. The
+    /// type name is the name passed by the `Serialize` and `Deserialize` implementations to serde,
+    /// e.g., the name of a struct or enum with derived implementations, unless renamed with
+    /// `#[serde(rename = "..")]`. The name of the field definition is replaced by the name of the
+    /// overwritten field. Overwrites given for paths take precedence over type overwrites.
+    ///
+    /// Types that serialize as primitive values, e.g., `chrono::NaiveDateTime`, `uuid::Uuid` or
+    /// `std::net::IpAddr`, do not pass their name to serde. They can be overwritten by wrapping
+    /// them in a newtype:
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// # use arrow::datatypes::FieldRef;
+    /// # use serde_arrow::schema::{SchemaLike, TracingOptions};
+    /// # use serde_json::json;
+    /// # use serde::{Serialize, Deserialize};
+    /// #
+    /// use chrono::NaiveDateTime;
+    ///
+    /// ##[derive(Debug, Serialize, Deserialize)]
+    /// struct Timestamp(NaiveDateTime);
+    ///
+    /// ##[derive(Debug, Serialize, Deserialize)]
+    /// struct Example {
+    ///     pub created: Timestamp,
+    ///     pub updated: Option<Timestamp>,
+    /// }
+    ///
+    /// let options = TracingOptions::default().overwrite_type(
+    ///     "Timestamp",
+    ///     json!({"name": "timestamp", "data_type": "Timestamp(Microsecond, None)"}),
+    /// )?;
+    /// let fields = Vec::<FieldRef>::from_type::<Example>(options)?;
+    ///
+    /// assert_eq!(fields, Vec::<FieldRef>::from_value(&json!([
+    ///     {"name": "created", "data_type": "Timestamp(Microsecond, None)"},
+    ///     {"name": "updated", "data_type": "Timestamp(Microsecond, None)", "nullable": true},
+    /// ]))?);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() { }
+    /// ```
+    pub type_overwrites: Overwrites,
+
+    /// The paths of fields to encode as `RunEndEncoded` arrays
+    ///
+    /// Paths can be added with `options.run_end_encoded(path)`. The traced (or overwritten) field is
+    /// used as the values child and `Int32` run ends are used. When serializing, consecutive equal
+    /// values are merged into a single run. Similar to overwrites, nested fields are selected with
+    /// dotted paths.
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// # use arrow::datatypes::FieldRef;
+    /// # use serde_arrow::{schema::{SchemaLike, TracingOptions}, utils::Item};
+    /// # use serde_json::json;
+    /// #
+    /// let options = TracingOptions::default().run_end_encoded("item");
+    /// let fields = Vec::<FieldRef>::from_type::<Item<String>>(options)?;
+    ///
+    /// assert_eq!(fields, Vec::<FieldRef>::from_value(&json!([{
+    ///     "name": "item",
+    ///     "data_type": "RunEndEncoded",
+    ///     "children": [
+    ///         {"name": "run_ends", "data_type": "I32"},
+    ///         {"name": "values", "data_type": "LargeUtf8"},
+    ///     ],
+    /// }]))?);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() { }
+    /// ```
+    pub run_end_encoded: HashSet<String>,
+
     /// Internal field to improve error messages for the different tracing
     /// functions
     pub(crate) tracing_mode: TracingMode,
@@ -228,10 +418,20 @@ impl Default for TracingOptions {
             map_as_struct: true,
             string_dictionary_encoding: false,
             coerce_numbers: false,
+            narrow_integers: false,
             guess_dates: false,
+            guess_integers: false,
+            guess_decimals: false,
+            guess_booleans: false,
+            guess_uuids: false,
+            datetime_patterns: Vec::new(),
             from_type_budget: 100,
             enums_without_data_as_strings: false,
+            union_mode: UnionMode::Dense,
+            int128_as_fixed_size_binary: false,
             overwrites: Overwrites::default(),
+            type_overwrites: Overwrites::default(),
+            run_end_encoded: HashSet::new(),
             sequence_as_large_list: true,
             string_as_large_utf8: true,
             tracing_mode: TracingMode::Unknown,
@@ -280,12 +480,48 @@ impl TracingOptions {
         self
     }
 
-    /// Set [`try_parse_dates`](#structfield.try_parse_dates)
+    /// Set [`narrow_integers`](#structfield.narrow_integers)
+    pub fn narrow_integers(mut self, value: bool) -> Self {
+        self.narrow_integers = value;
+        self
+    }
+
+    /// Set [`guess_dates`](#structfield.guess_dates)
     pub fn guess_dates(mut self, value: bool) -> Self {
         self.guess_dates = value;
         self
     }
 
+    /// Set [`guess_integers`](#structfield.guess_integers)
+    pub fn guess_integers(mut self, value: bool) -> Self {
+        self.guess_integers = value;
+        self
+    }
+
+    /// Set [`guess_decimals`](#structfield.guess_decimals)
+    pub fn guess_decimals(mut self, value: bool) -> Self {
+        self.guess_decimals = value;
+        self
+    }
+
+    /// Set [`guess_booleans`](#structfield.guess_booleans)
+    pub fn guess_booleans(mut self, value: bool) -> Self {
+        self.guess_booleans = value;
+        self
+    }
+
+    /// Set [`guess_uuids`](#structfield.guess_uuids)
+    pub fn guess_uuids(mut self, value: bool) -> Self {
+        self.guess_uuids = value;
+        self
+    }
+
+    /// Add a pattern to [`datetime_patterns`](#structfield.datetime_patterns)
+    pub fn datetime_pattern<P: Into<String>>(mut self, pattern: P) -> Self {
+        self.datetime_patterns.push(pattern.into());
+        self
+    }
+
     /// Set [`from_type_budget`](#structfield.from_type_budget)
     pub fn from_type_budget(mut self, value: usize) -> Self {
         self.from_type_budget = value;
@@ -298,6 +534,18 @@ impl TracingOptions {
         self
     }
 
+    /// Set [`union_mode`](#structfield.union_mode)
+    pub fn union_mode(mut self, value: UnionMode) -> Self {
+        self.union_mode = value;
+        self
+    }
+
+    /// Set [`int128_as_fixed_size_binary`](#structfield.int128_as_fixed_size_binary)
+    pub fn int128_as_fixed_size_binary(mut self, value: bool) -> Self {
+        self.int128_as_fixed_size_binary = value;
+        self
+    }
+
     /// Add an overwrite to [`overwrites`](#structfield.overwrites)
     pub fn overwrite<P: Into<String>, F: Serialize>(mut self, path: P, field: F) -> Result<Self> {
         self.overwrites.0.insert(
@@ -307,13 +555,70 @@ impl TracingOptions {
         Ok(self)
     }
 
+    /// Add an overwrite to [`type_overwrites`](#structfield.type_overwrites)
+    pub fn overwrite_type<N: Into<String>, F: Serialize>(
+        mut self,
+        type_name: N,
+        field: F,
+    ) -> Result<Self> {
+        self.type_overwrites
+            .0
+            .insert(type_name.into(), transmute_field(field)?);
+        Ok(self)
+    }
+
+    /// Add a path to [`run_end_encoded`](#structfield.run_end_encoded)
+    pub fn run_end_encoded<P: Into<String>>(mut self, path: P) -> Self {
+        self.run_end_encoded
+            .insert(format!("$.{path}", path = path.into()));
+        self
+    }
+
     pub(crate) fn tracing_mode(mut self, value: TracingMode) -> Self {
         self.tracing_mode = value;
         self
     }
 
     pub(crate) fn get_overwrite(&self, path: &str) -> Option<&Field> {
-        self.overwrites.0.get(path)
+        self.find_overwrite(path).map(|(_, field)| field)
+    }
+
+    /// Find the overwrite of a path together with the key that selected it
+    pub(crate) fn find_overwrite(&self, path: &str) -> Option<(&str, &Field)> {
+        if let Some((key, field)) = self.overwrites.0.get_key_value(path) {
+            return Some((key.as_str(), field));
+        }
+        self.overwrites
+            .0
+            .iter()
+            .filter(|(key, _)| is_pattern(key) && overwrite_matches(key, path))
+            .min_by(|(a, _), (b, _)| {
+                pattern_specificity(b)
+                    .cmp(&pattern_specificity(a))
+                    .then_with(|| a.cmp(b))
+            })
+            .map(|(key, field)| (key.as_str(), field))
+    }
+
+    pub(crate) fn get_type_overwrite(&self, type_name: &str) -> Option<&Field> {
+        self.type_overwrites.0.get(type_name)
+    }
+
+    pub(crate) fn is_in_json_field(&self, path: &str) -> bool {
+        let ancestors = path
+            .match_indices('.')
+            .map(|(idx, _)| &path[..idx])
+            .chain(std::iter::once(path));
+        for ancestor in ancestors {
+            if self.get_overwrite(ancestor).is_some_and(is_json_field) {
+                return true;
+            }
+        }
+        false
+    }
+
+    pub(crate) fn is_run_end_encoded(&self, path: &str) -> bool {
+        self.run_end_encoded.contains(path)
     }
 
     pub(crate) fn string_type(&self) -> DataType {
@@ -323,8 +628,99 @@ impl TracingOptions {
             DataType::Utf8
         }
     }
+
+    pub(crate) fn int128_type(&self) -> DataType {
+        if self.int128_as_fixed_size_binary {
+            DataType::FixedSizeBinary(16)
+        } else {
+            DataType::Decimal128(38, 0)
+        }
+    }
 }
 
-/// An opaque mapping of field paths to field definitions
+/// An opaque mapping of field paths or type names to field definitions
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Overwrites(pub(crate) HashMap<String, Field>);
+
+/// Whether the overwrite key selects the given path, either exactly or as a pattern
+pub(crate) fn overwrite_matches(key: &str, path: &str) -> bool {
+    if !is_pattern(key) {
+        return key == path;
+    }
+    let (Some(key), Some(path)) = (key.strip_prefix("$."), path.strip_prefix("$.")) else {
+        return false;
+    };
+    matches_segments(
+        &key.split('.').collect::<Vec<_>>(),
+        &path.split('.').collect::<Vec<_>>(),
+    )
+}
+
+/// Whether the field is stored as JSON text
+pub(crate) fn is_json_field(field: &Field) -> bool {
+    matches!(
+        get_strategy_from_metadata(&field.metadata),
+        Ok(Some(Strategy::Json))
+    )
+}
+
+/// Whether the overwrite key is a pattern that matches fields with different names
+pub(crate) fn matches_any_name(key: &str) -> bool {
+    key.rsplit('.')
+        .next()
+        .is_some_and(|name| name.contains('*'))
+}
+
+fn is_pattern(key: &str) -> bool {
+    key.contains('*')
+}
+
+/// The precedence of patterns, more specific patterns compare greater
+fn pattern_specificity(key: &str) -> (usize, Reverse<usize>, usize) {
+    let segments = key.strip_prefix("$.").unwrap_or(key).split('.');
+    let mut literal_segments = 0;
+    let mut recursive_segments = 0;
+    let mut literal_chars = 0;
+    for segment in segments {
+        if segment == "**" {
+            recursive_segments += 1;
+        } else if !segment.contains('*') {
+            literal_segments += 1;
+        }
+        literal_chars += segment.chars().filter(|c| *c != '*').count();
+    }
+    (literal_segments, Reverse(recursive_segments), literal_chars)
+}
+
+fn matches_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| matches_segments(rest, &path[skip..])),
+        Some((segment, rest)) => match path.split_first() {
+            Some((name, path_rest)) => {
+                matches_segment(segment, name) && matches_segments(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+fn matches_segment(pattern: &str, name: &str) -> bool {
+    let parts = pattern.split('*').collect::<Vec<_>>();
+    let [first, middle @ .., last] = parts.as_slice() else {
+        return pattern == name;
+    };
+    let Some(rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let Some(mut rest) = rest.strip_suffix(last) else {
+        return false;
+    };
+    for part in middle {
+        let Some(idx) = rest.find(part) else {
+            return false;
+        };
+        rest = &rest[idx + part.len()..];
+    }
+    true
+}
@@ -9,15 +9,23 @@ use crate::internal::error::{Context, Result};
 
 use super::{
     binary_builder::BinaryBuilder, bool_builder::BoolBuilder, date_builder::DateBuilder,
-    decimal_builder::DecimalBuilder, dictionary_utf8_builder::DictionaryUtf8Builder,
-    duration_builder::DurationBuilder, fixed_size_binary_builder::FixedSizeBinaryBuilder,
+    decimal_builder::DecimalBuilder, dictionary_builder::DictionaryBuilder,
+    dictionary_utf8_builder::DictionaryUtf8Builder, duration_builder::DurationBuilder,
+    extension_builder::ExtensionBuilder, fixed_shape_tensor_builder::FixedShapeTensorBuilder,
+    fixed_size_binary_builder::FixedSizeBinaryBuilder,
     fixed_size_list_builder::FixedSizeListBuilder, float_builder::FloatBuilder,
-    int_builder::IntBuilder, list_builder::ListBuilder, map_builder::MapBuilder,
-    null_builder::NullBuilder, simple_serializer::SimpleSerializer, struct_builder::StructBuilder,
-    time_builder::TimeBuilder, timestamp_builder::TimestampBuilder, union_builder::UnionBuilder,
-    unknown_variant_builder::UnknownVariantBuilder, utf8_builder::Utf8Builder,
+    int_builder::IntBuilder, interval_builder::IntervalBuilder, list_builder::ListBuilder,
+    map_builder::MapBuilder, null_builder::NullBuilder,
+    run_end_encoded_builder::RunEndEncodedBuilder, simple_serializer::SimpleSerializer,
+    struct_builder::StructBuilder, time_builder::TimeBuilder, timestamp_builder::TimestampBuilder,
+    union_builder::UnionBuilder, unknown_variant_builder::UnknownVariantBuilder,
+    utf8_builder::Utf8Builder, value_collector::ValueSink,
+    variable_shape_tensor_builder::VariableShapeTensorBuilder,
 };
 
+#[cfg(feature = "serde_json")]
+use super::json_builder::JsonBuilder;
+
 #[derive(Debug, Clone)]
 pub enum ArrayBuilder {
     Null(NullBuilder),
@@ -38,6 +46,9 @@ pub enum ArrayBuilder {
     Time32(TimeBuilder<i32>),
     Time64(TimeBuilder<i64>),
     Duration(DurationBuilder),
+    YearMonthInterval(IntervalBuilder<i32>),
+    DayTimeInterval(IntervalBuilder<(i32, i32)>),
+    MonthDayNanoInterval(IntervalBuilder<(i32, i32, i64)>),
     Timestamp(TimestampBuilder),
     Decimal128(DecimalBuilder),
     List(ListBuilder<i32>),
@@ -53,7 +64,14 @@ pub enum ArrayBuilder {
     LargeUtf8(Utf8Builder<BytesArray<i64>>),
     Utf8View(Utf8Builder<BytesViewArray>),
     DictionaryUtf8(DictionaryUtf8Builder),
+    Dictionary(DictionaryBuilder),
     Union(UnionBuilder),
+    RunEndEncoded(RunEndEncodedBuilder),
+    #[cfg(feature = "serde_json")]
+    Json(JsonBuilder),
+    Extension(ExtensionBuilder),
+    FixedShapeTensor(FixedShapeTensorBuilder),
+    VariableShapeTensor(VariableShapeTensorBuilder),
     UnknownVariant(UnknownVariantBuilder),
 }
 
@@ -78,6 +96,9 @@ macro_rules! dispatch {
             $wrapper::Time32($name) => $expr,
             $wrapper::Time64($name) => $expr,
             $wrapper::Duration($name) => $expr,
+            $wrapper::YearMonthInterval($name) => $expr,
+            $wrapper::DayTimeInterval($name) => $expr,
+            $wrapper::MonthDayNanoInterval($name) => $expr,
             $wrapper::Timestamp($name) => $expr,
             $wrapper::Decimal128($name) => $expr,
             $wrapper::Utf8($name) => $expr,
@@ -93,7 +114,14 @@ macro_rules! dispatch {
             $wrapper::Map($name) => $expr,
             $wrapper::Struct($name) => $expr,
             $wrapper::DictionaryUtf8($name) => $expr,
+            $wrapper::Dictionary($name) => $expr,
             $wrapper::Union($name) => $expr,
+            $wrapper::RunEndEncoded($name) => $expr,
+            #[cfg(feature = "serde_json")]
+            $wrapper::Json($name) => $expr,
+            $wrapper::Extension($name) => $expr,
+            $wrapper::FixedShapeTensor($name) => $expr,
+            $wrapper::VariableShapeTensor($name) => $expr,
             $wrapper::UnknownVariant($name) => $expr,
         }
     };
@@ -179,6 +207,14 @@ impl SimpleSerializer for ArrayBuilder {
         dispatch!(self, Self(builder) => builder.serialize_u64(v))
     }
 
+    fn serialize_i128(&mut self, v: i128) -> Result<()> {
+        dispatch!(self, Self(builder) => builder.serialize_i128(v))
+    }
+
+    fn serialize_u128(&mut self, v: u128) -> Result<()> {
+        dispatch!(self, Self(builder) => builder.serialize_u128(v))
+    }
+
     fn serialize_f32(&mut self, v: f32) -> Result<()> {
         dispatch!(self, Self(builder) => builder.serialize_f32(v))
     }
@@ -276,10 +312,45 @@ impl SimpleSerializer for ArrayBuilder {
     }
 
     fn serialize_struct_variant_start<'this>(&'this mut self, name: &'static str, variant_index: u32, variant: &'static str, len: usize) -> Result<&'this mut ArrayBuilder> {
+        // JSON, extension, run end encoded and dictionary builders collect the fields of the variant themselves
+        #[cfg(feature = "serde_json")]
+        if let Self::Json(builder) = self {
+            builder.start_struct_variant(variant_index, variant, len)?;
+            return Ok(self);
+        }
+        if let Self::Extension(builder) = self {
+            builder.start_struct_variant(variant_index, variant, len)?;
+            return Ok(self);
+        }
+        if let Self::RunEndEncoded(builder) = self {
+            builder.start_struct_variant(variant_index, variant, len)?;
+            return Ok(self);
+        }
+        if let Self::Dictionary(builder) = self {
+            builder.start_struct_variant(variant_index, variant, len)?;
+            return Ok(self);
+        }
         dispatch!(self, Self(builder) => builder.serialize_struct_variant_start(name, variant_index, variant, len))
     }
 
     fn serialize_tuple_variant_start<'this> (&'this mut self, name: &'static str, variant_index: u32, variant: &'static str, len: usize) -> Result<&'this mut ArrayBuilder> {
+        #[cfg(feature = "serde_json")]
+        if let Self::Json(builder) = self {
+            builder.start_tuple_variant(variant_index, variant, len)?;
+            return Ok(self);
+        }
+        if let Self::Extension(builder) = self {
+            builder.start_tuple_variant(variant_index, variant, len)?;
+            return Ok(self);
+        }
+        if let Self::RunEndEncoded(builder) = self {
+            builder.start_tuple_variant(variant_index, variant, len)?;
+            return Ok(self);
+        }
+        if let Self::Dictionary(builder) = self {
+            builder.start_tuple_variant(variant_index, variant, len)?;
+            return Ok(self);
+        }
         dispatch!(self, Self(builder) => builder.serialize_tuple_variant_start(name, variant_index, variant, len))
     }
 }
@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use marrow::array::{Array, BooleanArray};
 
 use crate::internal::{
-    error::{set_default, try_, Context, ContextSupport, Result},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
     utils::array_ext::{set_bit_buffer, set_validity, set_validity_default},
 };
 
@@ -13,6 +13,7 @@ use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
 pub struct BoolBuilder {
     path: String,
     array: BooleanArray,
+    strings: bool,
 }
 
 impl BoolBuilder {
@@ -24,9 +25,16 @@ impl BoolBuilder {
                 validity: is_nullable.then(Vec::new),
                 values: Vec::new(),
             },
+            strings: false,
         }
     }
 
+    /// Accept the strings `"true"` and `"false"`, e.g., for fields traced with guessed types
+    pub fn with_strings(mut self, value: bool) -> Self {
+        self.strings = value;
+        self
+    }
+
     pub fn take(&mut self) -> ArrayBuilder {
         ArrayBuilder::Bool(Self {
             path: self.path.clone(),
@@ -35,6 +43,7 @@ impl BoolBuilder {
                 validity: self.array.validity.as_mut().map(std::mem::take),
                 values: std::mem::take(&mut self.array.values),
             },
+            strings: self.strings,
         })
     }
 
@@ -84,4 +93,16 @@ impl SimpleSerializer for BoolBuilder {
         })
         .ctx(self)
     }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        if !self.strings {
+            fail!(in self, "Only boolean fields with the SERDE_ARROW:string_encoded metadata accept strings");
+        }
+        let v = match v {
+            "true" => true,
+            "false" => false,
+            _ => fail!(in self, "Cannot parse {v:?} as a boolean, expected \"true\" or \"false\""),
+        };
+        self.serialize_bool(v)
+    }
 }
@@ -2,13 +2,19 @@ use std::collections::BTreeMap;
 
 use chrono::{NaiveDate, NaiveDateTime};
 use marrow::array::{Array, PrimitiveArray};
+use serde::Serialize;
 
 use crate::internal::{
     error::{fail, set_default, try_, Context, ContextSupport, Result},
-    utils::array_ext::{ArrayExt, ScalarArrayExt},
+    utils::{
+        array_ext::{ArrayExt, ScalarArrayExt},
+        time_tuple, Mut,
+    },
 };
 
-use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
+use super::{
+    array_builder::ArrayBuilder, simple_serializer::SimpleSerializer, utils::I64Serializer,
+};
 
 pub trait DatePrimitive:
     TryFrom<i32>
@@ -47,6 +53,7 @@ impl DatePrimitive for i64 {
 pub struct DateBuilder<I: DatePrimitive> {
     path: String,
     array: PrimitiveArray<I>,
+    tuple: Vec<i64>,
 }
 
 impl<I: DatePrimitive> DateBuilder<I> {
@@ -54,6 +61,7 @@ impl<I: DatePrimitive> DateBuilder<I> {
         Self {
             path,
             array: PrimitiveArray::new(is_nullable),
+            tuple: Vec::new(),
         }
     }
 
@@ -61,6 +69,7 @@ impl<I: DatePrimitive> DateBuilder<I> {
         I::ARRAY_BUILDER_VARIANT(Self {
             path: self.path.clone(),
             array: self.array.take(),
+            tuple: Vec::new(),
         })
     }
 
@@ -73,9 +82,12 @@ impl<I: DatePrimitive> DateBuilder<I> {
     }
 
     fn parse_str_to_days_since_epoch(&self, s: &str) -> Result<I> {
+        self.date_to_days_since_epoch(s.parse::<NaiveDate>()?)
+    }
+
+    fn date_to_days_since_epoch(&self, date: NaiveDate) -> Result<I> {
         const UNIX_EPOCH: NaiveDate = NaiveDateTime::UNIX_EPOCH.date();
 
-        let date = s.parse::<NaiveDate>()?;
         let duration_since_epoch = date.signed_duration_since(UNIX_EPOCH).num_days();
         let Ok(days_since_epoch) = I::try_from(duration_since_epoch) else {
             fail!("cannot convert {duration_since_epoch} to {I}", I = I::NAME);
@@ -128,4 +140,28 @@ impl<I: DatePrimitive> SimpleSerializer for DateBuilder<I> {
         })
         .ctx(self)
     }
+
+    fn serialize_tuple_start(&mut self, _: usize) -> Result<()> {
+        self.tuple.clear();
+        Ok(())
+    }
+
+    fn serialize_tuple_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| {
+            let mut value_serializer = I64Serializer(0);
+            value.serialize(Mut(&mut value_serializer))?;
+            self.tuple.push(value_serializer.0);
+            Ok(())
+        })
+        .ctx(self)
+    }
+
+    fn serialize_tuple_end(&mut self) -> Result<()> {
+        try_(|| {
+            let date = time_tuple::date_from_tuple(&self.tuple)?;
+            let days_since_epoch = self.date_to_days_since_epoch(date)?;
+            self.array.push_scalar_value(days_since_epoch)
+        })
+        .ctx(self)
+    }
 }
@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use marrow::array::{Array, DecimalArray, PrimitiveArray};
 
 use crate::internal::{
-    error::{set_default, try_, Context, ContextSupport, Result},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
     utils::{
         array_ext::{ArrayExt, ScalarArrayExt},
         decimal::{self, DecimalParser},
@@ -86,6 +86,25 @@ impl SimpleSerializer for DecimalBuilder {
         try_(|| self.array.push_scalar_value((v * self.f64_factor) as i128)).ctx(self)
     }
 
+    fn serialize_i128(&mut self, v: i128) -> Result<()> {
+        try_(|| {
+            let val = scale_integer(v, self.precision, self.scale)?;
+            self.array.push_scalar_value(val)
+        })
+        .ctx(self)
+    }
+
+    fn serialize_u128(&mut self, v: u128) -> Result<()> {
+        try_(|| {
+            let Ok(v) = i128::try_from(v) else {
+                fail!("Cannot store {v} in Decimal128: value overflows i128");
+            };
+            let val = scale_integer(v, self.precision, self.scale)?;
+            self.array.push_scalar_value(val)
+        })
+        .ctx(self)
+    }
+
     fn serialize_str(&mut self, v: &str) -> Result<()> {
         try_(|| {
             let mut parse_buffer = [0; decimal::BUFFER_SIZE_I128];
@@ -98,3 +117,28 @@ impl SimpleSerializer for DecimalBuilder {
         .ctx(self)
     }
 }
+
+/// Convert an integer into the unscaled representation of a decimal
+fn scale_integer(v: i128, precision: u8, scale: i8) -> Result<i128> {
+    let Some(factor) = 10_i128.checked_pow(scale.unsigned_abs() as u32) else {
+        fail!("Cannot store {v} in Decimal128({precision}, {scale}): invalid scale");
+    };
+    let val = if scale >= 0 {
+        v.checked_mul(factor)
+    } else if v % factor == 0 {
+        Some(v / factor)
+    } else {
+        None
+    };
+    let Some(val) = val else {
+        fail!("Cannot store {v} in Decimal128({precision}, {scale}): value is not representable");
+    };
+    if let Some(bound) = 10_i128.checked_pow(precision as u32) {
+        if val.unsigned_abs() >= bound.unsigned_abs() {
+            fail!(
+                "Cannot store {v} in Decimal128({precision}, {scale}): value exceeds the precision"
+            );
+        }
+    }
+    Ok(val)
+}
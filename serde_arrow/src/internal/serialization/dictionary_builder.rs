@@ -0,0 +1,98 @@
+use std::collections::{BTreeMap, HashMap};
+
+use marrow::array::{Array, DictionaryArray};
+use serde::Serialize;
+
+use crate::internal::{
+    error::{set_default, Context, Result},
+    utils::{value::Value, Mut},
+};
+
+use super::{
+    array_builder::ArrayBuilder,
+    simple_serializer::SimpleSerializer,
+    value_collector::{PendingValue, ValueSink},
+};
+
+/// A dictionary builder for arbitrary value types
+///
+/// Values are deduplicated by their serde representation. Strings are handled by the more
+/// efficient [`DictionaryUtf8Builder`][super::dictionary_utf8_builder::DictionaryUtf8Builder].
+#[derive(Debug, Clone)]
+pub struct DictionaryBuilder {
+    path: String,
+    pub indices: Box<ArrayBuilder>,
+    pub values: Box<ArrayBuilder>,
+    pub index: HashMap<Value, usize>,
+    pending: PendingValue,
+}
+
+impl DictionaryBuilder {
+    pub fn new(path: String, indices: ArrayBuilder, values: ArrayBuilder) -> Self {
+        Self {
+            path,
+            indices: Box::new(indices),
+            values: Box::new(values),
+            index: HashMap::new(),
+            pending: PendingValue::default(),
+        }
+    }
+
+    pub fn take(&mut self) -> ArrayBuilder {
+        ArrayBuilder::Dictionary(Self {
+            path: self.path.clone(),
+            indices: Box::new(self.indices.take()),
+            values: Box::new(self.values.take()),
+            index: std::mem::take(&mut self.index),
+            pending: PendingValue::default(),
+        })
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.indices.is_nullable()
+    }
+
+    pub fn into_array(mut self) -> Result<Array> {
+        if self.index.is_empty() {
+            self.values.serialize_default()?;
+        }
+        Ok(Array::Dictionary(DictionaryArray {
+            keys: Box::new((*self.indices).into_array()?),
+            values: Box::new((*self.values).into_array()?),
+        }))
+    }
+}
+
+impl Context for DictionaryBuilder {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", "Dictionary(..)");
+    }
+}
+
+impl ValueSink for DictionaryBuilder {
+    fn pending(&mut self) -> &mut PendingValue {
+        &mut self.pending
+    }
+
+    fn push_default(&mut self) -> Result<()> {
+        self.indices.serialize_default()
+    }
+
+    fn push_none(&mut self) -> Result<()> {
+        self.indices.serialize_none()
+    }
+
+    fn push_value(&mut self, value: Value) -> Result<()> {
+        let idx = match self.index.get(&value) {
+            Some(idx) => *idx,
+            None => {
+                let idx = self.index.len();
+                value.serialize(Mut(self.values.as_mut()))?;
+                self.index.insert(value, idx);
+                idx
+            }
+        };
+        idx.serialize(Mut(self.indices.as_mut()))
+    }
+}
@@ -4,20 +4,29 @@ use marrow::{
     array::{Array, PrimitiveArray, TimeArray},
     datatypes::TimeUnit,
 };
+use serde::Serialize;
 
 use crate::internal::{
     chrono,
     error::{set_default, try_, Context, ContextSupport, Result},
-    utils::array_ext::{ArrayExt, ScalarArrayExt},
+    utils::{
+        array_ext::{ArrayExt, ScalarArrayExt},
+        std_time::{StdTimeParts, StdTimeType},
+        time_tuple, Mut,
+    },
 };
 
-use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
+use super::{
+    array_builder::ArrayBuilder, simple_serializer::SimpleSerializer, utils::I64Serializer,
+};
 
 #[derive(Debug, Clone)]
 pub struct DurationBuilder {
     path: String,
     pub unit: TimeUnit,
     pub array: PrimitiveArray<i64>,
+    parts: StdTimeParts,
+    tuple: Vec<i64>,
 }
 
 impl DurationBuilder {
@@ -26,6 +35,8 @@ impl DurationBuilder {
             path,
             unit,
             array: PrimitiveArray::new(is_nullable),
+            parts: StdTimeParts::default(),
+            tuple: Vec::new(),
         }
     }
 
@@ -34,6 +45,8 @@ impl DurationBuilder {
             path: self.path.clone(),
             unit: self.unit,
             array: self.array.take(),
+            parts: StdTimeParts::default(),
+            tuple: Vec::new(),
         })
     }
 
@@ -105,4 +118,54 @@ impl SimpleSerializer for DurationBuilder {
         })
         .ctx(self)
     }
+
+    fn serialize_struct_start(&mut self, _: &'static str, _: usize) -> Result<()> {
+        self.parts = StdTimeParts::default();
+        Ok(())
+    }
+
+    fn serialize_struct_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        try_(|| {
+            let mut value_serializer = I64Serializer(0);
+            value.serialize(Mut(&mut value_serializer))?;
+            self.parts
+                .set_component(StdTimeType::Duration, key, value_serializer.0)
+        })
+        .ctx(self)
+    }
+
+    fn serialize_struct_end(&mut self) -> Result<()> {
+        try_(|| {
+            let value = std::mem::take(&mut self.parts).to_arrow(self.unit)?;
+            self.array.push_scalar_value(value)
+        })
+        .ctx(self)
+    }
+
+    fn serialize_tuple_start(&mut self, _: usize) -> Result<()> {
+        self.tuple.clear();
+        Ok(())
+    }
+
+    fn serialize_tuple_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| {
+            let mut value_serializer = I64Serializer(0);
+            value.serialize(Mut(&mut value_serializer))?;
+            self.tuple.push(value_serializer.0);
+            Ok(())
+        })
+        .ctx(self)
+    }
+
+    fn serialize_tuple_end(&mut self) -> Result<()> {
+        try_(|| {
+            let value = time_tuple::duration_from_tuple(&self.tuple, self.unit)?;
+            self.array.push_scalar_value(value)
+        })
+        .ctx(self)
+    }
 }
@@ -0,0 +1,80 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use marrow::array::Array;
+use serde::Serialize;
+
+use crate::internal::{
+    error::{set_default, Context, Result},
+    schema::extensions::{ExtensionType, ExtensionValue},
+    utils::{value::Value, Mut},
+};
+
+use super::{
+    array_builder::ArrayBuilder,
+    simple_serializer::SimpleSerializer,
+    value_collector::{PendingValue, ValueSink},
+};
+
+/// A builder that transforms values with the hooks of a user defined extension type
+#[derive(Debug, Clone)]
+pub struct ExtensionBuilder {
+    path: String,
+    extension: Arc<dyn ExtensionType>,
+    storage: Box<ArrayBuilder>,
+    pending: PendingValue,
+}
+
+impl ExtensionBuilder {
+    pub fn new(path: String, extension: Arc<dyn ExtensionType>, storage: ArrayBuilder) -> Self {
+        Self {
+            path,
+            extension,
+            storage: Box::new(storage),
+            pending: PendingValue::default(),
+        }
+    }
+
+    pub fn take(&mut self) -> ArrayBuilder {
+        ArrayBuilder::Extension(Self {
+            path: self.path.clone(),
+            extension: self.extension.clone(),
+            storage: Box::new(self.storage.take()),
+            pending: PendingValue::default(),
+        })
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.storage.is_nullable()
+    }
+
+    pub fn into_array(self) -> Result<Array> {
+        self.storage.into_array()
+    }
+}
+
+impl Context for ExtensionBuilder {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "extension", self.extension.name());
+        self.storage.annotate(annotations);
+    }
+}
+
+impl ValueSink for ExtensionBuilder {
+    fn pending(&mut self) -> &mut PendingValue {
+        &mut self.pending
+    }
+
+    fn push_default(&mut self) -> Result<()> {
+        self.storage.serialize_default()
+    }
+
+    fn push_none(&mut self) -> Result<()> {
+        self.storage.serialize_none()
+    }
+
+    fn push_value(&mut self, value: Value) -> Result<()> {
+        let ExtensionValue(value) = self.extension.serialize_value(ExtensionValue(value))?;
+        value.serialize(Mut(self.storage.as_mut()))
+    }
+}
@@ -0,0 +1,317 @@
+use std::collections::BTreeMap;
+
+use marrow::array::Array;
+use serde::Serialize;
+
+use crate::internal::{
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    schema::extensions::TensorMetadata,
+    utils::{
+        value::{Value, ValueSerializer},
+        Mut,
+    },
+};
+
+use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
+
+/// A builder for `arrow.fixed_shape_tensor` fields that accepts nested sequences
+///
+/// Each tensor is given either as nested sequences following the logical shape (the physical
+/// shape reordered by the permutation) or as a flat sequence in storage order. Flat tensors are
+/// forwarded element by element to the `FixedSizeList` storage builder. Nested tensors are
+/// collected and flattened into storage order before they are passed on.
+#[derive(Debug, Clone)]
+pub struct FixedShapeTensorBuilder {
+    path: String,
+    shape: Vec<usize>,
+    permutation: Option<Vec<usize>>,
+    storage: Box<ArrayBuilder>,
+    state: TensorState,
+    len: usize,
+}
+
+/// The state of the tensor that is currently being serialized
+#[derive(Debug, Clone, Default)]
+enum TensorState {
+    #[default]
+    Idle,
+    /// A tensor was started, but no element was seen yet
+    Started,
+    /// The elements are forwarded to the storage builder
+    Flat,
+    /// The items of a nested tensor collected so far
+    Nested(Vec<Value>),
+}
+
+impl FixedShapeTensorBuilder {
+    pub fn new(
+        path: String,
+        n: usize,
+        metadata: TensorMetadata,
+        storage: ArrayBuilder,
+    ) -> Result<Self> {
+        let Some(shape) = metadata.shape else {
+            fail!("Fixed shape tensors require a shape");
+        };
+        if shape.iter().product::<usize>() != n {
+            fail!("The shape {shape:?} of the fixed shape tensor does not match the list size {n}");
+        }
+        if let Some(permutation) = metadata.permutation.as_ref() {
+            if permutation.len() != shape.len() {
+                fail!(
+                    "The permutation of the fixed shape tensor must have one entry per dimension"
+                );
+            }
+        }
+
+        Ok(Self {
+            path,
+            shape,
+            permutation: metadata.permutation,
+            storage: Box::new(storage),
+            state: TensorState::Idle,
+            len: 0,
+        })
+    }
+
+    pub fn take(&mut self) -> ArrayBuilder {
+        ArrayBuilder::FixedShapeTensor(Self {
+            path: self.path.clone(),
+            shape: self.shape.clone(),
+            permutation: self.permutation.clone(),
+            storage: Box::new(self.storage.take()),
+            state: TensorState::Idle,
+            len: std::mem::take(&mut self.len),
+        })
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.storage.is_nullable()
+    }
+
+    pub fn into_array(self) -> Result<Array> {
+        self.storage.into_array()
+    }
+}
+
+impl FixedShapeTensorBuilder {
+    fn start(&mut self) -> Result<()> {
+        if !matches!(self.state, TensorState::Idle) {
+            fail!("Invalid state: nested start of a tensor");
+        }
+        // with at most one dimension, nested and flat tensors coincide
+        if self.shape.len() <= 1 {
+            self.storage
+                .serialize_seq_start(Some(self.shape.iter().product()))?;
+            self.state = TensorState::Flat;
+        } else {
+            self.state = TensorState::Started;
+        }
+        Ok(())
+    }
+
+    fn element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        match &mut self.state {
+            TensorState::Flat => self.storage.serialize_seq_element(value),
+            TensorState::Nested(items) => {
+                items.push(value.serialize(ValueSerializer)?);
+                Ok(())
+            }
+            TensorState::Started => {
+                // the first element decides whether the tensor is given as nested sequences
+                let value = value.serialize(ValueSerializer)?;
+                if is_sequence(&value) {
+                    self.state = TensorState::Nested(vec![value]);
+                } else {
+                    self.storage
+                        .serialize_seq_start(Some(self.shape.iter().product()))?;
+                    self.storage.serialize_seq_element(&value)?;
+                    self.state = TensorState::Flat;
+                }
+                Ok(())
+            }
+            TensorState::Idle => fail!("Invalid state: tensor element outside of a tensor"),
+        }
+    }
+
+    fn end(&mut self) -> Result<()> {
+        match std::mem::take(&mut self.state) {
+            TensorState::Flat => self.storage.serialize_seq_end()?,
+            TensorState::Started => {
+                self.storage.serialize_seq_start(Some(0))?;
+                self.storage.serialize_seq_end()?;
+            }
+            TensorState::Nested(items) => {
+                let permutation = self.permutation.as_deref();
+                let logical_shape = logical_shape(&self.shape, permutation);
+                let mut elements = Vec::with_capacity(self.shape.iter().product());
+                collect_tensor_elements(
+                    Value::Seq(items),
+                    &logical_shape,
+                    0,
+                    self.len,
+                    &mut elements,
+                )?;
+                let elements = to_storage_order(elements, &self.shape, permutation);
+                Value::Seq(elements).serialize(Mut(self.storage.as_mut()))?;
+            }
+            TensorState::Idle => fail!("Invalid state: end of a tensor without start"),
+        }
+        self.len += 1;
+        Ok(())
+    }
+}
+
+/// The shape of the nested sequences of a tensor, i.e., the physical shape reordered by the
+/// permutation
+///
+/// The logical dimension `i` corresponds to the physical dimension `permutation[i]`.
+pub fn logical_shape(shape: &[usize], permutation: Option<&[usize]>) -> Vec<usize> {
+    match permutation {
+        Some(permutation) => permutation.iter().map(|&dim| shape[dim]).collect(),
+        None => shape.to_vec(),
+    }
+}
+
+/// Collect the elements of nested sequences in logical C order, checking the shape
+pub fn collect_tensor_elements(
+    value: Value,
+    logical_shape: &[usize],
+    dim: usize,
+    row: usize,
+    elements: &mut Vec<Value>,
+) -> Result<()> {
+    if dim == logical_shape.len() {
+        elements.push(value);
+        return Ok(());
+    }
+    let Some(items) = into_items(value) else {
+        fail!("Shape mismatch in row {row}: expected a sequence for dimension {dim}");
+    };
+    if items.len() != logical_shape[dim] {
+        fail!(
+            "Shape mismatch in row {row}: expected {expected} entries in dimension {dim}, found {actual}",
+            expected = logical_shape[dim],
+            actual = items.len(),
+        );
+    }
+    for item in items {
+        collect_tensor_elements(item, logical_shape, dim + 1, row, elements)?;
+    }
+    Ok(())
+}
+
+/// Reorder elements given in logical C order into physical C order
+pub fn to_storage_order(
+    elements: Vec<Value>,
+    shape: &[usize],
+    permutation: Option<&[usize]>,
+) -> Vec<Value> {
+    let Some(permutation) = permutation else {
+        return elements;
+    };
+    let logical_shape = logical_shape(shape, Some(permutation));
+
+    let mut physical_strides = vec![1; shape.len()];
+    for dim in (0..shape.len().saturating_sub(1)).rev() {
+        physical_strides[dim] = physical_strides[dim + 1] * shape[dim + 1];
+    }
+
+    let mut positions = Vec::with_capacity(elements.len());
+    for logical_idx in 0..elements.len() {
+        let mut remainder = logical_idx;
+        let mut position = 0;
+        for dim in (0..logical_shape.len()).rev() {
+            position += (remainder % logical_shape[dim]) * physical_strides[permutation[dim]];
+            remainder /= logical_shape[dim];
+        }
+        positions.push(position);
+    }
+
+    let mut result = vec![Value::None; elements.len()];
+    for (position, element) in std::iter::zip(positions, elements) {
+        result[position] = element;
+    }
+    result
+}
+
+/// Get the items of a sequence like value
+pub fn into_items(value: Value) -> Option<Vec<Value>> {
+    match value {
+        Value::Seq(items) | Value::Tuple(items) | Value::TupleStruct(_, items) => Some(items),
+        Value::NewtypeStruct(_, value) | Value::Some(value) => into_items(*value),
+        _ => None,
+    }
+}
+
+pub fn is_sequence(value: &Value) -> bool {
+    match value {
+        Value::Seq(_) | Value::Tuple(_) | Value::TupleStruct(_, _) => true,
+        Value::NewtypeStruct(_, value) | Value::Some(value) => is_sequence(value),
+        _ => false,
+    }
+}
+
+impl Context for FixedShapeTensorBuilder {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "extension", "arrow.fixed_shape_tensor");
+        self.storage.annotate(annotations);
+    }
+}
+
+impl SimpleSerializer for FixedShapeTensorBuilder {
+    fn serialize_default(&mut self) -> Result<()> {
+        try_(|| {
+            self.storage.serialize_default()?;
+            self.len += 1;
+            Ok(())
+        })
+        .ctx(self)
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        try_(|| {
+            self.storage.serialize_none()?;
+            self.len += 1;
+            Ok(())
+        })
+        .ctx(self)
+    }
+
+    fn serialize_seq_start(&mut self, _: Option<usize>) -> Result<()> {
+        try_(|| self.start()).ctx(self)
+    }
+
+    fn serialize_seq_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| self.element(value)).ctx(self)
+    }
+
+    fn serialize_seq_end(&mut self) -> Result<()> {
+        try_(|| self.end()).ctx(self)
+    }
+
+    fn serialize_tuple_start(&mut self, _: usize) -> Result<()> {
+        try_(|| self.start()).ctx(self)
+    }
+
+    fn serialize_tuple_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| self.element(value)).ctx(self)
+    }
+
+    fn serialize_tuple_end(&mut self) -> Result<()> {
+        try_(|| self.end()).ctx(self)
+    }
+
+    fn serialize_tuple_struct_start(&mut self, _: &'static str, _: usize) -> Result<()> {
+        try_(|| self.start()).ctx(self)
+    }
+
+    fn serialize_tuple_struct_field<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| self.element(value)).ctx(self)
+    }
+
+    fn serialize_tuple_struct_end(&mut self) -> Result<()> {
+        try_(|| self.end()).ctx(self)
+    }
+}
@@ -7,7 +7,7 @@ use crate::internal::{
     error::{fail, set_default, try_, Context, ContextSupport, Result},
     utils::{
         array_ext::{ArrayExt, CountArray, SeqArrayExt},
-        Mut,
+        uuid, Mut,
     },
 };
 
@@ -21,16 +21,19 @@ pub struct FixedSizeBinaryBuilder {
     pub buffer: Vec<u8>,
     pub current_n: usize,
     pub n: usize,
+    /// Whether the field has the `arrow.uuid` extension and accepts UUID strings
+    pub is_uuid: bool,
 }
 
 impl FixedSizeBinaryBuilder {
-    pub fn new(path: String, n: usize, is_nullable: bool) -> Self {
+    pub fn new(path: String, n: usize, is_uuid: bool, is_nullable: bool) -> Self {
         Self {
             path,
             seq: CountArray::new(is_nullable),
             buffer: Vec::new(),
             n,
             current_n: 0,
+            is_uuid,
         }
     }
 
@@ -41,6 +44,7 @@ impl FixedSizeBinaryBuilder {
             buffer: std::mem::take(&mut self.buffer),
             current_n: std::mem::take(&mut self.current_n),
             n: self.n,
+            is_uuid: self.is_uuid,
         })
     }
 
@@ -151,6 +155,22 @@ impl SimpleSerializer for FixedSizeBinaryBuilder {
         try_(|| self.end()).ctx(self)
     }
 
+    fn serialize_i128(&mut self, v: i128) -> Result<()> {
+        self.serialize_bytes(&v.to_le_bytes())
+    }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        if !self.is_uuid {
+            fail!(in self, "Only FixedSizeBinary(16) fields with the arrow.uuid extension support strings");
+        }
+        let bytes = try_(|| uuid::parse_uuid(v)).ctx(self)?;
+        self.serialize_bytes(&bytes)
+    }
+
+    fn serialize_u128(&mut self, v: u128) -> Result<()> {
+        self.serialize_bytes(&v.to_le_bytes())
+    }
+
     fn serialize_bytes(&mut self, v: &[u8]) -> Result<()> {
         try_(|| {
             if v.len() != self.n {
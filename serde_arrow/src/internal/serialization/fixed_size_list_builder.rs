@@ -8,6 +8,7 @@ use serde::Serialize;
 
 use crate::internal::{
     error::{fail, set_default, try_, Context, ContextSupport, Result},
+    schema::extensions::interleaved_dimensions,
     utils::{
         array_ext::{ArrayExt, CountArray, SeqArrayExt},
         Mut,
@@ -25,6 +26,8 @@ pub struct FixedSizeListBuilder {
     pub n: usize,
     pub current_count: usize,
     pub elements: Box<ArrayBuilder>,
+    /// The field names of structs stored as interleaved GeoArrow coordinates
+    pub dimensions: Option<&'static [&'static str]>,
 }
 
 impl FixedSizeListBuilder {
@@ -35,6 +38,7 @@ impl FixedSizeListBuilder {
         n: usize,
         is_nullable: bool,
     ) -> Self {
+        let dimensions = interleaved_dimensions(&meta.name).filter(|dims| dims.len() == n);
         Self {
             path,
             seq: CountArray::new(is_nullable),
@@ -42,6 +46,7 @@ impl FixedSizeListBuilder {
             n,
             current_count: 0,
             elements: Box::new(element),
+            dimensions,
         }
     }
 
@@ -53,6 +58,7 @@ impl FixedSizeListBuilder {
             n: self.n,
             current_count: std::mem::take(&mut self.current_count),
             elements: Box::new(self.elements.take()),
+            dimensions: self.dimensions,
         })
     }
 
@@ -161,4 +167,38 @@ impl SimpleSerializer for FixedSizeListBuilder {
     fn serialize_tuple_struct_end(&mut self) -> Result<()> {
         try_(|| self.end()).ctx(self)
     }
+
+    // interleaved GeoArrow coordinates are stored as the list of their field values
+    fn serialize_struct_start(&mut self, _: &'static str, _: usize) -> Result<()> {
+        try_(|| {
+            if self.dimensions.is_none() {
+                fail!("Only interleaved GeoArrow coordinates (elements named xy, xyz, xym or xyzm) can be serialized from structs");
+            }
+            self.start()
+        })
+        .ctx(self)
+    }
+
+    fn serialize_struct_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        try_(|| {
+            let expected = self
+                .dimensions
+                .and_then(|dims| dims.get(self.current_count));
+            if let Some(expected) = expected {
+                if key != *expected {
+                    fail!("Expected coordinate {expected:?}, got field {key:?}");
+                }
+            }
+            self.element(value)
+        })
+        .ctx(self)
+    }
+
+    fn serialize_struct_end(&mut self) -> Result<()> {
+        try_(|| self.end()).ctx(self)
+    }
 }
@@ -3,10 +3,10 @@ use std::collections::BTreeMap;
 use marrow::array::{Array, PrimitiveArray};
 
 use crate::internal::{
-    error::{set_default, try_, Context, ContextSupport, Error, Result},
+    error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
     utils::{
         array_ext::{ArrayExt, ScalarArrayExt},
-        NamedType,
+        integral_float_value, NamedType,
     },
 };
 
@@ -16,6 +16,8 @@ use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
 pub struct IntBuilder<I> {
     path: String,
     array: PrimitiveArray<I>,
+    floats: bool,
+    strings: bool,
 }
 
 impl<I: Default + 'static> IntBuilder<I> {
@@ -23,13 +25,29 @@ impl<I: Default + 'static> IntBuilder<I> {
         Self {
             path,
             array: PrimitiveArray::new(is_nullable),
+            floats: false,
+            strings: false,
         }
     }
 
+    /// Accept floats without fractional part, e.g., for narrowed integer fields
+    pub fn with_floats(mut self, value: bool) -> Self {
+        self.floats = value;
+        self
+    }
+
+    /// Accept integers encoded as strings, e.g., for fields traced with guessed types
+    pub fn with_strings(mut self, value: bool) -> Self {
+        self.strings = value;
+        self
+    }
+
     pub fn take_self(&mut self) -> Self {
         Self {
             path: self.path.clone(),
             array: self.array.take(),
+            floats: self.floats,
+            strings: self.strings,
         }
     }
 
@@ -152,7 +170,46 @@ where
         try_(|| self.array.push_scalar_value(I::try_from(v)?)).ctx(self)
     }
 
+    fn serialize_f32(&mut self, v: f32) -> Result<()> {
+        self.serialize_f64(f64::from(v))
+    }
+
+    fn serialize_f64(&mut self, v: f64) -> Result<()> {
+        try_(|| {
+            if !self.floats {
+                fail!("Only integer fields with the SERDE_ARROW:min and SERDE_ARROW:max metadata accept floats");
+            }
+            let Some(value) = integral_float_value(v) else {
+                fail!("Cannot store the non-integral float {v} in an integer field");
+            };
+            if value < 0 {
+                self.array
+                    .push_scalar_value(I::try_from(i64::try_from(value)?)?)
+            } else {
+                self.array
+                    .push_scalar_value(I::try_from(u64::try_from(value)?)?)
+            }
+        })
+        .ctx(self)
+    }
+
     fn serialize_char(&mut self, v: char) -> Result<()> {
         try_(|| self.array.push_scalar_value(I::try_from(u32::from(v))?)).ctx(self)
     }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        try_(|| {
+            if !self.strings {
+                fail!("Only integer fields with the SERDE_ARROW:string_encoded metadata accept strings");
+            }
+            if v.starts_with('-') {
+                self.array
+                    .push_scalar_value(I::try_from(v.parse::<i64>()?)?)
+            } else {
+                self.array
+                    .push_scalar_value(I::try_from(v.parse::<u64>()?)?)
+            }
+        })
+        .ctx(self)
+    }
 }
@@ -0,0 +1,282 @@
+use std::collections::BTreeMap;
+
+use marrow::{
+    array::{Array, PrimitiveArray},
+    types::{DayTimeInterval, MonthDayNanoInterval},
+};
+use serde::Serialize;
+
+use crate::internal::{
+    chrono,
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    utils::{
+        array_ext::{ArrayExt, ScalarArrayExt},
+        Mut,
+    },
+};
+
+use super::{
+    array_builder::ArrayBuilder, simple_serializer::SimpleSerializer, utils::I64Serializer,
+};
+
+/// The value type used while building an interval array
+///
+/// The marrow interval types do not implement `Default`. Therefore, tuples of their components
+/// are used during building and converted into the marrow types in `into_array`:
+///
+/// - `i32`: `Interval(YearMonth)` with the number of months
+/// - `(i32, i32)`: `Interval(DayTime)` with the number of days and milliseconds
+/// - `(i32, i32, i64)`: `Interval(MonthDayNano)` with the number of months, days and nanoseconds
+pub trait IntervalPrimitive: Copy + Default + Sized + 'static {
+    const DATA_TYPE_NAME: &'static str;
+    const ARRAY_BUILDER_VARIANT: fn(IntervalBuilder<Self>) -> ArrayBuilder;
+
+    fn from_parts(parts: IntervalParts) -> Result<Self>;
+    fn into_array(array: PrimitiveArray<Self>) -> Array;
+}
+
+impl IntervalPrimitive for i32 {
+    const DATA_TYPE_NAME: &'static str = "Interval(YearMonth)";
+    const ARRAY_BUILDER_VARIANT: fn(IntervalBuilder<Self>) -> ArrayBuilder =
+        ArrayBuilder::YearMonthInterval;
+
+    fn from_parts(parts: IntervalParts) -> Result<Self> {
+        if parts.days != 0 || parts.nanoseconds != 0 {
+            fail!("Interval(YearMonth) can only store months, found days or a time component");
+        }
+        Ok(i32::try_from(parts.months)?)
+    }
+
+    fn into_array(array: PrimitiveArray<Self>) -> Array {
+        Array::YearMonthInterval(array)
+    }
+}
+
+impl IntervalPrimitive for (i32, i32) {
+    const DATA_TYPE_NAME: &'static str = "Interval(DayTime)";
+    const ARRAY_BUILDER_VARIANT: fn(IntervalBuilder<Self>) -> ArrayBuilder =
+        ArrayBuilder::DayTimeInterval;
+
+    fn from_parts(parts: IntervalParts) -> Result<Self> {
+        if parts.months != 0 {
+            fail!("Interval(DayTime) cannot store months");
+        }
+        if parts.nanoseconds % 1_000_000 != 0 {
+            fail!("Interval(DayTime) cannot store fractions of milliseconds");
+        }
+        Ok((
+            i32::try_from(parts.days)?,
+            i32::try_from(parts.nanoseconds / 1_000_000)?,
+        ))
+    }
+
+    fn into_array(array: PrimitiveArray<Self>) -> Array {
+        Array::DayTimeInterval(PrimitiveArray {
+            validity: array.validity,
+            values: array
+                .values
+                .into_iter()
+                .map(|(days, milliseconds)| DayTimeInterval { days, milliseconds })
+                .collect(),
+        })
+    }
+}
+
+impl IntervalPrimitive for (i32, i32, i64) {
+    const DATA_TYPE_NAME: &'static str = "Interval(MonthDayNano)";
+    const ARRAY_BUILDER_VARIANT: fn(IntervalBuilder<Self>) -> ArrayBuilder =
+        ArrayBuilder::MonthDayNanoInterval;
+
+    fn from_parts(parts: IntervalParts) -> Result<Self> {
+        Ok((
+            i32::try_from(parts.months)?,
+            i32::try_from(parts.days)?,
+            parts.nanoseconds,
+        ))
+    }
+
+    fn into_array(array: PrimitiveArray<Self>) -> Array {
+        Array::MonthDayNanoInterval(PrimitiveArray {
+            validity: array.validity,
+            values: array
+                .values
+                .into_iter()
+                .map(|(months, days, nanoseconds)| MonthDayNanoInterval {
+                    months,
+                    days,
+                    nanoseconds,
+                })
+                .collect(),
+        })
+    }
+}
+
+/// The components of an interval independent of its storage layout
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IntervalParts {
+    pub months: i64,
+    pub days: i64,
+    pub nanoseconds: i64,
+}
+
+impl IntervalParts {
+    fn add_component(&mut self, key: &str, value: i64) -> Result<()> {
+        let (target, factor) = match key {
+            "months" => (&mut self.months, 1),
+            "days" => (&mut self.days, 1),
+            "milliseconds" => (&mut self.nanoseconds, 1_000_000),
+            "nanoseconds" => (&mut self.nanoseconds, 1),
+            _ => fail!(
+                "Unknown interval component {key:?}, expected one of months, days, milliseconds or nanoseconds"
+            ),
+        };
+        let Some(value) = value.checked_mul(factor) else {
+            fail!("Overflow when converting {key} {value} of the interval");
+        };
+        let Some(res) = target.checked_add(value) else {
+            fail!("Overflow when adding {key} {value} to the interval");
+        };
+        *target = res;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IntervalBuilder<I> {
+    path: String,
+    array: PrimitiveArray<I>,
+    parts: IntervalParts,
+    key: Option<String>,
+}
+
+impl<I: IntervalPrimitive> IntervalBuilder<I> {
+    pub fn new(path: String, is_nullable: bool) -> Self {
+        Self {
+            path,
+            array: PrimitiveArray::new(is_nullable),
+            parts: IntervalParts::default(),
+            key: None,
+        }
+    }
+
+    pub fn take(&mut self) -> ArrayBuilder {
+        I::ARRAY_BUILDER_VARIANT(Self {
+            path: self.path.clone(),
+            array: self.array.take(),
+            parts: IntervalParts::default(),
+            key: None,
+        })
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.array.is_nullable()
+    }
+
+    pub fn into_array(self) -> Result<Array> {
+        Ok(I::into_array(self.array))
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.parts = IntervalParts::default();
+        self.key = None;
+        Ok(())
+    }
+
+    fn component<V: Serialize + ?Sized>(&mut self, key: &str, value: &V) -> Result<()> {
+        let mut value_serializer = I64Serializer(0);
+        value.serialize(Mut(&mut value_serializer))?;
+        self.parts.add_component(key, value_serializer.0)
+    }
+
+    fn end(&mut self) -> Result<()> {
+        let value = I::from_parts(std::mem::take(&mut self.parts))?;
+        self.array.push_scalar_value(value)
+    }
+}
+
+impl<I: IntervalPrimitive> Context for IntervalBuilder<I> {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", I::DATA_TYPE_NAME);
+    }
+}
+
+impl<I: IntervalPrimitive> SimpleSerializer for IntervalBuilder<I> {
+    fn serialize_default(&mut self) -> Result<()> {
+        try_(|| self.array.push_scalar_default()).ctx(self)
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        try_(|| self.array.push_scalar_none()).ctx(self)
+    }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        try_(|| {
+            let (months, days, nanoseconds) = chrono::parse_span(v)?.to_arrow_interval()?;
+            let value = I::from_parts(IntervalParts {
+                months,
+                days,
+                nanoseconds,
+            })?;
+            self.array.push_scalar_value(value)
+        })
+        .ctx(self)
+    }
+
+    fn serialize_struct_start(&mut self, _: &'static str, _: usize) -> Result<()> {
+        try_(|| self.start()).ctx(self)
+    }
+
+    fn serialize_struct_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        try_(|| self.component(key, value)).ctx(self)
+    }
+
+    fn serialize_struct_end(&mut self) -> Result<()> {
+        try_(|| self.end()).ctx(self)
+    }
+
+    fn serialize_map_start(&mut self, _: Option<usize>) -> Result<()> {
+        try_(|| self.start()).ctx(self)
+    }
+
+    fn serialize_map_key<V: Serialize + ?Sized>(&mut self, key: &V) -> Result<()> {
+        try_(|| {
+            let mut key_serializer = KeySerializer(None);
+            key.serialize(Mut(&mut key_serializer))?;
+            self.key = key_serializer.0;
+            Ok(())
+        })
+        .ctx(self)
+    }
+
+    fn serialize_map_value<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| {
+            let Some(key) = self.key.take() else {
+                fail!("Interval map value without preceding key");
+            };
+            self.component(&key, value)
+        })
+        .ctx(self)
+    }
+
+    fn serialize_map_end(&mut self) -> Result<()> {
+        try_(|| self.end()).ctx(self)
+    }
+}
+
+struct KeySerializer(Option<String>);
+
+impl Context for KeySerializer {
+    fn annotate(&self, _: &mut BTreeMap<String, String>) {}
+}
+
+impl SimpleSerializer for KeySerializer {
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        self.0 = Some(v.to_owned());
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+use marrow::array::Array;
+
+use crate::internal::{
+    error::{set_default, Context, Result},
+    utils::value::Value,
+};
+
+use super::{
+    array_builder::ArrayBuilder,
+    simple_serializer::SimpleSerializer,
+    value_collector::{PendingValue, ValueSink},
+};
+
+/// A builder that stores arbitrary values as JSON text in a string array
+#[derive(Debug, Clone)]
+pub struct JsonBuilder {
+    path: String,
+    values: Box<ArrayBuilder>,
+    pending: PendingValue,
+}
+
+impl JsonBuilder {
+    pub fn new(path: String, values: ArrayBuilder) -> Self {
+        Self {
+            path,
+            values: Box::new(values),
+            pending: PendingValue::default(),
+        }
+    }
+
+    pub fn take(&mut self) -> ArrayBuilder {
+        ArrayBuilder::Json(Self {
+            path: self.path.clone(),
+            values: Box::new(self.values.take()),
+            pending: PendingValue::default(),
+        })
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.values.is_nullable()
+    }
+
+    pub fn into_array(self) -> Result<Array> {
+        self.values.into_array()
+    }
+}
+
+impl Context for JsonBuilder {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "strategy", "Json");
+        self.values.annotate(annotations);
+    }
+}
+
+impl ValueSink for JsonBuilder {
+    fn pending(&mut self) -> &mut PendingValue {
+        &mut self.pending
+    }
+
+    fn push_default(&mut self) -> Result<()> {
+        self.values.serialize_str("null")
+    }
+
+    fn push_none(&mut self) -> Result<()> {
+        // missing values of non-nullable fields are stored as JSON nulls
+        if self.values.is_nullable() {
+            self.values.serialize_none()
+        } else {
+            self.values.serialize_str("null")
+        }
+    }
+
+    fn push_value(&mut self, value: Value) -> Result<()> {
+        let json = serde_json::to_string(&value)?;
+        self.values.serialize_str(&json)
+    }
+}
@@ -7,7 +7,7 @@ use marrow::{
 use serde::Serialize;
 
 use crate::internal::{
-    error::{set_default, try_, Context, ContextSupport, Result},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
     utils::{
         array_ext::{ArrayExt, OffsetsArray, SeqArrayExt},
         Mut, NamedType, Offset,
@@ -23,6 +23,11 @@ pub struct ListBuilder<O> {
     pub meta: FieldMeta,
     pub elements: Box<ArrayBuilder>,
     pub offsets: OffsetsArray<O>,
+    /// Whether structs with the fields `exterior` and `interiors` are accepted as GeoArrow
+    /// polygons
+    pub polygon: bool,
+    /// Whether the exterior ring of the current polygon struct was serialized
+    pub has_exterior: bool,
 }
 
 impl<O: Offset> ListBuilder<O> {
@@ -32,15 +37,25 @@ impl<O: Offset> ListBuilder<O> {
             meta,
             elements: Box::new(element),
             offsets: OffsetsArray::new(is_nullable),
+            polygon: false,
+            has_exterior: false,
         }
     }
 
+    /// Accept structs with the fields `exterior` and `interiors` as GeoArrow polygons
+    pub fn with_polygon(mut self, polygon: bool) -> Self {
+        self.polygon = polygon;
+        self
+    }
+
     pub fn take_self(&mut self) -> Self {
         Self {
             path: self.path.clone(),
             meta: self.meta.clone(),
             offsets: self.offsets.take(),
             elements: Box::new(self.elements.take()),
+            polygon: self.polygon,
+            has_exterior: false,
         }
     }
 
@@ -92,6 +107,58 @@ impl<O: NamedType + Offset> ListBuilder<O> {
     fn end(&mut self) -> Result<()> {
         self.offsets.end_seq()
     }
+
+    fn start_polygon(&mut self) -> Result<()> {
+        if !self.polygon {
+            fail!("Only lists with the geoarrow.polygon extension accept structs");
+        }
+        self.has_exterior = false;
+        self.start()
+    }
+
+    fn polygon_field<V: Serialize + ?Sized>(&mut self, key: &str, value: &V) -> Result<()> {
+        match key {
+            "exterior" if !self.has_exterior => {
+                self.has_exterior = true;
+                self.element(value)
+            }
+            "interiors" if self.has_exterior => value.serialize(Mut(&mut InteriorRings(self))),
+            "exterior" | "interiors" => {
+                fail!("Polygons must serialize the exterior ring once before the interior rings")
+            }
+            key => fail!("Unknown polygon field {key:?}, expected \"exterior\" or \"interiors\""),
+        }
+    }
+
+    fn end_polygon(&mut self) -> Result<()> {
+        if !self.has_exterior {
+            fail!("Polygons require an exterior ring");
+        }
+        self.end()
+    }
+}
+
+/// Serialize the sequence of interior rings of a polygon as further elements of the list
+struct InteriorRings<'a, O>(&'a mut ListBuilder<O>);
+
+impl<O: NamedType> Context for InteriorRings<'_, O> {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        self.0.annotate(annotations)
+    }
+}
+
+impl<O: NamedType + Offset> SimpleSerializer for InteriorRings<'_, O> {
+    fn serialize_seq_start(&mut self, _: Option<usize>) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_seq_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        self.0.element(value)
+    }
+
+    fn serialize_seq_end(&mut self) -> Result<()> {
+        Ok(())
+    }
 }
 
 impl<O: NamedType> Context for ListBuilder<O> {
@@ -154,6 +221,22 @@ impl<O: NamedType + Offset> SimpleSerializer for ListBuilder<O> {
         try_(|| self.end()).ctx(self)
     }
 
+    fn serialize_struct_start(&mut self, _: &'static str, _: usize) -> Result<()> {
+        try_(|| self.start_polygon()).ctx(self)
+    }
+
+    fn serialize_struct_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        try_(|| self.polygon_field(key, value)).ctx(self)
+    }
+
+    fn serialize_struct_end(&mut self) -> Result<()> {
+        try_(|| self.end_polygon()).ctx(self)
+    }
+
     fn serialize_bytes(&mut self, v: &[u8]) -> Result<()> {
         try_(|| {
             self.start()?;
@@ -5,16 +5,23 @@ pub mod binary_builder;
 pub mod bool_builder;
 pub mod date_builder;
 pub mod decimal_builder;
+pub mod dictionary_builder;
 pub mod dictionary_utf8_builder;
 pub mod duration_builder;
+pub mod extension_builder;
+pub mod fixed_shape_tensor_builder;
 pub mod fixed_size_binary_builder;
 pub mod fixed_size_list_builder;
 pub mod float_builder;
 pub mod int_builder;
+pub mod interval_builder;
+#[cfg(feature = "serde_json")]
+pub mod json_builder;
 pub mod list_builder;
 pub mod map_builder;
 pub mod null_builder;
 pub mod outer_sequence_builder;
+pub mod run_end_encoded_builder;
 pub mod simple_serializer;
 pub mod struct_builder;
 pub mod time_builder;
@@ -22,6 +29,9 @@ pub mod timestamp_builder;
 pub mod union_builder;
 pub mod unknown_variant_builder;
 pub mod utf8_builder;
+pub mod utils;
+pub mod value_collector;
+pub mod variable_shape_tensor_builder;
 
 // #[cfg(test)]
 // mod test;
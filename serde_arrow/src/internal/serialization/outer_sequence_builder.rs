@@ -1,37 +1,47 @@
 use std::collections::{BTreeMap, HashMap};
 
-use marrow::datatypes::{DataType, Field, MapMeta, TimeUnit};
+use marrow::datatypes::{DataType, Field, IntervalUnit, MapMeta, RunEndEncodedMeta, TimeUnit};
 use serde::Serialize;
 
 use crate::internal::{
     error::{fail, Context, ContextSupport, Result},
-    schema::{get_strategy_from_metadata, SerdeArrowSchema, Strategy},
+    schema::{
+        extensions::{has_polygon_extension, is_uuid_field, ExtensionRegistry, TensorMetadata},
+        get_strategy_from_metadata,
+        tracer::IntegerRange,
+        SerdeArrowSchema, Strategy, StringGuess, DATETIME_PATTERN_KEY,
+    },
     serialization::{
         binary_builder::BinaryBuilder, duration_builder::DurationBuilder,
+        extension_builder::ExtensionBuilder, fixed_shape_tensor_builder::FixedShapeTensorBuilder,
         fixed_size_binary_builder::FixedSizeBinaryBuilder,
         fixed_size_list_builder::FixedSizeListBuilder,
+        variable_shape_tensor_builder::VariableShapeTensorBuilder,
     },
     utils::{btree_map, meta_from_field, ChildName, Mut},
 };
 
 use super::{
     bool_builder::BoolBuilder, date_builder::DateBuilder, decimal_builder::DecimalBuilder,
-    dictionary_utf8_builder::DictionaryUtf8Builder, float_builder::FloatBuilder,
-    int_builder::IntBuilder, list_builder::ListBuilder, map_builder::MapBuilder,
-    null_builder::NullBuilder, simple_serializer::SimpleSerializer, struct_builder::StructBuilder,
-    time_builder::TimeBuilder, timestamp_builder::TimestampBuilder, union_builder::UnionBuilder,
-    unknown_variant_builder::UnknownVariantBuilder, utf8_builder::Utf8Builder, ArrayBuilder,
+    dictionary_builder::DictionaryBuilder, dictionary_utf8_builder::DictionaryUtf8Builder,
+    float_builder::FloatBuilder, int_builder::IntBuilder, interval_builder::IntervalBuilder,
+    list_builder::ListBuilder, map_builder::MapBuilder, null_builder::NullBuilder,
+    run_end_encoded_builder::RunEndEncodedBuilder, simple_serializer::SimpleSerializer,
+    struct_builder::StructBuilder, time_builder::TimeBuilder, timestamp_builder::TimestampBuilder,
+    union_builder::UnionBuilder, unknown_variant_builder::UnknownVariantBuilder,
+    utf8_builder::Utf8Builder, ArrayBuilder,
 };
 
 #[derive(Debug, Clone)]
 pub struct OuterSequenceBuilder(StructBuilder);
 
 impl OuterSequenceBuilder {
-    pub fn new(schema: &SerdeArrowSchema) -> Result<Self> {
+    pub fn new(schema: &SerdeArrowSchema, extensions: &ExtensionRegistry) -> Result<Self> {
         Ok(Self(build_struct(
             String::from("$"),
             &schema.fields,
             false,
+            extensions,
         )?))
     }
 
@@ -109,36 +119,122 @@ impl SimpleSerializer for OuterSequenceBuilder {
     }
 }
 
-fn build_struct(path: String, struct_fields: &[Field], nullable: bool) -> Result<StructBuilder> {
+fn build_struct(
+    path: String,
+    struct_fields: &[Field],
+    nullable: bool,
+    extensions: &ExtensionRegistry,
+) -> Result<StructBuilder> {
     let mut fields = Vec::new();
     for field in struct_fields {
         let field_path = format!("{path}.{field_name}", field_name = field.name);
         fields.push((
-            build_builder(field_path, field)?,
+            build_builder(field_path, field, extensions)?,
             meta_from_field(field.clone()),
         ));
     }
     StructBuilder::new(path, fields, nullable)
 }
 
-fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
+#[cfg(feature = "serde_json")]
+fn build_json_builder(
+    path: String,
+    field: &Field,
+    extensions: &ExtensionRegistry,
+) -> Result<ArrayBuilder> {
+    use super::json_builder::JsonBuilder;
+
+    let values_field = Field {
+        metadata: Default::default(),
+        ..field.clone()
+    };
+    let values = build_builder(path.clone(), &values_field, extensions)?;
+    Ok(ArrayBuilder::Json(JsonBuilder::new(path, values)))
+}
+
+#[cfg(not(feature = "serde_json"))]
+fn build_json_builder(
+    path: String,
+    _field: &Field,
+    _extensions: &ExtensionRegistry,
+) -> Result<ArrayBuilder> {
+    fail!(in btree_map!("field" => path), "The Json strategy requires the serde_json feature");
+}
+
+fn build_builder(
+    path: String,
+    field: &Field,
+    extensions: &ExtensionRegistry,
+) -> Result<ArrayBuilder> {
+    let storage = build_storage_builder(path.clone(), field, extensions)?;
+    match extensions.get_for_metadata(&field.metadata) {
+        Some(extension) if extension.has_value_hooks() => Ok(ArrayBuilder::Extension(
+            ExtensionBuilder::new(path, extension.clone(), storage),
+        )),
+        _ => Ok(storage),
+    }
+}
+
+fn build_storage_builder(
+    path: String,
+    field: &Field,
+    extensions: &ExtensionRegistry,
+) -> Result<ArrayBuilder> {
     use {ArrayBuilder as A, DataType as T};
     let ctx: BTreeMap<String, String> = btree_map!("field" => path.clone());
+    let is_narrowed = IntegerRange::from_metadata(&field.metadata)
+        .ctx(&ctx)?
+        .is_some();
+    let is_string_encoded = StringGuess::is_string_encoded(&field.metadata);
 
     let builder = match &field.data_type {
         T::Null => match get_strategy_from_metadata(&field.metadata)? {
             Some(Strategy::UnknownVariant) => A::UnknownVariant(UnknownVariantBuilder::new(path)),
             _ => A::Null(NullBuilder::new(path)),
         },
-        T::Boolean => A::Bool(BoolBuilder::new(path, field.nullable)),
-        T::Int8 => A::I8(IntBuilder::new(path, field.nullable)),
-        T::Int16 => A::I16(IntBuilder::new(path, field.nullable)),
-        T::Int32 => A::I32(IntBuilder::new(path, field.nullable)),
-        T::Int64 => A::I64(IntBuilder::new(path, field.nullable)),
-        T::UInt8 => A::U8(IntBuilder::new(path, field.nullable)),
-        T::UInt16 => A::U16(IntBuilder::new(path, field.nullable)),
-        T::UInt32 => A::U32(IntBuilder::new(path, field.nullable)),
-        T::UInt64 => A::U64(IntBuilder::new(path, field.nullable)),
+        T::Boolean => {
+            A::Bool(BoolBuilder::new(path, field.nullable).with_strings(is_string_encoded))
+        }
+        T::Int8 => A::I8(
+            IntBuilder::new(path, field.nullable)
+                .with_floats(is_narrowed)
+                .with_strings(is_string_encoded),
+        ),
+        T::Int16 => A::I16(
+            IntBuilder::new(path, field.nullable)
+                .with_floats(is_narrowed)
+                .with_strings(is_string_encoded),
+        ),
+        T::Int32 => A::I32(
+            IntBuilder::new(path, field.nullable)
+                .with_floats(is_narrowed)
+                .with_strings(is_string_encoded),
+        ),
+        T::Int64 => A::I64(
+            IntBuilder::new(path, field.nullable)
+                .with_floats(is_narrowed)
+                .with_strings(is_string_encoded),
+        ),
+        T::UInt8 => A::U8(
+            IntBuilder::new(path, field.nullable)
+                .with_floats(is_narrowed)
+                .with_strings(is_string_encoded),
+        ),
+        T::UInt16 => A::U16(
+            IntBuilder::new(path, field.nullable)
+                .with_floats(is_narrowed)
+                .with_strings(is_string_encoded),
+        ),
+        T::UInt32 => A::U32(
+            IntBuilder::new(path, field.nullable)
+                .with_floats(is_narrowed)
+                .with_strings(is_string_encoded),
+        ),
+        T::UInt64 => A::U64(
+            IntBuilder::new(path, field.nullable)
+                .with_floats(is_narrowed)
+                .with_strings(is_string_encoded),
+        ),
         T::Float16 => A::F16(FloatBuilder::new(path, field.nullable)),
         T::Float32 => A::F32(FloatBuilder::new(path, field.nullable)),
         T::Float64 => A::F64(FloatBuilder::new(path, field.nullable)),
@@ -148,6 +244,7 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
             path,
             *unit,
             tz.clone(),
+            field.metadata.get(DATETIME_PATTERN_KEY).cloned(),
             field.nullable,
         )?),
         T::Time32(unit) => {
@@ -163,50 +260,88 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
             A::Time64(TimeBuilder::new(path, *unit, field.nullable))
         }
         T::Duration(unit) => A::Duration(DurationBuilder::new(path, *unit, field.nullable)),
+        T::Interval(IntervalUnit::YearMonth) => {
+            A::YearMonthInterval(IntervalBuilder::new(path, field.nullable))
+        }
+        T::Interval(IntervalUnit::DayTime) => {
+            A::DayTimeInterval(IntervalBuilder::new(path, field.nullable))
+        }
+        T::Interval(IntervalUnit::MonthDayNano) => {
+            A::MonthDayNanoInterval(IntervalBuilder::new(path, field.nullable))
+        }
         T::Decimal128(precision, scale) => A::Decimal128(DecimalBuilder::new(
             path,
             *precision,
             *scale,
             field.nullable,
         )),
+        T::Utf8 | T::LargeUtf8 | T::Utf8View
+            if matches!(
+                get_strategy_from_metadata(&field.metadata)?,
+                Some(Strategy::Json)
+            ) =>
+        {
+            build_json_builder(path, field, extensions)?
+        }
         T::Utf8 => A::Utf8(Utf8Builder::new(path, field.nullable)),
         T::LargeUtf8 => A::LargeUtf8(Utf8Builder::new(path, field.nullable)),
         T::Utf8View => A::Utf8View(Utf8Builder::new(path, field.nullable)),
         T::List(child) => {
             let child_path = format!("{path}.{child_name}", child_name = ChildName(&child.name));
-            A::List(ListBuilder::new(
-                path,
-                meta_from_field(*child.clone()),
-                build_builder(child_path, child.as_ref())?,
-                field.nullable,
-            ))
+            A::List(
+                ListBuilder::new(
+                    path,
+                    meta_from_field(*child.clone()),
+                    build_builder(child_path, child.as_ref(), extensions)?,
+                    field.nullable,
+                )
+                .with_polygon(has_polygon_extension(&field.metadata)),
+            )
         }
         T::LargeList(child) => {
             let child_path = format!("{path}.{child_name}", child_name = ChildName(&child.name));
-            A::LargeList(ListBuilder::new(
-                path,
-                meta_from_field(*child.clone()),
-                build_builder(child_path, child.as_ref())?,
-                field.nullable,
-            ))
+            A::LargeList(
+                ListBuilder::new(
+                    path,
+                    meta_from_field(*child.clone()),
+                    build_builder(child_path, child.as_ref(), extensions)?,
+                    field.nullable,
+                )
+                .with_polygon(has_polygon_extension(&field.metadata)),
+            )
         }
         T::FixedSizeList(child, n) => {
             let child_path = format!("{path}.{child_name}", child_name = ChildName(&child.name));
             let n = usize::try_from(*n).ctx(&ctx)?;
-            A::FixedSizedList(FixedSizeListBuilder::new(
-                path,
+            let builder = A::FixedSizedList(FixedSizeListBuilder::new(
+                path.clone(),
                 meta_from_field(*child.clone()),
-                build_builder(child_path, child.as_ref())?,
+                build_builder(child_path, child.as_ref(), extensions)?,
                 n,
                 field.nullable,
-            ))
+            ));
+
+            let tensor_metadata =
+                TensorMetadata::from_field_metadata(&field.metadata, "arrow.fixed_shape_tensor")
+                    .ctx(&ctx)?;
+            match tensor_metadata {
+                Some(tensor_metadata) => A::FixedShapeTensor(
+                    FixedShapeTensorBuilder::new(path, n, tensor_metadata, builder).ctx(&ctx)?,
+                ),
+                None => builder,
+            }
         }
         T::Binary => A::Binary(BinaryBuilder::new(path, field.nullable)),
         T::LargeBinary => A::LargeBinary(BinaryBuilder::new(path, field.nullable)),
         T::BinaryView => A::BinaryView(BinaryBuilder::new(path, field.nullable)),
         T::FixedSizeBinary(n) => {
             let n = usize::try_from(*n).ctx(&ctx)?;
-            A::FixedSizeBinary(FixedSizeBinaryBuilder::new(path, n, field.nullable))
+            A::FixedSizeBinary(FixedSizeBinaryBuilder::new(
+                path,
+                n,
+                is_uuid_field(field),
+                field.nullable,
+            ))
         }
         T::Map(entry_field, sorted) => {
             let DataType::Struct(entries_field) = &entry_field.data_type else {
@@ -243,14 +378,32 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
                 MapBuilder::new(
                     path,
                     meta,
-                    build_builder(keys_path, keys_field)?,
-                    build_builder(values_path, values_field)?,
+                    build_builder(keys_path, keys_field, extensions)?,
+                    build_builder(values_path, values_field, extensions)?,
                     field.nullable,
                 )
                 .ctx(&ctx)?,
             )
         }
-        T::Struct(children) => A::Struct(build_struct(path, children, field.nullable)?),
+        T::Struct(children) => {
+            let builder = A::Struct(build_struct(
+                path.clone(),
+                children,
+                field.nullable,
+                extensions,
+            )?);
+
+            let tensor_metadata =
+                TensorMetadata::from_field_metadata(&field.metadata, "arrow.variable_shape_tensor")
+                    .ctx(&ctx)?;
+            match tensor_metadata {
+                Some(tensor_metadata) => A::VariableShapeTensor(
+                    VariableShapeTensorBuilder::new(path, children, tensor_metadata, builder)
+                        .ctx(&ctx)?,
+                ),
+                None => builder,
+            }
+        }
         T::Dictionary(key, value) => {
             let key_path = format!("{path}.key");
             let key_field = Field {
@@ -268,27 +421,50 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
                 metadata: HashMap::new(),
             };
 
-            A::DictionaryUtf8(DictionaryUtf8Builder::new(
-                path,
-                build_builder(key_path, &key_field)?,
-                build_builder(value_path, &value_field)?,
-            ))
+            let indices = build_builder(key_path, &key_field, extensions)?;
+            let values = build_builder(value_path, &value_field, extensions)?;
+            if matches!(value.as_ref(), T::Utf8 | T::LargeUtf8) {
+                A::DictionaryUtf8(DictionaryUtf8Builder::new(path, indices, values))
+            } else {
+                A::Dictionary(DictionaryBuilder::new(path, indices, values))
+            }
         }
-        T::Union(union_fields, _) => {
+        T::Union(union_fields, mode) => {
             let mut fields = Vec::new();
+            let mut null_variant = None;
             for (idx, (type_id, field)) in union_fields.iter().enumerate() {
-                if usize::try_from(*type_id) != Ok(idx) {
-                    fail!(in ctx, "Union with non consecutive type ids are not supported");
+                if let Some(Strategy::NullVariant) = get_strategy_from_metadata(&field.metadata)? {
+                    null_variant = Some(idx);
                 }
                 let field_path =
                     format!("{path}.{field_name}", field_name = ChildName(&field.name));
                 fields.push((
-                    build_builder(field_path, field)?,
+                    *type_id,
+                    build_builder(field_path, field, extensions)?,
                     meta_from_field(field.clone()),
                 ));
             }
 
-            A::Union(UnionBuilder::new(path, fields))
+            A::Union(UnionBuilder::new(path, fields, *mode, null_variant))
+        }
+        T::RunEndEncoded(run_ends_field, values_field) => {
+            let values_path = format!(
+                "{path}.{values_name}",
+                values_name = ChildName(&values_field.name),
+            );
+            let meta = RunEndEncodedMeta {
+                run_ends_name: run_ends_field.name.clone(),
+                values: meta_from_field(*values_field.clone()),
+            };
+            A::RunEndEncoded(
+                RunEndEncodedBuilder::new(
+                    path,
+                    meta,
+                    run_ends_field.data_type.clone(),
+                    build_builder(values_path, values_field, extensions)?,
+                )
+                .ctx(&ctx)?,
+            )
         }
         dt => fail!("Cannot build ArrayBuilder for data type {dt:?}"),
     };
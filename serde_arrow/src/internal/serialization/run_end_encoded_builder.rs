@@ -0,0 +1,176 @@
+use std::collections::BTreeMap;
+
+use marrow::{
+    array::{Array, PrimitiveArray, RunEndEncodedArray},
+    datatypes::{DataType, RunEndEncodedMeta},
+};
+use serde::Serialize;
+
+use crate::internal::{
+    error::{fail, set_default, Context, Result},
+    schema::DataTypeDisplay,
+    utils::{value::Value, Mut},
+};
+
+use super::{
+    array_builder::ArrayBuilder,
+    simple_serializer::SimpleSerializer,
+    value_collector::{PendingValue, ValueSink},
+};
+
+/// The value of a run
+///
+/// Default values are kept separate from regular values, as their representation depends on the
+/// values builder.
+/// Floats are compared by their bit patterns: runs of the same NaN value are merged, whereas
+/// `0.0` and `-0.0` start separate runs.
+#[derive(Debug, Clone, PartialEq)]
+enum RunValue {
+    Default,
+    Value(Value),
+}
+
+#[derive(Debug, Clone)]
+pub struct RunEndEncodedBuilder {
+    path: String,
+    meta: RunEndEncodedMeta,
+    run_ends_type: DataType,
+    run_ends: Vec<i64>,
+    values: Box<ArrayBuilder>,
+    last: Option<RunValue>,
+    pending: PendingValue,
+}
+
+impl RunEndEncodedBuilder {
+    pub fn new(
+        path: String,
+        meta: RunEndEncodedMeta,
+        run_ends_type: DataType,
+        values: ArrayBuilder,
+    ) -> Result<Self> {
+        if !matches!(
+            run_ends_type,
+            DataType::Int16 | DataType::Int32 | DataType::Int64
+        ) {
+            fail!("The run ends of RunEndEncoded arrays must be Int16, Int32 or Int64, found {run_ends_type:?}");
+        }
+        Ok(Self {
+            path,
+            meta,
+            run_ends_type,
+            run_ends: Vec::new(),
+            values: Box::new(values),
+            last: None,
+            pending: PendingValue::default(),
+        })
+    }
+
+    pub fn take(&mut self) -> ArrayBuilder {
+        ArrayBuilder::RunEndEncoded(Self {
+            path: self.path.clone(),
+            meta: self.meta.clone(),
+            run_ends_type: self.run_ends_type.clone(),
+            run_ends: std::mem::take(&mut self.run_ends),
+            values: Box::new(self.values.take()),
+            last: self.last.take(),
+            pending: PendingValue::default(),
+        })
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.values.is_nullable()
+    }
+
+    pub fn into_array(self) -> Result<Array> {
+        let run_ends = match self.run_ends_type {
+            DataType::Int16 => Array::Int16(PrimitiveArray {
+                validity: None,
+                values: convert_run_ends(self.run_ends)?,
+            }),
+            DataType::Int32 => Array::Int32(PrimitiveArray {
+                validity: None,
+                values: convert_run_ends(self.run_ends)?,
+            }),
+            DataType::Int64 => Array::Int64(PrimitiveArray {
+                validity: None,
+                values: self.run_ends,
+            }),
+            dt => fail!("Invalid run ends type {dt:?}"),
+        };
+        Ok(Array::RunEndEncoded(RunEndEncodedArray {
+            meta: self.meta,
+            run_ends: Box::new(run_ends),
+            values: Box::new((*self.values).into_array()?),
+        }))
+    }
+}
+
+fn convert_run_ends<I: TryFrom<i64>>(run_ends: Vec<i64>) -> Result<Vec<I>> {
+    let mut result = Vec::with_capacity(run_ends.len());
+    for run_end in run_ends {
+        let Ok(run_end) = I::try_from(run_end) else {
+            fail!("Run end {run_end} cannot be represented with the chosen run ends type");
+        };
+        result.push(run_end);
+    }
+    Ok(result)
+}
+
+impl RunEndEncodedBuilder {
+    /// Push a value, either extending the current run or starting a new one
+    fn push(&mut self, value: RunValue) -> Result<()> {
+        let run_end = self.run_ends.last().copied().unwrap_or_default() + 1;
+        let max_run_end = match self.run_ends_type {
+            DataType::Int16 => i64::from(i16::MAX),
+            DataType::Int32 => i64::from(i32::MAX),
+            _ => i64::MAX,
+        };
+        if run_end > max_run_end {
+            fail!(
+                "Run end {run_end} cannot be represented as {run_ends_type}",
+                run_ends_type = DataTypeDisplay(&self.run_ends_type),
+            );
+        }
+
+        if self.last.as_ref() == Some(&value) {
+            let Some(last_run_end) = self.run_ends.last_mut() else {
+                fail!("Invalid state in RunEndEncoded builder: missing run end");
+            };
+            *last_run_end = run_end;
+            return Ok(());
+        }
+
+        match &value {
+            RunValue::Default => self.values.serialize_default()?,
+            RunValue::Value(value) => value.serialize(Mut(self.values.as_mut()))?,
+        }
+        self.run_ends.push(run_end);
+        self.last = Some(value);
+        Ok(())
+    }
+}
+
+impl Context for RunEndEncodedBuilder {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", "RunEndEncoded(..)");
+    }
+}
+
+impl ValueSink for RunEndEncodedBuilder {
+    fn pending(&mut self) -> &mut PendingValue {
+        &mut self.pending
+    }
+
+    fn push_default(&mut self) -> Result<()> {
+        self.push(RunValue::Default)
+    }
+
+    fn push_none(&mut self) -> Result<()> {
+        self.push(RunValue::Value(Value::None))
+    }
+
+    fn push_value(&mut self, value: Value) -> Result<()> {
+        self.push(RunValue::Value(value))
+    }
+}
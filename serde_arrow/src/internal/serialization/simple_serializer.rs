@@ -79,6 +79,14 @@ pub trait SimpleSerializer: Sized + Context {
         fail!(in self, "serialize_i64 is not supported ")
     }
 
+    fn serialize_i128(&mut self, v: i128) -> Result<()> {
+        fail!(in self, "serialize_i128 is not supported ")
+    }
+
+    fn serialize_u128(&mut self, v: u128) -> Result<()> {
+        fail!(in self, "serialize_u128 is not supported ")
+    }
+
     fn serialize_f32(&mut self, v: f32) -> Result<()> {
         fail!(in self, "serialize_f32 is not supported ")
     }
@@ -321,6 +329,14 @@ impl<'a, T: SimpleSerializer> Serializer for Mut<'a, T> {
         self.0.serialize_i64(v)
     }
 
+    fn serialize_i128(self, v: i128) -> Result<()> {
+        self.0.serialize_i128(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<()> {
+        self.0.serialize_u128(v)
+    }
+
     fn serialize_f32(self, v: f32) -> Result<()> {
         self.0.serialize_f32(v)
     }
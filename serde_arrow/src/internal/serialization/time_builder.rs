@@ -1,26 +1,30 @@
 use std::collections::BTreeMap;
 
-use chrono::Timelike;
+use chrono::{NaiveTime, Timelike};
 use marrow::{
     array::{Array, PrimitiveArray, TimeArray},
     datatypes::TimeUnit,
 };
+use serde::Serialize;
 
 use crate::internal::{
     error::{set_default, try_, Context, ContextSupport, Error, Result},
     utils::{
         array_ext::{ArrayExt, ScalarArrayExt},
-        NamedType,
+        time_tuple, Mut, NamedType,
     },
 };
 
-use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
+use super::{
+    array_builder::ArrayBuilder, simple_serializer::SimpleSerializer, utils::I64Serializer,
+};
 
 #[derive(Debug, Clone)]
 pub struct TimeBuilder<I> {
     path: String,
     pub unit: TimeUnit,
     pub array: PrimitiveArray<I>,
+    tuple: Vec<i64>,
 }
 
 impl<I: Default + 'static> TimeBuilder<I> {
@@ -29,6 +33,7 @@ impl<I: Default + 'static> TimeBuilder<I> {
             path,
             unit,
             array: PrimitiveArray::new(is_nullable),
+            tuple: Vec::new(),
         }
     }
 
@@ -37,12 +42,24 @@ impl<I: Default + 'static> TimeBuilder<I> {
             path: self.path.clone(),
             unit: self.unit,
             array: self.array.take(),
+            tuple: Vec::new(),
         }
     }
 
     pub fn is_nullable(&self) -> bool {
         self.array.is_nullable()
     }
+
+    fn time_to_value(&self, time: NaiveTime) -> i64 {
+        let (seconds_factor, nanoseconds_factor) = match self.unit {
+            TimeUnit::Nanosecond => (1_000_000_000, 1),
+            TimeUnit::Microsecond => (1_000_000, 1_000),
+            TimeUnit::Millisecond => (1_000, 1_000_000),
+            TimeUnit::Second => (1, 1_000_000_000),
+        };
+        i64::from(time.num_seconds_from_midnight()) * seconds_factor
+            + i64::from(time.nanosecond()) / nanoseconds_factor
+    }
 }
 
 impl TimeBuilder<i32> {
@@ -104,18 +121,7 @@ where
 
     fn serialize_str(&mut self, v: &str) -> Result<()> {
         try_(|| {
-            let (seconds_factor, nanoseconds_factor) = match self.unit {
-                TimeUnit::Nanosecond => (1_000_000_000, 1),
-                TimeUnit::Microsecond => (1_000_000, 1_000),
-                TimeUnit::Millisecond => (1_000, 1_000_000),
-                TimeUnit::Second => (1, 1_000_000_000),
-            };
-
-            use chrono::naive::NaiveTime;
-            let time = v.parse::<NaiveTime>()?;
-            let timestamp = i64::from(time.num_seconds_from_midnight()) * seconds_factor
-                + i64::from(time.nanosecond()) / nanoseconds_factor;
-
+            let timestamp = self.time_to_value(v.parse::<NaiveTime>()?);
             self.array.push_scalar_value(timestamp.try_into()?)
         })
         .ctx(self)
@@ -128,4 +134,27 @@ where
     fn serialize_i64(&mut self, v: i64) -> Result<()> {
         try_(|| self.array.push_scalar_value(v.try_into()?)).ctx(self)
     }
+
+    fn serialize_tuple_start(&mut self, _: usize) -> Result<()> {
+        self.tuple.clear();
+        Ok(())
+    }
+
+    fn serialize_tuple_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| {
+            let mut value_serializer = I64Serializer(0);
+            value.serialize(Mut(&mut value_serializer))?;
+            self.tuple.push(value_serializer.0);
+            Ok(())
+        })
+        .ctx(self)
+    }
+
+    fn serialize_tuple_end(&mut self) -> Result<()> {
+        try_(|| {
+            let timestamp = self.time_to_value(time_tuple::time_from_tuple(&self.tuple)?);
+            self.array.push_scalar_value(timestamp.try_into()?)
+        })
+        .ctx(self)
+    }
 }
@@ -1,24 +1,37 @@
 use std::collections::BTreeMap;
 
+use chrono::{DateTime, NaiveDateTime, Utc};
 use marrow::{
     array::{Array, PrimitiveArray, TimestampArray},
     datatypes::TimeUnit,
 };
+use serde::Serialize;
 
 use crate::internal::{
+    chrono::TimeZone,
     error::{fail, set_default, try_, Context, ContextSupport, Result},
-    utils::array_ext::{ArrayExt, ScalarArrayExt},
+    utils::{
+        array_ext::{ArrayExt, ScalarArrayExt},
+        std_time::{StdTimeParts, StdTimeType},
+        time_tuple, Mut,
+    },
 };
 
-use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
+use super::{
+    array_builder::ArrayBuilder, simple_serializer::SimpleSerializer, utils::I64Serializer,
+};
 
 #[derive(Debug, Clone)]
 pub struct TimestampBuilder {
     path: String,
     pub unit: TimeUnit,
     pub timezone: Option<String>,
-    pub utc: bool,
+    pub tz: TimeZone,
+    /// The `chrono` pattern used to parse strings, if not given RFC 3339 strings are expected
+    pub pattern: Option<String>,
     pub array: PrimitiveArray<i64>,
+    parts: StdTimeParts,
+    tuple: Vec<i64>,
 }
 
 impl TimestampBuilder {
@@ -26,14 +39,18 @@ impl TimestampBuilder {
         path: String,
         unit: TimeUnit,
         timezone: Option<String>,
+        pattern: Option<String>,
         is_nullable: bool,
     ) -> Result<Self> {
         Ok(Self {
-            utc: is_utc_tz(timezone.as_deref())?,
+            tz: TimeZone::new(timezone.as_deref())?,
             path,
             unit,
             timezone,
+            pattern,
             array: PrimitiveArray::new(is_nullable),
+            parts: StdTimeParts::default(),
+            tuple: Vec::new(),
         })
     }
 
@@ -42,8 +59,11 @@ impl TimestampBuilder {
             path: self.path.clone(),
             unit: self.unit,
             timezone: self.timezone.clone(),
-            utc: self.utc,
+            tz: self.tz,
+            pattern: self.pattern.clone(),
             array: self.array.take(),
+            parts: StdTimeParts::default(),
+            tuple: Vec::new(),
         })
     }
 
@@ -61,24 +81,40 @@ impl TimestampBuilder {
     }
 }
 
-fn is_utc_tz(tz: Option<&str>) -> Result<bool> {
-    match tz {
-        None => Ok(false),
-        Some(tz) if tz.to_uppercase() == "UTC" => Ok(true),
-        Some(tz) => fail!("Timezone {tz} is not supported"),
-    }
-}
-
 impl TimestampBuilder {
     fn parse_str_to_timestamp(&self, s: &str) -> Result<i64> {
-        use chrono::{DateTime, NaiveDateTime, Utc};
+        let date_time = match (&self.pattern, self.tz) {
+            (None, tz) => tz.parse(s)?,
+            (Some(pattern), TimeZone::Naive) => {
+                NaiveDateTime::parse_from_str(s, pattern)?.and_utc()
+            }
+            (Some(pattern), _) => DateTime::parse_from_str(s, pattern)?.to_utc(),
+        };
+        self.date_time_to_timestamp(date_time)
+    }
 
-        let date_time = if self.utc {
-            s.parse::<DateTime<Utc>>()?
-        } else {
-            s.parse::<NaiveDateTime>()?.and_utc()
+    /// Convert a date time tuple into a timestamp
+    ///
+    /// Timestamps without time zone require tuples without offset, timestamps with time zone
+    /// require tuples with offset.
+    fn tuple_to_timestamp(&self, components: &[i64]) -> Result<i64> {
+        let date_time = match time_tuple::date_time_from_tuple(components)? {
+            (date_time, None) if self.tz == TimeZone::Naive => date_time.and_utc(),
+            (date_time, Some(offset)) if self.tz != TimeZone::Naive => {
+                let Some(date_time) = date_time.and_local_timezone(offset).single() else {
+                    fail!("Cannot interpret {date_time} with offset {offset}");
+                };
+                date_time.to_utc()
+            }
+            (_, None) => fail!("Timestamps with time zone require date times with offset"),
+            (_, Some(_)) => {
+                fail!("Timestamps without time zone cannot store date times with offset")
+            }
         };
+        self.date_time_to_timestamp(date_time)
+    }
 
+    fn date_time_to_timestamp(&self, date_time: DateTime<Utc>) -> Result<i64> {
         match self.unit {
             TimeUnit::Nanosecond => match date_time.timestamp_nanos_opt() {
                 Some(timestamp) => Ok(timestamp),
@@ -125,4 +161,58 @@ impl SimpleSerializer for TimestampBuilder {
     fn serialize_i64(&mut self, v: i64) -> Result<()> {
         try_(|| self.array.push_scalar_value(v)).ctx(self)
     }
+
+    fn serialize_i128(&mut self, v: i128) -> Result<()> {
+        try_(|| self.array.push_scalar_value(i64::try_from(v)?)).ctx(self)
+    }
+
+    fn serialize_struct_start(&mut self, _: &'static str, _: usize) -> Result<()> {
+        self.parts = StdTimeParts::default();
+        Ok(())
+    }
+
+    fn serialize_struct_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        try_(|| {
+            let mut value_serializer = I64Serializer(0);
+            value.serialize(Mut(&mut value_serializer))?;
+            self.parts
+                .set_component(StdTimeType::SystemTime, key, value_serializer.0)
+        })
+        .ctx(self)
+    }
+
+    fn serialize_struct_end(&mut self) -> Result<()> {
+        try_(|| {
+            let value = std::mem::take(&mut self.parts).to_arrow(self.unit)?;
+            self.array.push_scalar_value(value)
+        })
+        .ctx(self)
+    }
+
+    fn serialize_tuple_start(&mut self, _: usize) -> Result<()> {
+        self.tuple.clear();
+        Ok(())
+    }
+
+    fn serialize_tuple_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| {
+            let mut value_serializer = I64Serializer(0);
+            value.serialize(Mut(&mut value_serializer))?;
+            self.tuple.push(value_serializer.0);
+            Ok(())
+        })
+        .ctx(self)
+    }
+
+    fn serialize_tuple_end(&mut self) -> Result<()> {
+        try_(|| {
+            let timestamp = self.tuple_to_timestamp(&self.tuple)?;
+            self.array.push_scalar_value(timestamp)
+        })
+        .ctx(self)
+    }
 }
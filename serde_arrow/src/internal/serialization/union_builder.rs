@@ -1,8 +1,8 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
 use marrow::{
     array::{Array, UnionArray},
-    datatypes::FieldMeta,
+    datatypes::{FieldMeta, UnionMode},
 };
 
 use crate::internal::{
@@ -15,16 +15,44 @@ use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
 #[derive(Debug, Clone)]
 pub struct UnionBuilder {
     pub path: String,
-    pub fields: Vec<(ArrayBuilder, FieldMeta)>,
+    /// The type id, builder and meta data of each variant in the order of the variant index
+    pub fields: Vec<(i8, ArrayBuilder, FieldMeta)>,
+    pub mode: UnionMode,
+    /// The index of the child that stores missing values, if the union is nullable
+    pub null_variant: Option<usize>,
+    /// The child of each named variant
+    pub children_by_name: HashMap<String, usize>,
+    /// Whether the child at a given variant index is not named after a variant
+    pub unnamed_children: Vec<bool>,
     pub types: Vec<i8>,
     pub offsets: Vec<i32>,
     pub current_offset: Vec<i32>,
 }
 
 impl UnionBuilder {
-    pub fn new(path: String, fields: Vec<(ArrayBuilder, FieldMeta)>) -> Self {
+    pub fn new(
+        path: String,
+        fields: Vec<(i8, ArrayBuilder, FieldMeta)>,
+        mode: UnionMode,
+        null_variant: Option<usize>,
+    ) -> Self {
+        let mut children_by_name = HashMap::new();
+        let mut unnamed_children = Vec::new();
+        for (idx, (_, _, meta)) in fields.iter().enumerate() {
+            let is_null_variant = Some(idx) == null_variant;
+            if !is_null_variant {
+                children_by_name.entry(meta.name.clone()).or_insert(idx);
+            }
+            unnamed_children
+                .push(!is_null_variant && (meta.name.is_empty() || meta.name == idx.to_string()));
+        }
+
         Self {
             path,
+            mode,
+            null_variant,
+            children_by_name,
+            unnamed_children,
             current_offset: vec![0; fields.len()],
             types: Vec::new(),
             offsets: Vec::new(),
@@ -38,8 +66,12 @@ impl UnionBuilder {
             fields: self
                 .fields
                 .iter_mut()
-                .map(|(field, meta)| (field.take(), meta.clone()))
+                .map(|(type_id, field, meta)| (*type_id, field.take(), meta.clone()))
                 .collect(),
+            mode: self.mode,
+            null_variant: self.null_variant,
+            children_by_name: self.children_by_name.clone(),
+            unnamed_children: self.unnamed_children.clone(),
             types: std::mem::take(&mut self.types),
             offsets: std::mem::take(&mut self.offsets),
             current_offset: std::mem::replace(&mut self.current_offset, vec![0; self.fields.len()]),
@@ -47,35 +79,70 @@ impl UnionBuilder {
     }
 
     pub fn is_nullable(&self) -> bool {
-        false
+        self.null_variant.is_some()
     }
 
     pub fn into_array(self) -> Result<Array> {
         let mut fields = Vec::new();
-        for (idx, (builder, meta)) in self.fields.into_iter().enumerate() {
-            fields.push((idx.try_into()?, meta, builder.into_array()?));
+        for (type_id, builder, meta) in self.fields {
+            fields.push((type_id, meta, builder.into_array()?));
         }
 
+        let offsets = match self.mode {
+            UnionMode::Dense => Some(self.offsets),
+            UnionMode::Sparse => None,
+        };
+
         Ok(Array::Union(UnionArray {
             types: self.types,
-            offsets: Some(self.offsets),
+            offsets,
             fields,
         }))
     }
 }
 
 impl UnionBuilder {
-    pub fn serialize_variant(&mut self, variant_index: u32) -> Result<&mut ArrayBuilder> {
-        let variant_index = variant_index as usize;
-        let Some((variant_builder, _)) = self.fields.get_mut(variant_index) else {
-            fail!("Could not find variant {variant_index} in Union");
-        };
+    /// Select the child of a variant
+    ///
+    /// Children are matched by the variant name, as done when deserializing. Only if the child at
+    /// the position of the variant index is not named after a variant, i.e., its name is empty
+    /// (as for variants not seen during tracing) or equal to the index, this child is used.
+    pub fn serialize_variant(
+        &mut self,
+        variant_index: u32,
+        variant: &str,
+    ) -> Result<&mut ArrayBuilder> {
+        if let Some(&child) = self.children_by_name.get(variant) {
+            return self.select_child(child);
+        }
 
-        self.offsets.push(self.current_offset[variant_index]);
-        self.types.push(i8::try_from(variant_index)?);
-        self.current_offset[variant_index] += 1;
+        let idx = variant_index as usize;
+        if !self.unnamed_children.get(idx).copied().unwrap_or(false) {
+            fail!("Unknown variant {variant:?} ({variant_index}): no union child with this name");
+        }
+        self.select_child(idx)
+    }
 
-        Ok(variant_builder)
+    fn select_child(&mut self, variant_index: usize) -> Result<&mut ArrayBuilder> {
+        match self.mode {
+            UnionMode::Dense => {
+                self.offsets.push(self.current_offset[variant_index]);
+                self.current_offset[variant_index] += 1;
+            }
+            UnionMode::Sparse => {
+                // in sparse unions all children have the length of the union, fill the inactive
+                // children with default values
+                for (idx, (_, builder, _)) in self.fields.iter_mut().enumerate() {
+                    if idx != variant_index {
+                        builder.serialize_default()?;
+                    }
+                }
+            }
+        }
+        let (type_id, builder, _) = &mut self.fields[variant_index];
+        self.types.push(*type_id);
+
+        Ok(builder)
     }
 }
 
@@ -91,33 +158,52 @@ impl SimpleSerializer for UnionBuilder {
         let mut ctx = BTreeMap::new();
         self.annotate(&mut ctx);
 
-        try_(|| self.serialize_variant(0)?.serialize_unit()).ctx(&ctx)
+        // nullable unions store defaults as missing values
+        let child = self.null_variant.unwrap_or(0);
+        try_(|| self.select_child(child)?.serialize_default()).ctx(&ctx)
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        let mut ctx = BTreeMap::new();
+        self.annotate(&mut ctx);
+
+        try_(|| {
+            let Some(null_variant) = self.null_variant else {
+                fail!("Cannot push null for a union without a NullVariant child");
+            };
+            self.select_child(null_variant)?.serialize_none()
+        })
+        .ctx(&ctx)
     }
 
     fn serialize_unit_variant(
         &mut self,
         _: &'static str,
         variant_index: u32,
-        _: &'static str,
+        variant: &'static str,
     ) -> Result<()> {
         let mut ctx = BTreeMap::new();
         self.annotate(&mut ctx);
 
-        try_(|| self.serialize_variant(variant_index)?.serialize_unit()).ctx(&ctx)
+        try_(|| {
+            self.serialize_variant(variant_index, variant)?
+                .serialize_unit()
+        })
+        .ctx(&ctx)
     }
 
     fn serialize_newtype_variant<V: serde::Serialize + ?Sized>(
         &mut self,
         _: &'static str,
         variant_index: u32,
-        _: &'static str,
+        variant: &'static str,
         value: &V,
     ) -> Result<()> {
         let mut ctx = BTreeMap::new();
         self.annotate(&mut ctx);
 
         try_(|| {
-            let variant_builder = self.serialize_variant(variant_index)?;
+            let variant_builder = self.serialize_variant(variant_index, variant)?;
             value.serialize(Mut(variant_builder))
         })
         .ctx(&ctx)
@@ -134,7 +220,7 @@ impl SimpleSerializer for UnionBuilder {
         self.annotate(&mut ctx);
 
         try_(|| {
-            let variant_builder = self.serialize_variant(variant_index)?;
+            let variant_builder = self.serialize_variant(variant_index, variant)?;
             variant_builder.serialize_struct_start(variant, len)?;
             Ok(variant_builder)
         })
@@ -152,7 +238,7 @@ impl SimpleSerializer for UnionBuilder {
         self.annotate(&mut ctx);
 
         try_(|| {
-            let variant_builder = self.serialize_variant(variant_index)?;
+            let variant_builder = self.serialize_variant(variant_index, variant)?;
             variant_builder.serialize_tuple_struct_start(variant, len)?;
             Ok(variant_builder)
         })
@@ -10,16 +10,19 @@ use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
 #[derive(Debug, Clone)]
 pub struct UnknownVariantBuilder {
     path: String,
+    /// The number of placeholder values, as required to fill sparse unions
+    len: usize,
 }
 
 impl UnknownVariantBuilder {
     pub fn new(path: String) -> Self {
-        UnknownVariantBuilder { path }
+        UnknownVariantBuilder { path, len: 0 }
     }
 
     pub fn take(&mut self) -> ArrayBuilder {
         ArrayBuilder::UnknownVariant(UnknownVariantBuilder {
             path: self.path.clone(),
+            len: std::mem::take(&mut self.len),
         })
     }
 
@@ -28,7 +31,7 @@ impl UnknownVariantBuilder {
     }
 
     pub fn into_array(self) -> Result<Array> {
-        Ok(Array::Null(NullArray { len: 0 }))
+        Ok(Array::Null(NullArray { len: self.len }))
     }
 }
 
@@ -41,7 +44,8 @@ impl Context for UnknownVariantBuilder {
 
 impl SimpleSerializer for UnknownVariantBuilder {
     fn serialize_default(&mut self) -> Result<()> {
-        fail!(in self, "Unknown variant does not support serialize_default")
+        self.len += 1;
+        Ok(())
     }
 
     fn serialize_unit(&mut self) -> Result<()> {
@@ -92,6 +96,14 @@ impl SimpleSerializer for UnknownVariantBuilder {
         fail!(in self, "Unknown variant does not support serialize_i64")
     }
 
+    fn serialize_i128(&mut self, _: i128) -> Result<()> {
+        fail!(in self, "Unknown variant does not support serialize_i128")
+    }
+
+    fn serialize_u128(&mut self, _: u128) -> Result<()> {
+        fail!(in self, "Unknown variant does not support serialize_u128")
+    }
+
     fn serialize_f32(&mut self, _: f32) -> Result<()> {
         fail!(in self, "Unknown variant does not support serialize_f32")
     }
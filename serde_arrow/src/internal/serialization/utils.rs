@@ -0,0 +1,47 @@
+use std::collections::BTreeMap;
+
+use crate::internal::error::{Context, Result};
+
+use super::simple_serializer::SimpleSerializer;
+
+/// Collect an integer value, e.g., the components of structs
+pub struct I64Serializer(pub i64);
+
+impl Context for I64Serializer {
+    fn annotate(&self, _: &mut BTreeMap<String, String>) {}
+}
+
+impl SimpleSerializer for I64Serializer {
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        self.0 = v;
+        Ok(())
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        self.serialize_i64(v.try_into()?)
+    }
+}
@@ -0,0 +1,371 @@
+//! Support for builders that need to inspect complete values before pushing them
+//!
+//! Builders such as the run end encoded or the dictionary builder compare values with previously
+//! seen values. They implement [`ValueSink`] and receive each value as a single [`Value`]. The
+//! [`SimpleSerializer`] implementation assembles compound values (structs, maps, sequences, ...)
+//! from the individual serialization calls.
+use serde::{Serialize, Serializer};
+
+use crate::internal::{
+    error::{fail, try_, Context, ContextSupport, Result},
+    utils::value::{Value, ValueSerializer, Variant},
+};
+
+use super::simple_serializer::SimpleSerializer;
+
+/// A builder that accepts complete values
+pub trait ValueSink: Context {
+    /// Access the state of the currently assembled compound value
+    fn pending(&mut self) -> &mut PendingValue;
+
+    /// Push the default value of the underlying array
+    fn push_default(&mut self) -> Result<()>;
+
+    /// Push a missing value
+    fn push_none(&mut self) -> Result<()>;
+
+    /// Push a complete value
+    fn push_value(&mut self, value: Value) -> Result<()>;
+
+    /// Start a struct variant, the fields are passed to this builder by the caller
+    fn start_struct_variant(
+        &mut self,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<()>
+    where
+        Self: Sized,
+    {
+        try_(|| {
+            self.pending()
+                .start_struct_variant(variant_index, variant, len)
+        })
+        .ctx(self)
+    }
+
+    /// Start a tuple variant, the fields are passed to this builder by the caller
+    fn start_tuple_variant(
+        &mut self,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<()>
+    where
+        Self: Sized,
+    {
+        try_(|| {
+            self.pending()
+                .start_tuple_variant(variant_index, variant, len)
+        })
+        .ctx(self)
+    }
+}
+
+/// A compound value (struct, seq, ...) that is currently being serialized
+#[derive(Debug, Clone, Default)]
+pub struct PendingValue {
+    value: Option<Value>,
+    key: Option<Value>,
+}
+
+impl PendingValue {
+    fn start(&mut self, value: Value) -> Result<()> {
+        if self.value.is_some() {
+            fail!("Invalid state: nested start of compound value");
+        }
+        self.value = Some(value);
+        self.key = None;
+        Ok(())
+    }
+
+    /// Start a struct variant, its fields are added via [`SimpleSerializer::serialize_struct_field`]
+    pub fn start_struct_variant(
+        &mut self,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<()> {
+        self.start(Value::StructVariant(
+            Variant(variant_index, variant),
+            Vec::with_capacity(len),
+        ))
+    }
+
+    /// Start a tuple variant, its fields are added via
+    /// [`SimpleSerializer::serialize_tuple_struct_field`]
+    pub fn start_tuple_variant(
+        &mut self,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<()> {
+        self.start(Value::TupleVariant(
+            Variant(variant_index, variant),
+            Vec::with_capacity(len),
+        ))
+    }
+
+    fn field<V: Serialize + ?Sized>(&mut self, key: &'static str, value: &V) -> Result<()> {
+        let value = value.serialize(ValueSerializer)?;
+        let Some(Value::Struct(_, fields) | Value::StructVariant(_, fields)) = self.value.as_mut()
+        else {
+            fail!("Invalid state: field outside of a struct");
+        };
+        fields.push((key, value));
+        Ok(())
+    }
+
+    fn key<V: Serialize + ?Sized>(&mut self, key: &V) -> Result<()> {
+        self.key = Some(key.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn value<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        let value = value.serialize(ValueSerializer)?;
+        let Some(key) = self.key.take() else {
+            fail!("Invalid state: map value without key");
+        };
+        let Some(Value::Map(entries)) = self.value.as_mut() else {
+            fail!("Invalid state: map value outside of a map");
+        };
+        entries.push((key, value));
+        Ok(())
+    }
+
+    fn element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        let value = value.serialize(ValueSerializer)?;
+        match self.value.as_mut() {
+            Some(
+                Value::Seq(values)
+                | Value::Tuple(values)
+                | Value::TupleStruct(_, values)
+                | Value::TupleVariant(_, values),
+            ) => values.push(value),
+            _ => fail!("Invalid state: element outside of a sequence"),
+        }
+        Ok(())
+    }
+
+    fn end(&mut self) -> Result<Value> {
+        let Some(value) = self.value.take() else {
+            fail!("Invalid state: end without start");
+        };
+        Ok(value)
+    }
+}
+
+fn end<S: ValueSink>(sink: &mut S) -> Result<()> {
+    let value = sink.pending().end()?;
+    sink.push_value(value)
+}
+
+impl<S: ValueSink> SimpleSerializer for S {
+    fn serialize_default(&mut self) -> Result<()> {
+        try_(|| self.push_default()).ctx(self)
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        try_(|| self.push_none()).ctx(self)
+    }
+
+    fn serialize_some<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| self.push_value(value.serialize(ValueSerializer)?)).ctx(self)
+    }
+
+    fn serialize_unit(&mut self) -> Result<()> {
+        try_(|| self.push_value(Value::Unit)).ctx(self)
+    }
+
+    fn serialize_bool(&mut self, v: bool) -> Result<()> {
+        try_(|| self.push_value(Value::Bool(v))).ctx(self)
+    }
+
+    fn serialize_char(&mut self, v: char) -> Result<()> {
+        try_(|| self.push_value(Value::Char(v))).ctx(self)
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        try_(|| self.push_value(Value::U8(v))).ctx(self)
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        try_(|| self.push_value(Value::U16(v))).ctx(self)
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        try_(|| self.push_value(Value::U32(v))).ctx(self)
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        try_(|| self.push_value(Value::U64(v))).ctx(self)
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        try_(|| self.push_value(Value::I8(v))).ctx(self)
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        try_(|| self.push_value(Value::I16(v))).ctx(self)
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        try_(|| self.push_value(Value::I32(v))).ctx(self)
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        try_(|| self.push_value(Value::I64(v))).ctx(self)
+    }
+
+    fn serialize_i128(&mut self, v: i128) -> Result<()> {
+        try_(|| self.push_value(Value::I128(v))).ctx(self)
+    }
+
+    fn serialize_u128(&mut self, v: u128) -> Result<()> {
+        try_(|| self.push_value(Value::U128(v))).ctx(self)
+    }
+
+    fn serialize_f32(&mut self, v: f32) -> Result<()> {
+        try_(|| self.push_value(v.serialize(ValueSerializer)?)).ctx(self)
+    }
+
+    fn serialize_f64(&mut self, v: f64) -> Result<()> {
+        try_(|| self.push_value(v.serialize(ValueSerializer)?)).ctx(self)
+    }
+
+    fn serialize_bytes(&mut self, v: &[u8]) -> Result<()> {
+        try_(|| self.push_value(Value::Bytes(v.to_vec()))).ctx(self)
+    }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        try_(|| self.push_value(Value::String(v.to_owned()))).ctx(self)
+    }
+
+    fn serialize_newtype_struct<V: Serialize + ?Sized>(
+        &mut self,
+        name: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        try_(|| {
+            let value = value.serialize(ValueSerializer)?;
+            self.push_value(Value::NewtypeStruct(name, Box::new(value)))
+        })
+        .ctx(self)
+    }
+
+    fn serialize_unit_struct(&mut self, name: &'static str) -> Result<()> {
+        try_(|| self.push_value(Value::UnitStruct(name))).ctx(self)
+    }
+
+    fn serialize_newtype_variant<V: Serialize + ?Sized>(
+        &mut self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        try_(|| {
+            let value =
+                ValueSerializer.serialize_newtype_variant(name, variant_index, variant, value)?;
+            self.push_value(value)
+        })
+        .ctx(self)
+    }
+
+    fn serialize_unit_variant(
+        &mut self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        try_(|| {
+            let value = ValueSerializer.serialize_unit_variant(name, variant_index, variant)?;
+            self.push_value(value)
+        })
+        .ctx(self)
+    }
+
+    fn serialize_struct_start(&mut self, name: &'static str, len: usize) -> Result<()> {
+        try_(|| {
+            self.pending()
+                .start(Value::Struct(name, Vec::with_capacity(len)))
+        })
+        .ctx(self)
+    }
+
+    fn serialize_struct_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        try_(|| self.pending().field(key, value)).ctx(self)
+    }
+
+    fn serialize_struct_end(&mut self) -> Result<()> {
+        try_(|| end(self)).ctx(self)
+    }
+
+    fn serialize_map_start(&mut self, len: Option<usize>) -> Result<()> {
+        try_(|| {
+            self.pending()
+                .start(Value::Map(Vec::with_capacity(len.unwrap_or_default())))
+        })
+        .ctx(self)
+    }
+
+    fn serialize_map_key<V: Serialize + ?Sized>(&mut self, key: &V) -> Result<()> {
+        try_(|| self.pending().key(key)).ctx(self)
+    }
+
+    fn serialize_map_value<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| self.pending().value(value)).ctx(self)
+    }
+
+    fn serialize_map_end(&mut self) -> Result<()> {
+        try_(|| end(self)).ctx(self)
+    }
+
+    fn serialize_seq_start(&mut self, len: Option<usize>) -> Result<()> {
+        try_(|| {
+            self.pending()
+                .start(Value::Seq(Vec::with_capacity(len.unwrap_or_default())))
+        })
+        .ctx(self)
+    }
+
+    fn serialize_seq_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| self.pending().element(value)).ctx(self)
+    }
+
+    fn serialize_seq_end(&mut self) -> Result<()> {
+        try_(|| end(self)).ctx(self)
+    }
+
+    fn serialize_tuple_start(&mut self, len: usize) -> Result<()> {
+        try_(|| self.pending().start(Value::Tuple(Vec::with_capacity(len)))).ctx(self)
+    }
+
+    fn serialize_tuple_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| self.pending().element(value)).ctx(self)
+    }
+
+    fn serialize_tuple_end(&mut self) -> Result<()> {
+        try_(|| end(self)).ctx(self)
+    }
+
+    fn serialize_tuple_struct_start(&mut self, name: &'static str, len: usize) -> Result<()> {
+        try_(|| {
+            self.pending()
+                .start(Value::TupleStruct(name, Vec::with_capacity(len)))
+        })
+        .ctx(self)
+    }
+
+    fn serialize_tuple_struct_field<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| self.pending().element(value)).ctx(self)
+    }
+
+    fn serialize_tuple_struct_end(&mut self) -> Result<()> {
+        try_(|| end(self)).ctx(self)
+    }
+}
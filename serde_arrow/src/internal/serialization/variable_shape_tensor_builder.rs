@@ -0,0 +1,295 @@
+use std::collections::BTreeMap;
+
+use marrow::{
+    array::Array,
+    datatypes::{DataType, Field},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::internal::{
+    error::{fail, set_default, Context, Result},
+    schema::extensions::TensorMetadata,
+    utils::{
+        value::{Value, ValueDeserializer},
+        Mut,
+    },
+};
+
+use super::{
+    array_builder::ArrayBuilder,
+    fixed_shape_tensor_builder::{
+        collect_tensor_elements, into_items, is_sequence, logical_shape, to_storage_order,
+    },
+    simple_serializer::SimpleSerializer,
+    value_collector::{PendingValue, ValueSink},
+};
+
+/// A builder for `arrow.variable_shape_tensor` fields
+///
+/// Each tensor is given either as nested sequences, whose shape is computed per tensor, or as a
+/// struct with the fields `data` and `shape`. In the struct form, `data` can be nested or flat in
+/// storage order and `shape` is the physical shape. The builder fills both children of the storage
+/// struct and checks the shape against the `uniform_shape` of the extension metadata.
+#[derive(Debug, Clone)]
+pub struct VariableShapeTensorBuilder {
+    path: String,
+    ndim: usize,
+    permutation: Option<Vec<usize>>,
+    uniform_shape: Option<Vec<Option<usize>>>,
+    storage: Box<ArrayBuilder>,
+    pending: PendingValue,
+    len: usize,
+}
+
+impl VariableShapeTensorBuilder {
+    pub fn new(
+        path: String,
+        children: &[Field],
+        metadata: TensorMetadata,
+        storage: ArrayBuilder,
+    ) -> Result<Self> {
+        let ndim = get_ndim(children)?;
+        if let Some(permutation) = metadata.permutation.as_ref() {
+            if permutation.len() != ndim {
+                fail!("The permutation of the variable shape tensor must have one entry per dimension");
+            }
+        }
+        if let Some(uniform_shape) = metadata.uniform_shape.as_ref() {
+            if uniform_shape.len() != ndim {
+                fail!("The uniform shape of the variable shape tensor must have one entry per dimension");
+            }
+        }
+
+        Ok(Self {
+            path,
+            ndim,
+            permutation: metadata.permutation,
+            uniform_shape: metadata.uniform_shape,
+            storage: Box::new(storage),
+            pending: PendingValue::default(),
+            len: 0,
+        })
+    }
+
+    pub fn take(&mut self) -> ArrayBuilder {
+        ArrayBuilder::VariableShapeTensor(Self {
+            path: self.path.clone(),
+            ndim: self.ndim,
+            permutation: self.permutation.clone(),
+            uniform_shape: self.uniform_shape.clone(),
+            storage: Box::new(self.storage.take()),
+            pending: PendingValue::default(),
+            len: std::mem::take(&mut self.len),
+        })
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.storage.is_nullable()
+    }
+
+    pub fn into_array(self) -> Result<Array> {
+        self.storage.into_array()
+    }
+
+    /// Flatten the tensor data into storage order and determine its physical shape
+    fn flatten(&self, data: Value, shape: Option<Vec<usize>>) -> Result<(Vec<Value>, Vec<usize>)> {
+        let row = self.len;
+        let Some(items) = into_items(data) else {
+            fail!("Expected a sequence for the tensor data in row {row}");
+        };
+
+        if self.ndim <= 1 || items.first().is_some_and(|item| !is_sequence(item)) {
+            // flat data is given in storage order
+            let shape = match shape {
+                Some(shape) => shape,
+                None if self.ndim == 1 => vec![items.len()],
+                None if items.is_empty() => vec![0; self.ndim],
+                None => fail!("Flat tensor data in row {row} requires a shape"),
+            };
+            if shape.iter().product::<usize>() != items.len() {
+                fail!(
+                    "Shape mismatch in row {row}: the shape {shape:?} requires {expected} elements, found {actual}",
+                    expected = shape.iter().product::<usize>(),
+                    actual = items.len(),
+                );
+            }
+            return Ok((items, shape));
+        }
+
+        let data = Value::Seq(items);
+        let nested_shape = infer_nested_shape(&data, self.ndim);
+        let permutation = self.permutation.as_deref();
+
+        let mut physical_shape = vec![0; self.ndim];
+        for (dim, &size) in nested_shape.iter().enumerate() {
+            physical_shape[permutation.map(|p| p[dim]).unwrap_or(dim)] = size;
+        }
+        if let Some(shape) = shape {
+            if shape != physical_shape {
+                fail!(
+                    "Shape mismatch in row {row}: the shape {shape:?} does not match the data with shape {physical_shape:?}",
+                );
+            }
+        }
+
+        let mut elements = Vec::with_capacity(physical_shape.iter().product());
+        collect_tensor_elements(
+            data,
+            &logical_shape(&physical_shape, permutation),
+            0,
+            row,
+            &mut elements,
+        )?;
+        let elements = to_storage_order(elements, &physical_shape, permutation);
+
+        Ok((elements, physical_shape))
+    }
+
+    fn check_shape(&self, shape: &[usize]) -> Result<()> {
+        let row = self.len;
+        if shape.len() != self.ndim {
+            fail!(
+                "Shape mismatch in row {row}: expected {ndim} dimensions, found {actual}",
+                ndim = self.ndim,
+                actual = shape.len(),
+            );
+        }
+        if let Some(uniform_shape) = self.uniform_shape.as_ref() {
+            for (dim, (&actual, &expected)) in std::iter::zip(shape, uniform_shape).enumerate() {
+                if let Some(expected) = expected {
+                    if actual != expected {
+                        fail!(
+                            "Shape mismatch in row {row}: dimension {dim} must have the uniform size {expected}, found {actual}",
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn get_ndim(children: &[Field]) -> Result<usize> {
+    for child in children {
+        if child.name == "shape" {
+            let DataType::FixedSizeList(_, n) = &child.data_type else {
+                fail!("The shape of a variable shape tensor must be a FixedSizeList");
+            };
+            return Ok(usize::try_from(*n)?);
+        }
+    }
+    fail!("Variable shape tensors require a shape field");
+}
+
+/// Determine the shape of nested sequences from their first entries
+fn infer_nested_shape(value: &Value, ndim: usize) -> Vec<usize> {
+    let mut shape = Vec::with_capacity(ndim);
+    let mut current = Some(value);
+    for _ in 0..ndim {
+        match current.and_then(sequence_items) {
+            Some(items) => {
+                shape.push(items.len());
+                current = items.first();
+            }
+            None => {
+                shape.push(0);
+                current = None;
+            }
+        }
+    }
+    shape
+}
+
+fn sequence_items(value: &Value) -> Option<&[Value]> {
+    match value {
+        Value::Seq(items) | Value::Tuple(items) | Value::TupleStruct(_, items) => Some(items),
+        Value::NewtypeStruct(_, value) | Value::Some(value) => sequence_items(value),
+        _ => None,
+    }
+}
+
+/// Split a value into the tensor data and the optional shape
+fn split_tensor(value: Value) -> Result<(Value, Option<Vec<usize>>)> {
+    let entries = match value {
+        Value::Struct(_, fields) => fields
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), value))
+            .collect::<Vec<_>>(),
+        Value::Map(entries) => {
+            let mut res = Vec::new();
+            for (key, value) in entries {
+                let key = match key {
+                    Value::String(key) => key,
+                    Value::StaticStr(key) => key.to_owned(),
+                    key => fail!("Invalid key {key:?} in tensor map"),
+                };
+                res.push((key, value));
+            }
+            res
+        }
+        value => return Ok((value, None)),
+    };
+
+    let mut data = None;
+    let mut shape = None;
+    for (key, value) in entries {
+        match key.as_str() {
+            "data" => data = Some(value),
+            "shape" => shape = Some(Vec::<usize>::deserialize(ValueDeserializer::new(&value))?),
+            _ => {
+                fail!("Unexpected field {key:?} in variable shape tensor, expected data and shape")
+            }
+        }
+    }
+    let Some(data) = data else {
+        fail!("Missing field \"data\" in variable shape tensor");
+    };
+    Ok((data, shape))
+}
+
+impl Context for VariableShapeTensorBuilder {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "extension", "arrow.variable_shape_tensor");
+        self.storage.annotate(annotations);
+    }
+}
+
+impl ValueSink for VariableShapeTensorBuilder {
+    fn pending(&mut self) -> &mut PendingValue {
+        &mut self.pending
+    }
+
+    fn push_default(&mut self) -> Result<()> {
+        self.storage.serialize_default()?;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn push_none(&mut self) -> Result<()> {
+        self.storage.serialize_none()?;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn push_value(&mut self, value: Value) -> Result<()> {
+        let (data, shape) = split_tensor(value)?;
+        let (elements, shape) = self.flatten(data, shape)?;
+        self.check_shape(&shape)?;
+
+        let mut shape_values = Vec::with_capacity(shape.len());
+        for size in shape {
+            shape_values.push(Value::I32(size.try_into()?));
+        }
+        let tensor = Value::Struct(
+            "VariableShapeTensor",
+            vec![
+                ("data", Value::Seq(elements)),
+                ("shape", Value::Seq(shape_values)),
+            ],
+        );
+        tensor.serialize(Mut(self.storage.as_mut()))?;
+        self.len += 1;
+        Ok(())
+    }
+}
@@ -283,6 +283,6 @@ impl<A: AsMut<ArrayBuilder>> serde::ser::SerializeTupleVariant for CollectionSer
 }
 
 const _: () = {
-    trait AssertSendSync: Send + Sync {}
-    impl<A: Send + Sync> AssertSendSync for Serializer<A> {}
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Serializer<ArrayBuilder>>();
 };
@@ -34,6 +34,9 @@ impl ViewExt for View<'_> {
             V::Time64(view) => Ok(view.values.len()),
             V::Timestamp(view) => Ok(view.values.len()),
             V::Duration(view) => Ok(view.values.len()),
+            V::YearMonthInterval(view) => Ok(view.values.len()),
+            V::DayTimeInterval(view) => Ok(view.values.len()),
+            V::MonthDayNanoInterval(view) => Ok(view.values.len()),
             V::Decimal128(view) => Ok(view.values.len()),
             V::Utf8(view) => Ok(view.offsets.len().saturating_sub(1)),
             V::Utf8View(view) => Ok(view.data.len()),
@@ -52,6 +55,27 @@ impl ViewExt for View<'_> {
             V::Map(view) => Ok(view.offsets.len().saturating_sub(1)),
             V::Struct(view) => Ok(view.len),
             V::Dictionary(view) => view.keys.len(),
+            V::RunEndEncoded(view) => match view.run_ends.as_ref() {
+                V::Int16(run_ends) => Ok(run_ends
+                    .values
+                    .last()
+                    .copied()
+                    .unwrap_or_default()
+                    .try_into()?),
+                V::Int32(run_ends) => Ok(run_ends
+                    .values
+                    .last()
+                    .copied()
+                    .unwrap_or_default()
+                    .try_into()?),
+                V::Int64(run_ends) => Ok(run_ends
+                    .values
+                    .last()
+                    .copied()
+                    .unwrap_or_default()
+                    .try_into()?),
+                _ => fail!("Unsupported run ends type"),
+            },
             _ => fail!("Unknown view type"),
         }
     }
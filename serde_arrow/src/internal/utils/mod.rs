@@ -2,6 +2,9 @@ pub mod array_ext;
 pub mod array_view_ext;
 pub mod decimal;
 pub mod dsl;
+pub mod std_time;
+pub mod time_tuple;
+pub mod uuid;
 pub mod value;
 
 #[cfg(test)]
@@ -204,6 +207,20 @@ pub fn meta_from_field(field: Field) -> FieldMeta {
     }
 }
 
+/// The integer value of a float without fractional part, if it can be stored in 64 bits
+pub fn integral_float_value(value: f64) -> Option<i128> {
+    // the bounds are exactly representable: -2^63 and 2^64
+    if value.is_finite()
+        && value.fract() == 0.0
+        && value >= i64::MIN as f64
+        && value < u64::MAX as f64
+    {
+        Some(value as i128)
+    } else {
+        None
+    }
+}
+
 macro_rules! btree_map {
     () => {
         ::std::collections::BTreeMap::new()
@@ -230,3 +247,8 @@ impl std::fmt::Display for ChildName<'_> {
         }
     }
 }
+
+/// The path of the child with the given name, e.g., `$.a.b` for the child `b` of `$.a`
+pub fn child_path(path: &str, name: &str) -> String {
+    format!("{path}.{name}", name = ChildName(name))
+}
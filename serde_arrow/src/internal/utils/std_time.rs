@@ -0,0 +1,164 @@
+//! Support for the serde representation of `std::time::Duration` and `std::time::SystemTime`
+//!
+//! Serde serializes `Duration` as a struct `Duration { secs: u64, nanos: u32 }` and `SystemTime`
+//! as a struct `SystemTime { secs_since_epoch: u64, nanos_since_epoch: u32 }`.
+use marrow::datatypes::{DataType, TimeUnit};
+use serde::de::{value::StrDeserializer, DeserializeSeed, IntoDeserializer, MapAccess};
+
+use crate::internal::error::{fail, Error, Result};
+
+pub const DURATION_NAME: &str = "Duration";
+pub const DURATION_FIELDS: &[&str] = &["secs", "nanos"];
+
+pub const SYSTEM_TIME_NAME: &str = "SystemTime";
+pub const SYSTEM_TIME_FIELDS: &[&str] = &["secs_since_epoch", "nanos_since_epoch"];
+
+/// The std time types with a dedicated arrow representation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StdTimeType {
+    Duration,
+    SystemTime,
+}
+
+impl StdTimeType {
+    /// Recognize the std time types by their struct name and their number of fields
+    pub fn from_struct_len(name: &str, len: usize) -> Option<Self> {
+        match (name, len) {
+            (DURATION_NAME, 2) => Some(Self::Duration),
+            (SYSTEM_TIME_NAME, 2) => Some(Self::SystemTime),
+            _ => None,
+        }
+    }
+
+    /// Recognize the std time types by their struct name and their fields
+    pub fn from_struct_fields(name: &str, fields: &[&str]) -> Option<Self> {
+        match name {
+            DURATION_NAME if fields == DURATION_FIELDS => Some(Self::Duration),
+            SYSTEM_TIME_NAME if fields == SYSTEM_TIME_FIELDS => Some(Self::SystemTime),
+            _ => None,
+        }
+    }
+
+    /// The data type used in schema tracing
+    pub fn data_type(self) -> DataType {
+        match self {
+            Self::Duration => DataType::Duration(TimeUnit::Nanosecond),
+            Self::SystemTime => {
+                DataType::Timestamp(TimeUnit::Nanosecond, Some(String::from("UTC")))
+            }
+        }
+    }
+
+    pub fn fields(self) -> &'static [&'static str] {
+        match self {
+            Self::Duration => DURATION_FIELDS,
+            Self::SystemTime => SYSTEM_TIME_FIELDS,
+        }
+    }
+}
+
+/// The seconds and nanoseconds of a std time value
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct StdTimeParts {
+    pub secs: i64,
+    pub nanos: i64,
+}
+
+impl StdTimeParts {
+    /// Set the component with the given field name
+    pub fn set_component(&mut self, ty: StdTimeType, key: &str, value: i64) -> Result<()> {
+        let [secs_key, nanos_key] = ty.fields() else {
+            unreachable!();
+        };
+        if key == *secs_key {
+            self.secs = value;
+        } else if key == *nanos_key {
+            if !(0..1_000_000_000).contains(&value) {
+                fail!("Invalid nanoseconds {value}, expected a value in 0..1_000_000_000");
+            }
+            self.nanos = value;
+        } else {
+            fail!("Unknown field {key:?}, expected {secs_key:?} or {nanos_key:?}");
+        }
+        Ok(())
+    }
+
+    /// Convert the value into an arrow value with the given unit
+    ///
+    /// Sub-unit nanoseconds are truncated.
+    pub fn to_arrow(self, unit: TimeUnit) -> Result<i64> {
+        let (units_per_second, nanos_per_unit) = unit_factors(unit);
+        let Some(value) = self
+            .secs
+            .checked_mul(units_per_second)
+            .and_then(|value| value.checked_add(self.nanos / nanos_per_unit))
+        else {
+            fail!(
+                "Overflow when converting {secs}s {nanos}ns to the unit {unit}",
+                secs = self.secs,
+                nanos = self.nanos,
+            );
+        };
+        Ok(value)
+    }
+
+    /// Split an arrow value with the given unit into seconds and nanoseconds
+    pub fn from_arrow(value: i64, unit: TimeUnit) -> Result<Self> {
+        if value < 0 {
+            fail!("Cannot convert the negative value {value} into a std time value");
+        }
+        let (units_per_second, nanos_per_unit) = unit_factors(unit);
+        Ok(Self {
+            secs: value / units_per_second,
+            nanos: (value % units_per_second) * nanos_per_unit,
+        })
+    }
+}
+
+/// The number of units per second and the number of nanoseconds per unit
+pub fn unit_factors(unit: TimeUnit) -> (i64, i64) {
+    match unit {
+        TimeUnit::Second => (1, 1_000_000_000),
+        TimeUnit::Millisecond => (1_000, 1_000_000),
+        TimeUnit::Microsecond => (1_000_000, 1_000),
+        TimeUnit::Nanosecond => (1_000_000_000, 1),
+    }
+}
+
+/// Deserialize the struct form of a std time value
+pub struct StdTimeAccess {
+    fields: &'static [&'static str],
+    values: [i64; 2],
+    next: usize,
+}
+
+impl StdTimeAccess {
+    pub fn new(ty: StdTimeType, parts: StdTimeParts) -> Self {
+        Self {
+            fields: ty.fields(),
+            values: [parts.secs, parts.nanos],
+            next: 0,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for StdTimeAccess {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let Some(field) = self.fields.get(self.next) else {
+            return Ok(None);
+        };
+        let key = seed.deserialize(StrDeserializer::<Error>::new(field))?;
+        Ok(Some(key))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let Some(value) = self.values.get(self.next) else {
+            fail!("Invalid state in std time deserializer");
+        };
+        let res = seed.deserialize(IntoDeserializer::<Error>::into_deserializer(*value))?;
+        self.next += 1;
+        Ok(res)
+    }
+}
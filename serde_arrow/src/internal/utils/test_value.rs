@@ -66,3 +66,34 @@ fn example_u64() {
     let item: u64 = 42;
     assert_eq!(item, roundtrip(&item));
 }
+
+#[test]
+fn reserialize_values() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum E {
+        A(i32),
+        B { c: Option<String> },
+        D(u8, f32),
+        F,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S {
+        items: Vec<E>,
+        tuple: (bool, char),
+        bytes: Vec<u8>,
+    }
+
+    let item = S {
+        items: vec![E::A(13), E::B { c: None }, E::D(4, 2.0), E::F],
+        tuple: (true, 'x'),
+        bytes: vec![1, 2, 3],
+    };
+    let value = item.serialize(ValueSerializer).unwrap();
+    let reserialized = value.serialize(ValueSerializer).unwrap();
+    assert_eq!(reserialized, value);
+    assert_eq!(
+        S::deserialize(ValueDeserializer::new(&reserialized)).unwrap(),
+        item
+    );
+}
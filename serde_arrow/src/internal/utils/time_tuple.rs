@@ -0,0 +1,154 @@
+//! Support for the tuple representation of the types of the `time` crate
+//!
+//! Without its `serde-human-readable` feature, `time` serializes its types as tuples of their
+//! components:
+//!
+//! - `Date`: `(year, ordinal)`
+//! - `Time`: `(hour, minute, second, nanosecond)`
+//! - `PrimitiveDateTime`: `(year, ordinal, hour, minute, second, nanosecond)`
+//! - `OffsetDateTime`: the components of a `PrimitiveDateTime`, followed by
+//!   `(offset_hours, offset_minutes, offset_seconds)`
+//! - `Duration`: `(seconds, nanoseconds)`, both with the same sign
+use chrono::{Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use marrow::datatypes::{DataType, TimeUnit};
+use serde::de::{value::SeqDeserializer, Visitor};
+
+use crate::internal::{
+    error::{fail, Error, Result},
+    utils::std_time::unit_factors,
+};
+
+/// Visit the components of a tuple as a sequence
+pub fn visit_tuple<'de, V: Visitor<'de>>(visitor: V, components: Vec<i64>) -> Result<V::Value> {
+    visitor.visit_seq(SeqDeserializer::<_, Error>::new(components.into_iter()))
+}
+
+/// Valid components for a tuple of the given length stored in a field of the given data type
+///
+/// Used to trace types with overwritten fields that only accept valid tuples.
+pub fn placeholder(data_type: &DataType, len: usize) -> Option<Vec<i64>> {
+    match (data_type, len) {
+        (DataType::Date32 | DataType::Date64, 2) => Some(vec![1970, 1]),
+        (DataType::Time32(_) | DataType::Time64(_), 4) => Some(vec![0; 4]),
+        (DataType::Timestamp(_, None), 6) => Some(vec![1970, 1, 0, 0, 0, 0]),
+        (DataType::Timestamp(_, Some(_)), 9) => Some(vec![1970, 1, 0, 0, 0, 0, 0, 0, 0]),
+        (DataType::Duration(_), 2) => Some(vec![0, 0]),
+        _ => None,
+    }
+}
+
+pub fn date_from_tuple(components: &[i64]) -> Result<NaiveDate> {
+    let &[year, ordinal] = components else {
+        fail!(
+            "Expected a date tuple (year, ordinal), found {len} components",
+            len = components.len(),
+        );
+    };
+    let Some(date) = NaiveDate::from_yo_opt(i32::try_from(year)?, u32::try_from(ordinal)?) else {
+        fail!("Invalid date tuple ({year}, {ordinal})");
+    };
+    Ok(date)
+}
+
+pub fn date_to_tuple(date: NaiveDate) -> Vec<i64> {
+    vec![i64::from(date.year()), i64::from(date.ordinal())]
+}
+
+pub fn time_from_tuple(components: &[i64]) -> Result<NaiveTime> {
+    let &[hour, minute, second, nanosecond] = components else {
+        fail!(
+            "Expected a time tuple (hour, minute, second, nanosecond), found {len} components",
+            len = components.len(),
+        );
+    };
+    let Some(time) = NaiveTime::from_hms_nano_opt(
+        u32::try_from(hour)?,
+        u32::try_from(minute)?,
+        u32::try_from(second)?,
+        u32::try_from(nanosecond)?,
+    ) else {
+        fail!("Invalid time tuple ({hour}, {minute}, {second}, {nanosecond})");
+    };
+    Ok(time)
+}
+
+pub fn time_to_tuple(time: NaiveTime) -> Vec<i64> {
+    vec![
+        i64::from(time.hour()),
+        i64::from(time.minute()),
+        i64::from(time.second()),
+        i64::from(time.nanosecond()),
+    ]
+}
+
+/// Parse a date time tuple with 6 components or a date time tuple with offset with 9 components
+pub fn date_time_from_tuple(components: &[i64]) -> Result<(NaiveDateTime, Option<FixedOffset>)> {
+    let (date_time, offset) = match components.len() {
+        6 => (components, None),
+        9 => (&components[..6], Some(&components[6..])),
+        len => fail!(
+            concat!(
+                "Expected a date time tuple with 6 components or a date time tuple with offset ",
+                "with 9 components, found {len} components",
+            ),
+            len = len,
+        ),
+    };
+    let date = date_from_tuple(&date_time[..2])?;
+    let time = time_from_tuple(&date_time[2..])?;
+
+    let offset = match offset {
+        Some(&[hours, minutes, seconds]) => {
+            let offset = hours
+                .checked_mul(3_600)
+                .and_then(|offset| offset.checked_add(minutes.checked_mul(60)?))
+                .and_then(|offset| offset.checked_add(seconds))
+                .and_then(|offset| i32::try_from(offset).ok())
+                .and_then(FixedOffset::east_opt);
+            let Some(offset) = offset else {
+                fail!("Invalid offset tuple ({hours}, {minutes}, {seconds})");
+            };
+            Some(offset)
+        }
+        _ => None,
+    };
+    Ok((NaiveDateTime::new(date, time), offset))
+}
+
+/// Build a date time tuple, with offset if given as seconds east of UTC
+pub fn date_time_to_tuple(date_time: NaiveDateTime, offset_seconds: Option<i64>) -> Vec<i64> {
+    let mut components = date_to_tuple(date_time.date());
+    components.extend(time_to_tuple(date_time.time()));
+    if let Some(offset) = offset_seconds {
+        components.extend([offset / 3_600, (offset / 60) % 60, offset % 60]);
+    }
+    components
+}
+
+/// Convert a duration tuple into a value with the given unit
+///
+/// Sub-unit nanoseconds are truncated.
+pub fn duration_from_tuple(components: &[i64], unit: TimeUnit) -> Result<i64> {
+    let &[seconds, nanoseconds] = components else {
+        fail!(
+            "Expected a duration tuple (seconds, nanoseconds), found {len} components",
+            len = components.len(),
+        );
+    };
+    let (units_per_second, nanos_per_unit) = unit_factors(unit);
+    let Some(value) = seconds
+        .checked_mul(units_per_second)
+        .and_then(|value| value.checked_add(nanoseconds / nanos_per_unit))
+    else {
+        fail!("Overflow when converting {seconds}s {nanoseconds}ns to the unit {unit}");
+    };
+    Ok(value)
+}
+
+pub fn duration_to_tuple(value: i64, unit: TimeUnit) -> Vec<i64> {
+    let (units_per_second, nanos_per_unit) = unit_factors(unit);
+    vec![
+        value / units_per_second,
+        (value % units_per_second) * nanos_per_unit,
+    ]
+}
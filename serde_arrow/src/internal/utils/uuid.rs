@@ -0,0 +1,88 @@
+//! UUID support
+//!
+//! UUIDs are stored as 16 bytes in big-endian order, i.e., in the order the hex digits appear in
+//! the canonical hyphenated representation (`xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`).
+
+use crate::internal::error::{fail, Result};
+
+pub const BUFFER_SIZE: usize = 36;
+
+/// The positions of the hyphens in the canonical representation
+const HYPHENS: [usize; 4] = [8, 13, 18, 23];
+
+/// Parse a hyphenated UUID string into its bytes
+pub fn parse_uuid(s: &str) -> Result<[u8; 16]> {
+    let s = s.as_bytes();
+    if s.len() != BUFFER_SIZE || HYPHENS.iter().any(|&pos| s[pos] != b'-') {
+        fail!("Invalid UUID: expected a hyphenated string of the form xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx");
+    }
+
+    let mut digits = s
+        .iter()
+        .enumerate()
+        .filter(|(pos, _)| !HYPHENS.contains(pos));
+    let mut res = [0_u8; 16];
+    for byte in &mut res {
+        let (Some((_, &hi)), Some((_, &lo))) = (digits.next(), digits.next()) else {
+            unreachable!("the length was checked");
+        };
+        *byte = (parse_hex_digit(hi)? << 4) | parse_hex_digit(lo)?;
+    }
+    Ok(res)
+}
+
+/// Format the bytes of a UUID in the canonical lower case hyphenated representation
+pub fn format_uuid<'b>(buffer: &'b mut [u8; BUFFER_SIZE], bytes: &[u8; 16]) -> &'b str {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let mut pos = 0;
+    for (idx, byte) in bytes.iter().enumerate() {
+        if matches!(idx, 4 | 6 | 8 | 10) {
+            buffer[pos] = b'-';
+            pos += 1;
+        }
+        buffer[pos] = DIGITS[(byte >> 4) as usize];
+        buffer[pos + 1] = DIGITS[(byte & 0xf) as usize];
+        pos += 2;
+    }
+
+    // the buffer only contains ASCII characters
+    std::str::from_utf8(buffer).unwrap()
+}
+
+fn parse_hex_digit(c: u8) -> Result<u8> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => fail!("Invalid UUID: invalid hex digit {c:?}", c = c as char),
+    }
+}
+
+#[test]
+fn roundtrip() {
+    let s = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+    let bytes = parse_uuid(s).unwrap();
+    assert_eq!(bytes[..4], [0x67, 0xe5, 0x50, 0x44]);
+
+    let mut buffer = [0; BUFFER_SIZE];
+    assert_eq!(format_uuid(&mut buffer, &bytes), s);
+}
+
+#[test]
+fn upper_case_digits() {
+    let bytes = parse_uuid("67E55044-10B1-426F-9247-BB680E5FE0C8").unwrap();
+    let mut buffer = [0; BUFFER_SIZE];
+    assert_eq!(
+        format_uuid(&mut buffer, &bytes),
+        "67e55044-10b1-426f-9247-bb680e5fe0c8"
+    );
+}
+
+#[test]
+fn invalid_strings() {
+    assert!(parse_uuid("67e55044").is_err());
+    assert!(parse_uuid("67e55044-10b1-426f-9247-bb680e5fe0c").is_err());
+    assert!(parse_uuid("67e55044010b1-426f-9247-bb680e5fe0c8").is_err());
+    assert!(parse_uuid("67e55044-10b1-426f-9247-bb680e5fe0cx").is_err());
+}
@@ -1,10 +1,14 @@
 //! Serialize values into a in-memory representation
-use serde::{de::DeserializeOwned, forward_to_deserialize_any, Serialize};
+use serde::{
+    de::{Deserialize, DeserializeOwned},
+    forward_to_deserialize_any, Serialize,
+};
 
 use crate::internal::error::{fail, Error, Result};
 
+/// An enum variant, given by its index and name
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Variant(u32, &'static str);
+pub struct Variant(pub u32, pub &'static str);
 
 /// A in-memory representation of a Serde value
 ///
@@ -26,6 +30,8 @@ pub enum Value {
     I16(i16),
     I32(i32),
     I64(i64),
+    I128(i128),
+    U128(u128),
     F32(HashF32),
     F64(HashF64),
     StaticStr(&'static str),
@@ -48,8 +54,9 @@ pub enum Value {
     NewtypeVariant(Variant, Box<Value>),
 }
 
+/// A hashable `f32`
 #[derive(Debug, Clone, Copy)]
-pub struct HashF32(f32);
+pub struct HashF32(pub f32);
 
 impl std::cmp::PartialEq<HashF32> for HashF32 {
     fn eq(&self, other: &Self) -> bool {
@@ -65,8 +72,9 @@ impl std::hash::Hash for HashF32 {
     }
 }
 
+/// A hashable `f64`
 #[derive(Debug, Clone, Copy)]
-pub struct HashF64(f64);
+pub struct HashF64(pub f64);
 
 impl std::cmp::PartialEq<HashF64> for HashF64 {
     fn eq(&self, other: &Self) -> bool {
@@ -82,6 +90,195 @@ impl std::hash::Hash for HashF64 {
     }
 }
 
+/// Serialize the value again
+///
+/// Note: enum names are not retained in values. Variants are serialized with an empty enum name.
+impl Serialize for Value {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{
+            SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+            SerializeTupleStruct, SerializeTupleVariant,
+        };
+
+        match self {
+            Value::Bool(v) => serializer.serialize_bool(*v),
+            Value::U8(v) => serializer.serialize_u8(*v),
+            Value::U16(v) => serializer.serialize_u16(*v),
+            Value::U32(v) => serializer.serialize_u32(*v),
+            Value::U64(v) => serializer.serialize_u64(*v),
+            Value::I8(v) => serializer.serialize_i8(*v),
+            Value::I16(v) => serializer.serialize_i16(*v),
+            Value::I32(v) => serializer.serialize_i32(*v),
+            Value::I64(v) => serializer.serialize_i64(*v),
+            Value::I128(v) => serializer.serialize_i128(*v),
+            Value::U128(v) => serializer.serialize_u128(*v),
+            Value::F32(HashF32(v)) => serializer.serialize_f32(*v),
+            Value::F64(HashF64(v)) => serializer.serialize_f64(*v),
+            Value::StaticStr(v) => serializer.serialize_str(v),
+            Value::String(v) => serializer.serialize_str(v),
+            Value::Char(v) => serializer.serialize_char(*v),
+            Value::Bytes(v) => serializer.serialize_bytes(v),
+            Value::None => serializer.serialize_none(),
+            Value::Some(v) => serializer.serialize_some(v.as_ref()),
+            Value::Unit => serializer.serialize_unit(),
+            Value::Tuple(values) => {
+                let mut s = serializer.serialize_tuple(values.len())?;
+                for value in values {
+                    s.serialize_element(value)?;
+                }
+                s.end()
+            }
+            Value::Seq(values) => {
+                let mut s = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    s.serialize_element(value)?;
+                }
+                s.end()
+            }
+            Value::Map(entries) => {
+                let mut s = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    s.serialize_entry(key, value)?;
+                }
+                s.end()
+            }
+            Value::Struct(name, fields) => {
+                let mut s = serializer.serialize_struct(name, fields.len())?;
+                for (key, value) in fields {
+                    s.serialize_field(key, value)?;
+                }
+                s.end()
+            }
+            Value::NewtypeStruct(name, value) => {
+                serializer.serialize_newtype_struct(name, value.as_ref())
+            }
+            Value::UnitStruct(name) => serializer.serialize_unit_struct(name),
+            Value::TupleStruct(name, values) => {
+                let mut s = serializer.serialize_tuple_struct(name, values.len())?;
+                for value in values {
+                    s.serialize_field(value)?;
+                }
+                s.end()
+            }
+            Value::StructVariant(Variant(idx, variant), fields) => {
+                let mut s = serializer.serialize_struct_variant("", *idx, variant, fields.len())?;
+                for (key, value) in fields {
+                    s.serialize_field(key, value)?;
+                }
+                s.end()
+            }
+            Value::TupleVariant(Variant(idx, variant), values) => {
+                let mut s = serializer.serialize_tuple_variant("", *idx, variant, values.len())?;
+                for value in values {
+                    s.serialize_field(value)?;
+                }
+                s.end()
+            }
+            Value::UnitVariant(Variant(idx, variant)) => {
+                serializer.serialize_unit_variant("", *idx, variant)
+            }
+            Value::NewtypeVariant(Variant(idx, variant), value) => {
+                serializer.serialize_newtype_variant("", *idx, variant, value.as_ref())
+            }
+        }
+    }
+}
+
+/// Capture the value of any self-describing deserializer
+///
+/// Note: as field names are not known, structs are captured as maps with string keys. Enums are
+/// not supported.
+impl<'de> serde::de::Deserialize<'de> for Value {
+    fn deserialize<D: serde::de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+macro_rules! impl_visit {
+    ($($method:ident($ty:ty) => $variant:ident),* $(,)?) => {
+        $(
+            fn $method<E: serde::de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                Ok(Value::$variant(v.into()))
+            }
+        )*
+    };
+}
+
+impl<'de> serde::de::Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "any value")
+    }
+
+    impl_visit!(
+        visit_bool(bool) => Bool,
+        visit_i8(i8) => I8,
+        visit_i16(i16) => I16,
+        visit_i32(i32) => I32,
+        visit_i64(i64) => I64,
+        visit_i128(i128) => I128,
+        visit_u8(u8) => U8,
+        visit_u16(u16) => U16,
+        visit_u32(u32) => U32,
+        visit_u64(u64) => U64,
+        visit_u128(u128) => U128,
+        visit_char(char) => Char,
+        visit_str(&str) => String,
+        visit_string(String) => String,
+        visit_bytes(&[u8]) => Bytes,
+        visit_byte_buf(Vec<u8>) => Bytes,
+    );
+
+    fn visit_f32<E: serde::de::Error>(self, v: f32) -> Result<Self::Value, E> {
+        Ok(Value::F32(HashF32(v)))
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Value::F64(HashF64(v)))
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Value::None)
+    }
+
+    fn visit_some<D: serde::de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        Ok(Value::Some(Box::new(Value::deserialize(deserializer)?)))
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Value::Unit)
+    }
+
+    fn visit_newtype_struct<D: serde::de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        Value::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(Value::Seq(values))
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::new();
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(Value::Map(entries))
+    }
+}
+
 pub fn transmute<T: DeserializeOwned>(value: impl Serialize) -> Result<T> {
     let value = value.serialize(ValueSerializer)?;
     T::deserialize(ValueDeserializer::new(&value))
@@ -114,6 +311,8 @@ macro_rules! impl_try_from_value_for_int {
                     &Value::I16(v) => Ok(v.try_into()?),
                     &Value::I32(v) => Ok(v.try_into()?),
                     &Value::I64(v) => Ok(v.try_into()?),
+                    &Value::I128(v) => Ok(v.try_into()?),
+                    &Value::U128(v) => Ok(v.try_into()?),
                     _ => fail!("Cannot extract integer from non-integer value"),
                 }
             }
@@ -180,6 +379,14 @@ impl serde::ser::Serializer for ValueSerializer {
         Ok(Value::I64(v))
     }
 
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok> {
+        Ok(Value::I128(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok> {
+        Ok(Value::U128(v))
+    }
+
     fn serialize_f32(self, v: f32) -> Result<Self::Ok> {
         Ok(Value::F32(HashF32(v)))
     }
@@ -541,6 +748,8 @@ impl<'de> serde::de::Deserializer<'de> for ValueDeserializer<'_> {
             &Value::I16(v) => visitor.visit_i16(v),
             &Value::I32(v) => visitor.visit_i32(v),
             &Value::I64(v) => visitor.visit_i64(v),
+            &Value::I128(v) => visitor.visit_i128(v),
+            &Value::U128(v) => visitor.visit_u128(v),
             &Value::F32(v) => visitor.visit_f32(v.0),
             &Value::F64(v) => visitor.visit_f64(v.0),
             Value::String(v) => visitor.visit_str(v),
@@ -122,6 +122,12 @@
 //! | `arrow2-0-17` | `arrow2=0.17` |
 //! | `arrow2-0-16` | `arrow2=0.16` |
 //!
+//! Further, the `chrono-tz` feature adds support for timestamps with IANA time zones, e.g.,
+//! `Timestamp(Millisecond, Some("Europe/Berlin"))`. Timestamps without time zone, in UTC or with
+//! fixed offsets, e.g., `Some("+05:30")`, are supported without it. The `serde_json` feature adds
+//! support for the `arrow.json` extension type, i.e., fields storing arbitrary values as JSON
+//! text (see [`schema::ext::JsonField`]).
+//!
 //! # Usage in  libraries
 //!
 //! In libraries, it is not recommended to use the `arrow` and `arrow2` functions directly. Rather
@@ -326,18 +332,31 @@ pub mod deserializer {
 ///   depends on the union type: Field-less variants are mapped to `NULL`. New
 ///   type variants are mapped according to their inner type. Other variant
 ///   types are mapped to struct types.
+///
+/// Timestamps with a time zone are deserialized as strings with the offset of the zone, e.g.,
+/// `2024-07-02T13:30:00+05:30`. Only IANA time zones (e.g., `Some("Europe/Berlin")`) add the
+/// zone name in brackets by default, e.g., `2024-07-02T10:00:00+02:00[Europe/Berlin]`; fixed
+/// offsets (e.g., `Some("+05:30")`) are not annotated. Types that require the annotation for
+/// fixed offsets, e.g., `jiff::Zoned`, or reject it for IANA time zones, e.g.,
+/// `chrono::DateTime<FixedOffset>`, need the `SERDE_ARROW:time_zone_annotation` metadata entry
+/// of the field set to `"true"` or `"false"`. Traced schemas never set this entry.
 #[deny(missing_docs)]
 pub mod schema {
     pub use crate::internal::schema::{
-        Overwrites, SchemaLike, SerdeArrowSchema, Strategy, TracingOptions, STRATEGY_KEY,
+        Compatibility, FieldChange, FieldChangeKind, Overwrites, SchemaDiff, SchemaLike,
+        SchemaTracer, SerdeArrowSchema, Strategy, TracingOptions, NULL_VARIANT_NAME, STRATEGY_KEY,
     };
 
-    /// Support for [canonical extension types][ext-docs]. This module is experimental without semver guarantees.
+    /// Support for [canonical extension types][ext-docs] and user defined extension types
+    /// ([`ExtensionType`][ext::ExtensionType]). This module is experimental without semver
+    /// guarantees.
     ///
     /// [ext-docs]: https://arrow.apache.org/docs/format/CanonicalExtensions.html
     pub mod ext {
         pub use crate::internal::schema::extensions::{
-            Bool8Field, FixedShapeTensorField, VariableShapeTensorField,
+            from_value, to_value, Bool8Field, ExtensionField, ExtensionRegistry, ExtensionType,
+            ExtensionValue, FixedShapeTensorField, JsonField, LineStringField, PointField,
+            PolygonField, UuidField, VariableShapeTensorField,
         };
     }
 }
@@ -2,8 +2,11 @@ use marrow::{array::Array, datatypes::Field, view::View};
 use serde::{Deserialize, Serialize};
 
 use crate::internal::{
-    array_builder::ArrayBuilder, deserializer::Deserializer, error::Result,
-    schema::SerdeArrowSchema, serializer::Serializer,
+    array_builder::ArrayBuilder,
+    deserializer::Deserializer,
+    error::Result,
+    schema::{extensions::ExtensionRegistry, SerdeArrowSchema},
+    serializer::Serializer,
 };
 
 /// Build [marrow array][marrow::array::Array] from the given items
@@ -134,4 +137,17 @@ impl<'de> Deserializer<'de> {
     pub fn from_marrow(fields: &[Field], views: &[View<'de>]) -> Result<Self> {
         Self::new(fields, views.to_vec())
     }
+
+    /// Build a deserializer that applies the given user defined extension types
+    ///
+    /// Fields with a registered extension name are validated by the extension type and their
+    /// values are passed through its hooks (see
+    /// [`ExtensionType`][crate::schema::ext::ExtensionType]).
+    pub fn from_marrow_with_extensions(
+        fields: &[Field],
+        views: &[View<'de>],
+        extensions: &ExtensionRegistry,
+    ) -> Result<Self> {
+        Self::new_with_extensions(fields, views.to_vec(), extensions)
+    }
 }
@@ -6,7 +6,7 @@ use crate::{
 
 #[test]
 fn non_sequence() {
-    let res = SerdeArrowSchema::from_samples(&42, TracingOptions::default());
+    let res = SerdeArrowSchema::from_samples(42, TracingOptions::default());
     assert_error_contains(&res, "Cannot trace non-sequences with `from_samples`");
     assert_error_contains(&res, "path: \"$\"");
 }
@@ -20,7 +20,7 @@ fn incompatible_primitives() {
 
 #[test]
 fn number_coercion() {
-    let res = SerdeArrowSchema::from_samples(&(&32.0_f32, 42_u64), TracingOptions::default());
+    let res = SerdeArrowSchema::from_samples((&32.0_f32, 42_u64), TracingOptions::default());
     assert_error_contains(
         &res,
         "consider setting `coerce_numbers` to `true` to coerce different numeric types.",
@@ -1,5 +1,8 @@
 mod api_chrono;
 mod error_messages;
 mod jiff;
+mod schema_diff;
 mod schema_like;
+mod schema_merge;
+mod schema_tracer;
 mod schema_tracing;
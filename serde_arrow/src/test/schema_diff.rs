@@ -0,0 +1,229 @@
+use serde_json::json;
+
+use marrow::datatypes::DataType;
+
+use crate::internal::{
+    error::PanicOnError,
+    schema::{Compatibility, FieldChange, FieldChangeKind, SchemaLike, SerdeArrowSchema, Strategy},
+};
+
+fn schema(value: serde_json::Value) -> SerdeArrowSchema {
+    SerdeArrowSchema::from_value(value).unwrap()
+}
+
+fn compatibilities(old: serde_json::Value, new: serde_json::Value) -> Vec<(String, Compatibility)> {
+    schema(old)
+        .diff(&schema(new))
+        .unwrap()
+        .changes
+        .into_iter()
+        .map(|change| (change.path, change.compatibility))
+        .collect()
+}
+
+#[test]
+fn identical_schemas() -> PanicOnError<()> {
+    let schema = schema(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "b", "data_type": "Struct", "children": [{"name": "c", "data_type": "Utf8"}]},
+    ]));
+    let diff = schema.diff(&schema)?;
+    assert!(diff.is_empty());
+    assert!(diff.is_backward_compatible());
+    assert!(diff.is_forward_compatible());
+    Ok(())
+}
+
+#[test]
+fn added_and_removed_fields() -> PanicOnError<()> {
+    let old = schema(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "b", "data_type": "I32", "nullable": true},
+    ]));
+    let new = schema(json!([
+        {"name": "c", "data_type": "LargeUtf8", "strategy": "Json"},
+        {"name": "d", "data_type": "Bool", "nullable": true},
+    ]));
+
+    let diff = old.diff(&new)?;
+    assert_eq!(
+        diff.changes,
+        vec![
+            FieldChange {
+                path: String::from("a"),
+                kind: FieldChangeKind::Removed {
+                    data_type: DataType::Int32,
+                    nullable: false,
+                    strategy: None,
+                },
+                compatibility: Compatibility::Backward,
+            },
+            FieldChange {
+                path: String::from("b"),
+                kind: FieldChangeKind::Removed {
+                    data_type: DataType::Int32,
+                    nullable: true,
+                    strategy: None,
+                },
+                compatibility: Compatibility::Full,
+            },
+            FieldChange {
+                path: String::from("c"),
+                kind: FieldChangeKind::Added {
+                    data_type: DataType::LargeUtf8,
+                    nullable: false,
+                    strategy: Some(Strategy::Json),
+                },
+                compatibility: Compatibility::Forward,
+            },
+            FieldChange {
+                path: String::from("d"),
+                kind: FieldChangeKind::Added {
+                    data_type: DataType::Boolean,
+                    nullable: true,
+                    strategy: None,
+                },
+                compatibility: Compatibility::Full,
+            },
+        ],
+    );
+    assert!(!diff.is_backward_compatible());
+    assert!(!diff.is_forward_compatible());
+    Ok(())
+}
+
+#[test]
+fn renullabled_fields() {
+    assert_eq!(
+        compatibilities(
+            json!([{"name": "a", "data_type": "I32"}]),
+            json!([{"name": "a", "data_type": "I32", "nullable": true}]),
+        ),
+        vec![(String::from("a"), Compatibility::Backward)],
+    );
+    assert_eq!(
+        compatibilities(
+            json!([{"name": "a", "data_type": "I32", "nullable": true}]),
+            json!([{"name": "a", "data_type": "I32"}]),
+        ),
+        vec![(String::from("a"), Compatibility::Forward)],
+    );
+}
+
+#[test]
+fn retyped_fields() {
+    let cases = [
+        ("I32", "I64", Compatibility::Backward),
+        ("I64", "I32", Compatibility::Forward),
+        ("U8", "I16", Compatibility::Backward),
+        ("I8", "U64", Compatibility::Breaking),
+        ("F32", "F64", Compatibility::Backward),
+        ("I32", "F64", Compatibility::Breaking),
+        ("Utf8", "LargeUtf8", Compatibility::Full),
+        ("Binary", "LargeBinary", Compatibility::Full),
+        ("Utf8", "Binary", Compatibility::Breaking),
+        ("Date32", "Date64", Compatibility::Breaking),
+    ];
+    for (old, new, expected) in cases {
+        assert_eq!(
+            compatibilities(
+                json!([{"name": "a", "data_type": old}]),
+                json!([{"name": "a", "data_type": new}]),
+            ),
+            vec![(String::from("a"), expected)],
+            "{old} -> {new}",
+        );
+    }
+}
+
+#[test]
+fn changed_strategies_are_breaking() {
+    assert_eq!(
+        compatibilities(
+            json!([{"name": "a", "data_type": "LargeUtf8"}]),
+            json!([{"name": "a", "data_type": "LargeUtf8", "strategy": "Json"}]),
+        ),
+        vec![(String::from("a"), Compatibility::Breaking)],
+    );
+}
+
+#[test]
+fn nested_fields() {
+    let old = json!([{
+        "name": "a",
+        "data_type": "List",
+        "children": [{
+            "name": "element",
+            "data_type": "Struct",
+            "children": [{"name": "b", "data_type": "I32"}],
+        }],
+    }]);
+    let new = json!([{
+        "name": "a",
+        "data_type": "LargeList",
+        "children": [{
+            "name": "element",
+            "data_type": "Struct",
+            "children": [
+                {"name": "b", "data_type": "I32", "nullable": true},
+                {"name": "c", "data_type": "Utf8"},
+            ],
+        }],
+    }]);
+
+    assert_eq!(
+        compatibilities(old, new),
+        vec![
+            (String::from("a"), Compatibility::Full),
+            (String::from("a.element.b"), Compatibility::Backward),
+            (String::from("a.element.c"), Compatibility::Forward),
+        ],
+    );
+}
+
+#[test]
+fn empty_names_are_escaped() {
+    let old = json!([{
+        "name": "a",
+        "data_type": "Struct",
+        "children": [{"name": "", "data_type": "I32"}],
+    }]);
+    let new = json!([{
+        "name": "a",
+        "data_type": "Struct",
+        "children": [{"name": "", "data_type": "I64"}],
+    }]);
+
+    assert_eq!(
+        compatibilities(old, new),
+        vec![(String::from("a.<empty>"), Compatibility::Backward)],
+    );
+}
+
+#[test]
+fn display() -> PanicOnError<()> {
+    let old = schema(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "b", "data_type": "I32"},
+    ]));
+    let new = schema(json!([
+        {"name": "a", "data_type": "I64", "nullable": true},
+        {"name": "c", "data_type": "LargeUtf8", "strategy": "Json", "nullable": true},
+    ]));
+    let actual = old
+        .diff(&new)?
+        .changes
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        actual,
+        vec![
+            "retyped a: Int32 -> Int64 (backward compatible)",
+            "renullabled a: non-nullable -> nullable (backward compatible)",
+            "removed b: Int32 (non-nullable) (backward compatible)",
+            "added c: LargeUtf8 (nullable) with strategy Json (fully compatible)",
+        ],
+    );
+    Ok(())
+}
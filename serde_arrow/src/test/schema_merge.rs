@@ -0,0 +1,209 @@
+use serde_json::json;
+
+use crate::internal::{
+    error::PanicOnError,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    testing::assert_error_contains,
+};
+
+fn schema(value: serde_json::Value) -> SerdeArrowSchema {
+    SerdeArrowSchema::from_value(value).unwrap()
+}
+
+#[test]
+fn identical_schemas() -> PanicOnError<()> {
+    let left = schema(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "b", "data_type": "LargeList", "children": [{"name": "element", "data_type": "Utf8"}]},
+    ]));
+    assert_eq!(left.merge(&left, TracingOptions::default())?, left);
+    Ok(())
+}
+
+#[test]
+fn missing_fields_are_nullable() -> PanicOnError<()> {
+    let left = schema(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "b", "data_type": "Bool"},
+    ]));
+    let right = schema(json!([
+        {"name": "b", "data_type": "Bool"},
+        {"name": "c", "data_type": "F64"},
+    ]));
+    let expected = schema(json!([
+        {"name": "a", "data_type": "I32", "nullable": true},
+        {"name": "b", "data_type": "Bool"},
+        {"name": "c", "data_type": "F64", "nullable": true},
+    ]));
+    assert_eq!(left.merge(&right, TracingOptions::default())?, expected);
+    Ok(())
+}
+
+#[test]
+fn nullability_and_null_fields() -> PanicOnError<()> {
+    let left = schema(json!([
+        {"name": "a", "data_type": "I32", "nullable": true},
+        {"name": "b", "data_type": "Null", "nullable": true},
+    ]));
+    let right = schema(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "b", "data_type": "Struct", "children": [{"name": "c", "data_type": "Utf8"}]},
+    ]));
+    let expected = schema(json!([
+        {"name": "a", "data_type": "I32", "nullable": true},
+        {
+            "name": "b",
+            "data_type": "Struct",
+            "nullable": true,
+            "children": [{"name": "c", "data_type": "Utf8"}],
+        },
+    ]));
+    assert_eq!(left.merge(&right, TracingOptions::default())?, expected);
+    assert_eq!(right.merge(&left, TracingOptions::default())?, expected);
+    Ok(())
+}
+
+#[test]
+fn nested_structs_and_lists() -> PanicOnError<()> {
+    let left = schema(json!([{
+        "name": "a",
+        "data_type": "List",
+        "children": [{
+            "name": "element",
+            "data_type": "Struct",
+            "children": [{"name": "x", "data_type": "U8"}],
+        }],
+    }]));
+    let right = schema(json!([{
+        "name": "a",
+        "data_type": "LargeList",
+        "children": [{
+            "name": "element",
+            "data_type": "Struct",
+            "children": [{"name": "x", "data_type": "I16"}, {"name": "y", "data_type": "Utf8"}],
+        }],
+    }]));
+    let expected = schema(json!([{
+        "name": "a",
+        "data_type": "LargeList",
+        "children": [{
+            "name": "element",
+            "data_type": "Struct",
+            "children": [
+                {"name": "x", "data_type": "I64"},
+                {"name": "y", "data_type": "Utf8", "nullable": true},
+            ],
+        }],
+    }]));
+    let options = TracingOptions::default().coerce_numbers(true);
+    assert_eq!(left.merge(&right, options)?, expected);
+    Ok(())
+}
+
+#[test]
+fn numbers_are_widened() -> PanicOnError<()> {
+    let left = schema(json!([
+        {"name": "a", "data_type": "U8"},
+        {"name": "b", "data_type": "I32"},
+        {"name": "c", "data_type": "F32"},
+    ]));
+    let right = schema(json!([
+        {"name": "a", "data_type": "U32"},
+        {"name": "b", "data_type": "U64"},
+        {"name": "c", "data_type": "I64"},
+    ]));
+    let expected = schema(json!([
+        {"name": "a", "data_type": "U64"},
+        {"name": "b", "data_type": "I64"},
+        {"name": "c", "data_type": "F64"},
+    ]));
+    let options = TracingOptions::default().coerce_numbers(true);
+    assert_eq!(left.merge(&right, options)?, expected);
+    Ok(())
+}
+
+#[test]
+fn incompatible_types() {
+    let left = schema(json!([{
+        "name": "a",
+        "data_type": "Struct",
+        "children": [{"name": "b", "data_type": "I32"}],
+    }]));
+    let right = schema(json!([{
+        "name": "a",
+        "data_type": "Struct",
+        "children": [{"name": "b", "data_type": "I64"}],
+    }]));
+
+    let res = left.merge(&right, TracingOptions::default());
+    assert_error_contains(&res, "Cannot merge incompatible types Int32 and Int64");
+    assert_error_contains(&res, "consider setting `coerce_numbers` to `true`");
+    assert_error_contains(&res, "field: \"$.a.b\"");
+
+    let right = schema(json!([{
+        "name": "a",
+        "data_type": "Struct",
+        "children": [{"name": "b", "data_type": "Utf8"}],
+    }]));
+    let res = left.merge(&right, TracingOptions::default().coerce_numbers(true));
+    assert_error_contains(&res, "Cannot merge incompatible types Int32 and Utf8");
+    assert_error_contains(&res, "field: \"$.a.b\"");
+
+    let right = schema(json!([{"name": "a", "data_type": "I32"}]));
+    let res = left.merge(&right, TracingOptions::default());
+    assert_error_contains(&res, "Cannot merge incompatible types Struct and Int32");
+    assert_error_contains(&res, "field: \"$.a\"");
+}
+
+#[test]
+fn metadata_must_agree() {
+    let left = schema(json!([{
+        "name": "a",
+        "data_type": "FixedSizeBinary(16)",
+        "metadata": {"ARROW:extension:name": "arrow.uuid", "ARROW:extension:metadata": ""},
+    }]));
+    let right = schema(json!([{"name": "a", "data_type": "FixedSizeBinary(16)"}]));
+
+    let res = left.merge(&right, TracingOptions::default());
+    assert_error_contains(&res, "Cannot merge fields with different metadata");
+    assert_error_contains(&res, "field: \"$.a\"");
+}
+
+#[test]
+fn strategies() -> PanicOnError<()> {
+    let left = schema(json!([
+        {"name": "a", "data_type": "LargeUtf8", "strategy": "Json"},
+        {"name": "b", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+    ]));
+    let right = schema(json!([
+        {"name": "a", "data_type": "LargeUtf8", "strategy": "Json", "nullable": true},
+        {"name": "b", "data_type": "LargeUtf8"},
+    ]));
+    let expected = schema(json!([
+        {"name": "a", "data_type": "LargeUtf8", "strategy": "Json", "nullable": true},
+        {"name": "b", "data_type": "LargeUtf8"},
+    ]));
+    assert_eq!(left.merge(&right, TracingOptions::default())?, expected);
+
+    let right = schema(json!([{"name": "a", "data_type": "LargeUtf8"}]));
+    let res = left.merge(&right, TracingOptions::default());
+    assert_error_contains(
+        &res,
+        "Cannot merge incompatible types LargeUtf8 with strategy Json and LargeUtf8",
+    );
+    Ok(())
+}
+
+#[test]
+fn narrowed_integers() -> PanicOnError<()> {
+    let options = TracingOptions::default().narrow_integers(true);
+    let left = SerdeArrowSchema::from_samples(json!([{"a": 3, "b": 1}]), options.clone())?;
+    let right = SerdeArrowSchema::from_samples(json!([{"a": -300, "b": 1.5}]), options.clone())?;
+
+    let expected = schema(json!([
+        {"name": "a", "data_type": "I16", "metadata": {"SERDE_ARROW:min": "-300", "SERDE_ARROW:max": "3"}},
+        {"name": "b", "data_type": "F64"},
+    ]));
+    assert_eq!(left.merge(&right, options.coerce_numbers(true))?, expected);
+    Ok(())
+}
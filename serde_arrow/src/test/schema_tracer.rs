@@ -0,0 +1,141 @@
+use serde_json::json;
+
+use crate::internal::{
+    error::PanicOnError,
+    schema::{SchemaLike, SchemaTracer, SerdeArrowSchema, TracingOptions},
+    testing::assert_error_contains,
+};
+
+#[test]
+fn batches_match_from_samples() -> PanicOnError<()> {
+    let samples = json!([
+        {"a": 1, "b": null},
+        {"a": 2, "b": "foo"},
+        {"a": 3},
+        {"a": 4, "b": "bar", "c": [1.0, 2.0]},
+    ]);
+    let expected = SerdeArrowSchema::from_samples(&samples, TracingOptions::default())?;
+
+    let mut tracer = SchemaTracer::new(TracingOptions::default());
+    tracer.trace_samples(&json!([{"a": 1, "b": null}, {"a": 2, "b": "foo"}]))?;
+    tracer.trace_sample(&json!({"a": 3}))?;
+    tracer.trace_samples(&json!([{"a": 4, "b": "bar", "c": [1.0, 2.0]}]))?;
+
+    assert_eq!(tracer.num_samples(), 4);
+    assert_eq!(tracer.to_schema()?, expected);
+    Ok(())
+}
+
+#[test]
+fn schema_snapshots() -> PanicOnError<()> {
+    let mut tracer = SchemaTracer::new(TracingOptions::default());
+    tracer.trace_sample(&json!({"a": 1}))?;
+    assert_eq!(
+        tracer.to_schema()?,
+        SerdeArrowSchema::from_value(json!([{"name": "a", "data_type": "U64"}]))?,
+    );
+
+    tracer.trace_sample(&json!({"a": null}))?;
+    assert_eq!(
+        tracer.to_schema()?,
+        SerdeArrowSchema::from_value(json!([{"name": "a", "data_type": "U64", "nullable": true}]))?,
+    );
+    Ok(())
+}
+
+#[test]
+fn empty_tracer() {
+    let tracer = SchemaTracer::new(TracingOptions::default());
+    assert_error_contains(&tracer.to_schema(), "Encountered null only field $");
+}
+
+#[test]
+fn sample_limit() -> PanicOnError<()> {
+    let mut tracer = SchemaTracer::new(TracingOptions::default()).with_sample_limit(2)?;
+    tracer.trace_samples(&json!([{"a": 1}, {"a": 2}, {"a": "three"}]))?;
+    tracer.trace_sample(&json!({"a": null}))?;
+
+    assert_eq!(tracer.num_samples(), 4);
+    assert_eq!(
+        tracer.to_schema()?,
+        SerdeArrowSchema::from_value(json!([{"name": "a", "data_type": "U64"}]))?,
+    );
+    Ok(())
+}
+
+#[test]
+fn reservoir_sampling_with_enough_capacity() -> PanicOnError<()> {
+    let samples = json!([{"a": 1}, {"a": null, "b": true}, {"a": 3}]);
+    let expected = SerdeArrowSchema::from_samples(&samples, TracingOptions::default())?;
+
+    let mut tracer =
+        SchemaTracer::new(TracingOptions::default()).with_reservoir_sampling(10, 42)?;
+    tracer.trace_samples(&samples)?;
+    assert_eq!(tracer.to_schema()?, expected);
+    Ok(())
+}
+
+#[test]
+fn reservoir_sampling_of_many_samples() -> PanicOnError<()> {
+    let mut tracer = SchemaTracer::new(TracingOptions::default()).with_reservoir_sampling(5, 0)?;
+    for idx in 0..1000 {
+        tracer.trace_sample(&json!({"idx": idx, "flag": idx % 2 == 0}))?;
+    }
+    assert_eq!(tracer.num_samples(), 1000);
+
+    let schema = tracer.to_schema()?;
+    assert_eq!(
+        schema,
+        SerdeArrowSchema::from_value(json!([
+            {"name": "flag", "data_type": "Bool"},
+            {"name": "idx", "data_type": "U64"},
+        ]))?,
+    );
+    Ok(())
+}
+
+#[test]
+fn sampling_must_be_configured_first() -> PanicOnError<()> {
+    let mut tracer = SchemaTracer::new(TracingOptions::default());
+    tracer.trace_sample(&json!({"a": 1}))?;
+
+    let res = tracer.clone().with_sample_limit(10);
+    assert_error_contains(&res, "must be configured before samples are traced");
+
+    let res = tracer.with_reservoir_sampling(10, 0);
+    assert_error_contains(&res, "must be configured before samples are traced");
+    Ok(())
+}
+
+#[test]
+fn errors_are_reported() -> PanicOnError<()> {
+    let mut tracer = SchemaTracer::new(TracingOptions::default());
+    let res = tracer.trace_samples(&json!({"a": 1}));
+    assert_error_contains(&res, "Cannot trace non-sequences");
+
+    tracer.trace_sample(&json!({"a": 1}))?;
+    let res = tracer.trace_sample(&json!({"a": "foo"}));
+    assert_error_contains(&res, "path: \"$.a\"");
+
+    let mut tracer = SchemaTracer::new(TracingOptions::default()).with_reservoir_sampling(5, 0)?;
+    tracer.trace_samples(&json!([{"a": 1}, {"a": "foo"}]))?;
+    assert_error_contains(&tracer.to_schema(), "path: \"$.a\"");
+    Ok(())
+}
+
+#[test]
+fn failing_samples_do_not_modify_the_tracer() -> PanicOnError<()> {
+    let mut tracer = SchemaTracer::new(TracingOptions::default());
+    tracer.trace_sample(&json!({"a": 1}))?;
+
+    let res = tracer.trace_sample(&json!({"a": "foo", "b": true}));
+    assert_error_contains(&res, "path: \"$.a\"");
+
+    tracer.trace_sample(&json!({"a": 2}))?;
+    assert_eq!(tracer.num_samples(), 2);
+    assert_eq!(
+        tracer.to_schema()?,
+        SerdeArrowSchema::from_value(json!([{"name": "a", "data_type": "U64"}]))?,
+    );
+    Ok(())
+}
@@ -3,6 +3,7 @@ use serde_json::json;
 use crate::internal::{
     error::PanicOnError,
     schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    testing::assert_error_contains,
 };
 
 /// A mixture of nulls and strings is parsed as a nullable LargeUtf8
@@ -441,3 +442,225 @@ mod untagged_enum_number_coercion {
         [(Num::U8(0),), (Num::U16(0),), (Num::Null(()),)]
     );
 }
+
+mod narrow_integers {
+    use serde::Deserialize;
+
+    use super::*;
+
+    fn options() -> TracingOptions {
+        TracingOptions::default().narrow_integers(true)
+    }
+
+    #[test]
+    fn smallest_covering_types() -> PanicOnError<()> {
+        let data = json!([
+            {"a": 0, "b": -1000, "c": 70000, "d": -3, "e": 5_000_000_000u64},
+            {"a": 200, "b": 1000, "c": 3, "d": 100, "e": 0},
+        ]);
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "U8", "metadata": {"SERDE_ARROW:min": "0", "SERDE_ARROW:max": "200"}},
+            {"name": "b", "data_type": "I16", "metadata": {"SERDE_ARROW:min": "-1000", "SERDE_ARROW:max": "1000"}},
+            {"name": "c", "data_type": "U32", "metadata": {"SERDE_ARROW:min": "3", "SERDE_ARROW:max": "70000"}},
+            {"name": "d", "data_type": "I8", "metadata": {"SERDE_ARROW:min": "-3", "SERDE_ARROW:max": "100"}},
+            {"name": "e", "data_type": "U64", "metadata": {"SERDE_ARROW:min": "0", "SERDE_ARROW:max": "5000000000"}},
+        ]))?;
+
+        let actual = SerdeArrowSchema::from_samples(data, options().coerce_numbers(true))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn integral_floats() -> PanicOnError<()> {
+        let data = json!([
+            {"a": 1.0, "b": 1.5, "c": null},
+            {"a": 42.0, "b": 2.0, "c": -2.0},
+        ]);
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "U8", "metadata": {"SERDE_ARROW:min": "1", "SERDE_ARROW:max": "42"}},
+            {"name": "b", "data_type": "F64"},
+            {"name": "c", "data_type": "I8", "nullable": true, "metadata": {"SERDE_ARROW:min": "-2", "SERDE_ARROW:max": "-2"}},
+        ]))?;
+
+        let actual = SerdeArrowSchema::from_samples(data, options())?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn disabled_by_default() -> PanicOnError<()> {
+        let data = json!([{"a": 1.0, "b": 2}]);
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "F64"},
+            {"name": "b", "data_type": "U64"},
+        ]))?;
+
+        let actual = SerdeArrowSchema::from_samples(data, TracingOptions::default())?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn types_without_samples_are_kept() -> PanicOnError<()> {
+        #[derive(Deserialize)]
+        struct Record {
+            #[allow(dead_code)]
+            value: u64,
+        }
+
+        let expected =
+            SerdeArrowSchema::from_value(json!([{"name": "value", "data_type": "U64"}]))?;
+        let actual = SerdeArrowSchema::from_type::<Record>(options())?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
+
+mod guess_string_types {
+    use super::*;
+
+    fn options() -> TracingOptions {
+        TracingOptions::default()
+            .guess_dates(true)
+            .guess_integers(true)
+            .guess_decimals(true)
+            .guess_booleans(true)
+            .guess_uuids(true)
+    }
+
+    #[test]
+    fn guessed_types() -> PanicOnError<()> {
+        let data = json!([
+            {"int": "-42", "dec": "12.50", "bool": "true", "id": "67e55044-10b1-426f-9247-bb680e5fe0c8"},
+            {"int": "1000", "dec": "-0.25", "bool": "false", "id": null},
+        ]);
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "bool", "data_type": "Bool", "metadata": {"SERDE_ARROW:string_encoded": "true"}},
+            {"name": "dec", "data_type": "Decimal128(4, 2)"},
+            {
+                "name": "id",
+                "data_type": "FixedSizeBinary(16)",
+                "nullable": true,
+                "metadata": {"ARROW:extension:name": "arrow.uuid", "ARROW:extension:metadata": ""},
+            },
+            {"name": "int", "data_type": "I64", "metadata": {"SERDE_ARROW:string_encoded": "true"}},
+        ]))?;
+
+        let actual = SerdeArrowSchema::from_samples(data, options())?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn mixed_guesses() -> PanicOnError<()> {
+        let data = json!([
+            {"a": "1", "b": "1", "c": "007", "d": "true", "e": "2024-08-09", "f": "12345"},
+            {"a": "123.456", "b": "foo", "c": "7", "d": "1", "e": "foo", "f": "12345678901234567890"},
+        ]);
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "Decimal128(6, 3)"},
+            {"name": "b", "data_type": "LargeUtf8"},
+            {"name": "c", "data_type": "LargeUtf8"},
+            {"name": "d", "data_type": "LargeUtf8"},
+            {"name": "e", "data_type": "LargeUtf8"},
+            {"name": "f", "data_type": "Decimal128(20, 0)"},
+        ]))?;
+
+        let actual = SerdeArrowSchema::from_samples(data, options())?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn datetimes_with_offsets() -> PanicOnError<()> {
+        let data = json!([
+            {"a": "2024-08-09T12:15:00+05:30", "b": "2024-08-09T12:15:00+05:30", "c": "2024-08-09T12:15:00-0800"},
+            {"a": "2024-08-09T12:15:00+05:30", "b": "2024-08-09T12:15:00Z", "c": "2024-08-09T12:15:00"},
+        ]);
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "Timestamp(Millisecond, Some(\"+05:30\"))"},
+            {"name": "b", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+            {"name": "c", "data_type": "LargeUtf8"},
+        ]))?;
+
+        let actual = SerdeArrowSchema::from_samples(data, options())?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn datetime_patterns() -> PanicOnError<()> {
+        let data = json!([
+            {"a": "09/08/2024 12:15", "b": "2024-08-09 12:15:00 +0530"},
+            {"a": "31/12/1999 23:59", "b": null},
+        ]);
+        let expected = SerdeArrowSchema::from_value(json!([
+            {
+                "name": "a",
+                "data_type": "Timestamp(Millisecond, None)",
+                "metadata": {"SERDE_ARROW:datetime_pattern": "%d/%m/%Y %H:%M"},
+            },
+            {
+                "name": "b",
+                "data_type": "Timestamp(Millisecond, Some(\"UTC\"))",
+                "nullable": true,
+                "metadata": {"SERDE_ARROW:datetime_pattern": "%Y-%m-%d %H:%M:%S %z"},
+            },
+        ]))?;
+
+        let options = TracingOptions::default()
+            .datetime_pattern("%d/%m/%Y %H:%M")
+            .datetime_pattern("%Y-%m-%d %H:%M:%S %z");
+        let actual = SerdeArrowSchema::from_samples(data, options)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn disabled_by_default() -> PanicOnError<()> {
+        let data = json!([{"a": "1", "b": "1.5", "c": "true", "d": "67e55044-10b1-426f-9247-bb680e5fe0c8"}]);
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "LargeUtf8"},
+            {"name": "b", "data_type": "LargeUtf8"},
+            {"name": "c", "data_type": "LargeUtf8"},
+            {"name": "d", "data_type": "LargeUtf8"},
+        ]))?;
+
+        let actual = SerdeArrowSchema::from_samples(data, TracingOptions::default())?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn strings_and_numbers() -> PanicOnError<()> {
+        let data = json!([
+            {"a": "1", "b": 2},
+            {"a": 2, "b": "1"},
+            {"a": -3, "b": null},
+        ]);
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "I64", "metadata": {"SERDE_ARROW:string_encoded": "true"}},
+            {
+                "name": "b",
+                "data_type": "I64",
+                "nullable": true,
+                "metadata": {"SERDE_ARROW:string_encoded": "true"},
+            },
+        ]))?;
+
+        let actual = SerdeArrowSchema::from_samples(&data, options().coerce_numbers(true))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn strings_and_floats_are_rejected() {
+        let data = json!([{"a": "1"}, {"a": 2.5}]);
+        let res = SerdeArrowSchema::from_samples(&data, options().coerce_numbers(true));
+        assert_error_contains(
+            &res,
+            "Cannot combine strings guessed as Int64 with non-string values in a field of type Float64",
+        );
+    }
+}
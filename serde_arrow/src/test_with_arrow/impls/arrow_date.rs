@@ -66,14 +66,14 @@ mod time_string_conversion {
             .iter()
             .map(|s| Item(String::from(*s)))
             .collect::<Vec<_>>();
-        let times = strings.iter().map(|s| date::<T>(*s)).collect::<Vec<_>>();
+        let times = strings.iter().map(|s| date::<T>(s)).collect::<Vec<_>>();
 
         assert_deserialization::<T>(&items, &times);
         assert_serialization::<T>(&items, &times);
     }
 
     fn assert_deserialization<T: DatePrimitive>(items: &[Item<String>], times: &[T]) {
-        let arrays = vec![T::ARRAY_VARIANT(PrimitiveArray {
+        let arrays = [T::ARRAY_VARIANT(PrimitiveArray {
             validity: None,
             values: times.to_vec(),
         })];
@@ -1,6 +1,6 @@
 use serde_json::json;
 
-use crate::{_impl::arrow, utils::Item};
+use crate::{_impl::arrow, schema::TracingOptions, utils::Item};
 
 use super::utils::Test;
 
@@ -58,3 +58,84 @@ fn too_small_precision() {
         .expect_err("Expected error");
     assert!(err.to_string().contains("not enough precision"));
 }
+
+#[test]
+fn i128_values() {
+    let items = [
+        Item(0_i128),
+        Item(-42),
+        Item(99_999_999_999_999_999_999_999_999_999_999_999_999),
+    ];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Decimal128(38, 0)"}]))
+        .trace_schema_from_type::<Item<i128>>(TracingOptions::default())
+        .trace_schema_from_samples(&items, TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|it| assert_eq!(get_i128_values(it), &[0, -42, 10_i128.pow(38) - 1]));
+}
+
+#[test]
+fn u128_values() {
+    let items = [Item(Some(0_u128)), Item(None), Item(Some(42))];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Decimal128(38, 0)", "nullable": true}]))
+        .trace_schema_from_type::<Item<Option<u128>>>(TracingOptions::default())
+        .trace_schema_from_samples(&items, TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn integers_with_scale() {
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Decimal128(5, 2)"}]))
+        .serialize(&[Item(1_i128), Item(-42)])
+        .deserialize(&[Item(1_i128), Item(-42)])
+        .also(|it| assert_eq!(get_i128_values(it), &[100, -4200]));
+}
+
+#[test]
+fn integers_exceeding_the_precision() {
+    let mut test =
+        Test::new().with_schema(json!([{"name": "item", "data_type": "Decimal128(38, 0)"}]));
+
+    let err = test
+        .try_serialize_arrow(&[Item(10_i128.pow(38))])
+        .expect_err("Expected error");
+    assert!(err.to_string().contains("exceeds the precision"), "{err}");
+
+    let err = test
+        .try_serialize_arrow(&[Item(u128::MAX)])
+        .expect_err("Expected error");
+    assert!(err.to_string().contains("overflows i128"), "{err}");
+}
+
+#[test]
+fn fractional_decimals_cannot_be_deserialized_as_integers() {
+    let items = [Item(String::from("1.50"))];
+    let mut test =
+        Test::new().with_schema(json!([{"name": "item", "data_type": "Decimal128(5, 2)"}]));
+    test.try_serialize_arrow(&items).unwrap();
+
+    let fields = test.get_arrow_fields();
+    let arrays = test.arrays.arrow.as_ref().unwrap();
+    let err = crate::from_arrow::<Vec<Item<i128>>, _>(&fields, arrays).expect_err("Expected error");
+    assert!(err.to_string().contains("fractional part"), "{err}");
+}
+
+#[test]
+fn negative_decimals_cannot_be_deserialized_as_u128() {
+    let items = [Item(-1_i128)];
+    let mut test =
+        Test::new().with_schema(json!([{"name": "item", "data_type": "Decimal128(38, 0)"}]));
+    test.try_serialize_arrow(&items).unwrap();
+
+    let fields = test.get_arrow_fields();
+    let arrays = test.arrays.arrow.as_ref().unwrap();
+    let err = crate::from_arrow::<Vec<Item<u128>>, _>(&fields, arrays).expect_err("Expected error");
+    assert!(err.to_string().contains("as u128"), "{err}");
+}
@@ -97,3 +97,232 @@ define_tests!(u32_utf8, "U32", "Utf8");
 define_tests!(u32_large_utf8, "U32", "LargeUtf8");
 define_tests!(u64_utf8, "U64", "Utf8");
 define_tests!(u64_large_utf8, "U64", "LargeUtf8");
+
+mod non_string_values {
+    use marrow::array::Array;
+    use serde::{Deserialize, Serialize};
+    use serde_bytes::ByteBuf;
+
+    use super::*;
+
+    fn dictionary_keys_and_values_len(test: &Test) -> (Vec<u32>, usize) {
+        let Some(Array::Dictionary(array)) = &test.arrays.marrow.as_ref().unwrap().first() else {
+            panic!("Expected a dictionary array");
+        };
+        let Array::UInt32(keys) = array.keys.as_ref() else {
+            panic!("Expected UInt32 keys");
+        };
+        let values_len = match array.values.as_ref() {
+            Array::Int64(values) => values.values.len(),
+            Array::Binary(values) => values.offsets.len() - 1,
+            Array::Utf8View(values) => values.data.len(),
+            Array::Struct(values) => values.len,
+            Array::Union(values) => values.types.len(),
+            _ => panic!("Unexpected values array"),
+        };
+        (keys.values.clone(), values_len)
+    }
+
+    #[test]
+    fn integers() {
+        let items = [Item(13_i64), Item(21), Item(13), Item(13), Item(-1)];
+
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Dictionary",
+                "children": [
+                    {"name": "key", "data_type": "U32"},
+                    {"name": "value", "data_type": "I64"},
+                ]
+            }]))
+            .serialize(&items)
+            .deserialize(&items)
+            .also(|test| {
+                assert_eq!(
+                    dictionary_keys_and_values_len(test),
+                    (vec![0, 1, 0, 0, 2], 3)
+                );
+            });
+    }
+
+    #[test]
+    fn nullable_integers() {
+        let items = [Item(Some(13_i64)), Item(None), Item(Some(13)), Item(None)];
+
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Dictionary",
+                "nullable": true,
+                "children": [
+                    {"name": "key", "data_type": "U32"},
+                    {"name": "value", "data_type": "I64"},
+                ]
+            }]))
+            .serialize(&items)
+            .deserialize(&items)
+            .check_nulls(&[&[false, true, false, true]]);
+    }
+
+    #[test]
+    fn binary() {
+        let items = [
+            Item(ByteBuf::from(b"foo".to_vec())),
+            Item(ByteBuf::from(b"bar".to_vec())),
+            Item(ByteBuf::from(b"foo".to_vec())),
+        ];
+
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Dictionary",
+                "children": [
+                    {"name": "key", "data_type": "U32"},
+                    {"name": "value", "data_type": "Binary"},
+                ]
+            }]))
+            .serialize(&items)
+            .deserialize(&items)
+            .also(|test| {
+                assert_eq!(dictionary_keys_and_values_len(test), (vec![0, 1, 0], 2));
+            });
+    }
+
+    #[test]
+    fn utf8_view() {
+        let items = [
+            Item(String::from("a")),
+            Item(String::from("b")),
+            Item(String::from("a")),
+        ];
+
+        Test::new()
+            .skip_arrow2()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Dictionary",
+                "children": [
+                    {"name": "key", "data_type": "U32"},
+                    {"name": "value", "data_type": "Utf8View"},
+                ]
+            }]))
+            .serialize(&items)
+            .deserialize(&items)
+            .also(|test| {
+                assert_eq!(dictionary_keys_and_values_len(test), (vec![0, 1, 0], 2));
+            });
+    }
+
+    #[test]
+    fn structs() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct S {
+            a: u8,
+            b: String,
+        }
+
+        let items = [
+            Item(S {
+                a: 1,
+                b: String::from("x"),
+            }),
+            Item(S {
+                a: 2,
+                b: String::from("x"),
+            }),
+            Item(S {
+                a: 1,
+                b: String::from("x"),
+            }),
+        ];
+
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Dictionary",
+                "children": [
+                    {"name": "key", "data_type": "U32"},
+                    {
+                        "name": "value",
+                        "data_type": "Struct",
+                        "children": [
+                            {"name": "a", "data_type": "U8"},
+                            {"name": "b", "data_type": "LargeUtf8"},
+                        ],
+                    },
+                ]
+            }]))
+            .serialize(&items)
+            .deserialize(&items)
+            .also(|test| {
+                assert_eq!(dictionary_keys_and_values_len(test), (vec![0, 1, 0], 2));
+            });
+    }
+
+    #[test]
+    fn enum_values() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        enum E {
+            A { a: u8 },
+            B(u8, String),
+        }
+
+        let items = [
+            Item(E::A { a: 1 }),
+            Item(E::B(2, String::from("x"))),
+            Item(E::A { a: 1 }),
+        ];
+
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Dictionary",
+                "children": [
+                    {"name": "key", "data_type": "U32"},
+                    {
+                        "name": "value",
+                        "data_type": "Union",
+                        "children": [
+                            {
+                                "name": "A",
+                                "data_type": "Struct",
+                                "children": [{"name": "a", "data_type": "U8"}],
+                            },
+                            {
+                                "name": "B",
+                                "data_type": "Struct",
+                                "strategy": "TupleAsStruct",
+                                "children": [
+                                    {"name": "0", "data_type": "U8"},
+                                    {"name": "1", "data_type": "LargeUtf8"},
+                                ],
+                            },
+                        ],
+                    },
+                ]
+            }]))
+            .serialize(&items)
+            .deserialize(&items)
+            .also(|test| {
+                assert_eq!(dictionary_keys_and_values_len(test), (vec![0, 1, 0], 2));
+            });
+    }
+
+    #[test]
+    fn empty() {
+        let items: [Item<i64>; 0] = [];
+
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Dictionary",
+                "children": [
+                    {"name": "key", "data_type": "U32"},
+                    {"name": "value", "data_type": "I64"},
+                ]
+            }]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+}
@@ -1,7 +1,7 @@
 use serde_bytes::{ByteBuf, Bytes};
 use serde_json::json;
 
-use crate::utils::Item;
+use crate::{schema::TracingOptions, utils::Item};
 
 use super::utils::Test;
 
@@ -46,3 +46,51 @@ fn example_borrowed_fixed_size_binary() {
         .serialize(&items)
         .deserialize_borrowed(&items);
 }
+
+#[test]
+fn i128_as_fixed_size_binary() {
+    let items = [Item(i128::MIN), Item(-1), Item(i128::MAX)];
+    let options = TracingOptions::default().int128_as_fixed_size_binary(true);
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "FixedSizeBinary(16)"}]))
+        .trace_schema_from_type::<Item<i128>>(options.clone())
+        .trace_schema_from_samples(&items, options)
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn u128_as_fixed_size_binary() {
+    let items = [Item(Some(u128::MAX)), Item(None), Item(Some(1))];
+    let options = TracingOptions::default().int128_as_fixed_size_binary(true);
+
+    Test::new()
+        .with_schema(
+            json!([{"name": "item", "data_type": "FixedSizeBinary(16)", "nullable": true}]),
+        )
+        .trace_schema_from_type::<Item<Option<u128>>>(options.clone())
+        .trace_schema_from_samples(&items, options)
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]])
+        .also(|it| {
+            let arrays = it.arrays.arrow.as_ref().unwrap();
+            let arr = arrays[0]
+                .as_any()
+                .downcast_ref::<crate::_impl::arrow::_raw::array::FixedSizeBinaryArray>()
+                .unwrap();
+            assert_eq!(arr.value(2), 1_u128.to_le_bytes());
+        });
+}
+
+#[test]
+fn i128_requires_16_bytes() {
+    let mut test =
+        Test::new().with_schema(json!([{"name": "item", "data_type": "FixedSizeBinary(8)"}]));
+
+    let err = test
+        .try_serialize_arrow(&[Item(0_i128)])
+        .expect_err("Expected error");
+    assert!(err.to_string().contains("expected 8"), "{err}");
+}
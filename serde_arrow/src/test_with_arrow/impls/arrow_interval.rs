@@ -0,0 +1,271 @@
+use marrow::{
+    array::Array,
+    types::{DayTimeInterval, MonthDayNanoInterval},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{internal::testing::assert_error_contains, utils::Item};
+
+use super::utils::Test;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct YearMonth {
+    months: i32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct DayTime {
+    days: i32,
+    milliseconds: i32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct MonthDayNano {
+    months: i32,
+    days: i32,
+    nanoseconds: i64,
+}
+
+#[test]
+fn year_month_struct() {
+    let items = [
+        Item(YearMonth { months: 14 }),
+        Item(YearMonth { months: -3 }),
+    ];
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{"name": "item", "data_type": "Interval(YearMonth)"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|test| {
+            let Some(Array::YearMonthInterval(array)) =
+                &test.arrays.marrow.as_ref().unwrap().first()
+            else {
+                panic!("Expected a year month interval array");
+            };
+            assert_eq!(array.values, vec![14, -3]);
+        });
+}
+
+#[test]
+fn day_time_struct() {
+    let items = [
+        Item(DayTime {
+            days: 2,
+            milliseconds: 1_500,
+        }),
+        Item(DayTime {
+            days: -1,
+            milliseconds: 0,
+        }),
+    ];
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{"name": "item", "data_type": "Interval(DayTime)"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|test| {
+            let Some(Array::DayTimeInterval(array)) = &test.arrays.marrow.as_ref().unwrap().first()
+            else {
+                panic!("Expected a day time interval array");
+            };
+            assert_eq!(
+                array.values,
+                vec![
+                    DayTimeInterval {
+                        days: 2,
+                        milliseconds: 1_500
+                    },
+                    DayTimeInterval {
+                        days: -1,
+                        milliseconds: 0
+                    },
+                ]
+            );
+        });
+}
+
+#[test]
+fn month_day_nano_struct() {
+    let items = [
+        Item(MonthDayNano {
+            months: 1,
+            days: 2,
+            nanoseconds: 3,
+        }),
+        Item(MonthDayNano {
+            months: -1,
+            days: 15,
+            nanoseconds: 0,
+        }),
+    ];
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{"name": "item", "data_type": "Interval(MonthDayNano)"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|test| {
+            let Some(Array::MonthDayNanoInterval(array)) =
+                &test.arrays.marrow.as_ref().unwrap().first()
+            else {
+                panic!("Expected a month day nano interval array");
+            };
+            assert_eq!(
+                array.values[0],
+                MonthDayNanoInterval {
+                    months: 1,
+                    days: 2,
+                    nanoseconds: 3
+                }
+            );
+        });
+}
+
+#[test]
+fn month_day_nano_nullable_struct() {
+    let items = [
+        Item(Some(MonthDayNano {
+            months: 1,
+            days: 2,
+            nanoseconds: 3,
+        })),
+        Item(None),
+    ];
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Interval(MonthDayNano)",
+            "nullable": true,
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true]]);
+}
+
+#[test]
+fn month_day_nano_from_map() {
+    let items = [Item(json!({"months": 1, "days": 2, "milliseconds": 3}))];
+    let expected = [Item(MonthDayNano {
+        months: 1,
+        days: 2,
+        nanoseconds: 3_000_000,
+    })];
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{"name": "item", "data_type": "Interval(MonthDayNano)"}]))
+        .serialize(&items)
+        .deserialize(&expected);
+}
+
+#[test]
+fn year_month_string() {
+    let items = [Item(String::from("P1y2m")), Item(String::from("-P3m"))];
+    let expected = [Item(String::from("P14m")), Item(String::from("-P3m"))];
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{"name": "item", "data_type": "Interval(YearMonth)"}]))
+        .serialize(&items)
+        .deserialize(&expected);
+}
+
+#[test]
+fn day_time_string() {
+    let items = [Item(String::from("P1w2dT1.5s")), Item(String::from("-P1d"))];
+    let expected = [Item(String::from("P9dT1.500s")), Item(String::from("-P1d"))];
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{"name": "item", "data_type": "Interval(DayTime)"}]))
+        .serialize(&items)
+        .deserialize(&expected);
+}
+
+#[test]
+fn month_day_nano_string() {
+    let items = [
+        Item(String::from("P1m2dT3.000000004s")),
+        Item(String::from("-P1mT2s")),
+    ];
+    let expected = [
+        Item(String::from("P1m2dT3.000000004s")),
+        Item(String::from("-P1mT2.000000000s")),
+    ];
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{"name": "item", "data_type": "Interval(MonthDayNano)"}]))
+        .serialize(&items)
+        .deserialize(&expected);
+}
+
+#[test]
+fn year_month_rejects_days() {
+    let mut test = Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{"name": "item", "data_type": "Interval(YearMonth)"}]));
+
+    let res = test.try_serialize_arrow(&[Item("P1m2d")]);
+    assert_error_contains(&res, "Interval(YearMonth) can only store months");
+    assert_error_contains(&res, "data_type: \"Interval(YearMonth)\"");
+}
+
+#[test]
+fn day_time_rejects_months() {
+    let mut test = Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{"name": "item", "data_type": "Interval(DayTime)"}]));
+
+    let res = test.try_serialize_arrow(&[Item(MonthDayNano {
+        months: 1,
+        days: 0,
+        nanoseconds: 0,
+    })]);
+    assert_error_contains(&res, "Interval(DayTime) cannot store months");
+}
+
+#[test]
+fn day_time_rejects_fractions_of_milliseconds() {
+    let mut test = Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{"name": "item", "data_type": "Interval(DayTime)"}]));
+
+    let res = test.try_serialize_arrow(&[Item("PT0.0015s")]);
+    assert_error_contains(
+        &res,
+        "Interval(DayTime) cannot store fractions of milliseconds",
+    );
+}
+
+#[test]
+fn unknown_components_are_rejected() {
+    let mut test = Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{"name": "item", "data_type": "Interval(MonthDayNano)"}]));
+
+    let res = test.try_serialize_arrow(&[Item(json!({"weeks": 1}))]);
+    assert_error_contains(&res, "Unknown interval component \"weeks\"");
+}
+
+#[test]
+fn overflowing_spans_are_rejected() {
+    let mut test = Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{"name": "item", "data_type": "Interval(MonthDayNano)"}]));
+
+    let res = test.try_serialize_arrow(&[Item("P1000000000000000000y")]);
+    assert_error_contains(&res, "Cannot represent the months of the span");
+
+    let res = test.try_serialize_arrow(&[Item("P2000000000000000000w")]);
+    assert_error_contains(&res, "Cannot represent the days of the span");
+
+    let res = test.try_serialize_arrow(&[Item("PT3000000000000000h")]);
+    assert_error_contains(&res, "Cannot represent the seconds of the span");
+}
@@ -0,0 +1,325 @@
+use marrow::array::Array;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    utils::{Item, Items},
+};
+
+use super::utils::Test;
+
+fn run_ends_and_values_len(test: &Test) -> (Vec<i32>, usize) {
+    let Some(Array::RunEndEncoded(array)) = &test.arrays.marrow.as_ref().unwrap().first() else {
+        panic!("Expected a run end encoded array");
+    };
+    let Array::Int32(run_ends) = array.run_ends.as_ref() else {
+        panic!("Expected Int32 run ends");
+    };
+    let values_len = match array.values.as_ref() {
+        Array::Int64(values) => values.values.len(),
+        Array::Float64(values) => values.values.len(),
+        Array::LargeUtf8(values) => values.offsets.len() - 1,
+        Array::Struct(values) => values.len,
+        Array::Union(values) => values.types.len(),
+        _ => panic!("Unexpected values array"),
+    };
+    (run_ends.values.clone(), values_len)
+}
+
+#[test]
+fn primitive_values() {
+    let items = [Item(1_i64), Item(1), Item(1), Item(2), Item(3), Item(3)];
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "RunEndEncoded",
+            "children": [
+                {"name": "run_ends", "data_type": "I32"},
+                {"name": "values", "data_type": "I64"},
+            ],
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|test| {
+            assert_eq!(run_ends_and_values_len(test), (vec![3, 4, 6], 3));
+        });
+}
+
+#[test]
+fn float_values_are_compared_bitwise() {
+    let items = [
+        Item(f64::NAN),
+        Item(f64::NAN),
+        Item(0.0),
+        Item(-0.0),
+        Item(1.0),
+        Item(1.0),
+    ];
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "RunEndEncoded",
+            "children": [
+                {"name": "run_ends", "data_type": "I32"},
+                {"name": "values", "data_type": "F64"},
+            ],
+        }]))
+        .serialize(&items)
+        .also(|test| {
+            assert_eq!(run_ends_and_values_len(test), (vec![2, 3, 4, 6], 4));
+        });
+}
+
+#[test]
+fn nullable_values() {
+    let items = [
+        Item(None),
+        Item(None),
+        Item(Some(String::from("foo"))),
+        Item(Some(String::from("foo"))),
+        Item(None),
+        Item(Some(String::from("bar"))),
+    ];
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "RunEndEncoded",
+            "nullable": true,
+            "children": [
+                {"name": "run_ends", "data_type": "I32"},
+                {"name": "values", "data_type": "LargeUtf8", "nullable": true},
+            ],
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|test| {
+            assert_eq!(run_ends_and_values_len(test), (vec![2, 4, 5, 6], 4));
+        });
+}
+
+#[test]
+fn struct_values() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S {
+        a: i64,
+        b: String,
+    }
+
+    let items = [
+        Item(S {
+            a: 1,
+            b: String::from("x"),
+        }),
+        Item(S {
+            a: 1,
+            b: String::from("x"),
+        }),
+        Item(S {
+            a: 1,
+            b: String::from("y"),
+        }),
+    ];
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "RunEndEncoded",
+            "children": [
+                {"name": "run_ends", "data_type": "I32"},
+                {
+                    "name": "values",
+                    "data_type": "Struct",
+                    "children": [
+                        {"name": "a", "data_type": "I64"},
+                        {"name": "b", "data_type": "LargeUtf8"},
+                    ],
+                },
+            ],
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|test| {
+            assert_eq!(run_ends_and_values_len(test), (vec![2, 3], 2));
+        });
+}
+
+#[test]
+fn enum_values() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum E {
+        A { a: i64 },
+        B(i64, String),
+    }
+
+    let items = [
+        Item(E::A { a: 1 }),
+        Item(E::A { a: 1 }),
+        Item(E::B(2, String::from("x"))),
+        Item(E::B(2, String::from("x"))),
+        Item(E::A { a: 1 }),
+    ];
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "RunEndEncoded",
+            "children": [
+                {"name": "run_ends", "data_type": "I32"},
+                {
+                    "name": "values",
+                    "data_type": "Union",
+                    "children": [
+                        {
+                            "name": "A",
+                            "data_type": "Struct",
+                            "children": [{"name": "a", "data_type": "I64"}],
+                        },
+                        {
+                            "name": "B",
+                            "data_type": "Struct",
+                            "strategy": "TupleAsStruct",
+                            "children": [
+                                {"name": "0", "data_type": "I64"},
+                                {"name": "1", "data_type": "LargeUtf8"},
+                            ],
+                        },
+                    ],
+                },
+            ],
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|test| {
+            assert_eq!(run_ends_and_values_len(test), (vec![2, 4, 5], 3));
+        });
+}
+
+#[test]
+fn empty() {
+    let items: [Item<i64>; 0] = [];
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "RunEndEncoded",
+            "children": [
+                {"name": "run_ends", "data_type": "I32"},
+                {"name": "values", "data_type": "I64"},
+            ],
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|test| {
+            assert_eq!(run_ends_and_values_len(test), (vec![], 0));
+        });
+}
+
+#[test]
+fn run_ends_overflow() {
+    let items = (0..200).map(|_| Item(0_i64)).collect::<Vec<_>>();
+
+    let mut test = Test::new().skip_arrow2().with_schema(json!([{
+        "name": "item",
+        "data_type": "RunEndEncoded",
+        "children": [
+            {"name": "run_ends", "data_type": "I16"},
+            {"name": "values", "data_type": "I64"},
+        ],
+    }]));
+    assert!(test.try_serialize_arrow(&items).is_ok());
+
+    let items = (0..40_000_i64).map(Item).collect::<Vec<_>>();
+    let res = test.try_serialize_arrow(&items);
+    assert_error_contains(&res, "data_type: \"RunEndEncoded(..)\"");
+}
+
+#[test]
+fn traced_with_option() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S {
+        category: String,
+        value: i64,
+    }
+
+    let items = [
+        S {
+            category: String::from("a"),
+            value: 1,
+        },
+        S {
+            category: String::from("a"),
+            value: 2,
+        },
+        S {
+            category: String::from("b"),
+            value: 3,
+        },
+    ];
+    let expected_schema = json!([
+        {
+            "name": "category",
+            "data_type": "RunEndEncoded",
+            "children": [
+                {"name": "run_ends", "data_type": "I32"},
+                {"name": "values", "data_type": "LargeUtf8"},
+            ],
+        },
+        {"name": "value", "data_type": "I64"},
+    ]);
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(expected_schema)
+        .trace_schema_from_type::<S>(TracingOptions::default().run_end_encoded("category"))
+        .trace_schema_from_samples(
+            &items,
+            TracingOptions::default().run_end_encoded("category"),
+        )
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn traced_with_option_and_overwrite() {
+    let items = [Item(1_i64), Item(1), Item(2)];
+
+    Test::new()
+        .skip_arrow2()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "RunEndEncoded",
+            "children": [
+                {"name": "run_ends", "data_type": "I32"},
+                {"name": "values", "data_type": "U8"},
+            ],
+        }]))
+        .trace_schema_from_samples(
+            &items,
+            TracingOptions::default()
+                .run_end_encoded("item")
+                .overwrite("item", json!({"name": "item", "data_type": "U8"}))
+                .unwrap(),
+        )
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn unknown_paths_are_rejected() {
+    let res = SerdeArrowSchema::from_samples(
+        Items(&[1_i64, 2, 3]),
+        TracingOptions::default().run_end_encoded("missing"),
+    );
+    assert_error_contains(&res, "missing");
+}
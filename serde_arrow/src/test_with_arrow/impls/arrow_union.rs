@@ -373,10 +373,10 @@ fn missing_union_variants() {
     }
 
     let tracing_options = TracingOptions::default().allow_null_fields(true);
-    let fields = Vec::<FieldRef>::from_samples(&Items(&[U::A, U::C]), tracing_options).unwrap();
+    let fields = Vec::<FieldRef>::from_samples(Items(&[U::A, U::C]), tracing_options).unwrap();
 
     // NOTE: variant B was never encountered during tracing
-    let res = crate::to_arrow(&fields, &Items(&[U::A, U::B, U::C]));
+    let res = crate::to_arrow(&fields, Items(&[U::A, U::B, U::C]));
     assert_error_contains(&res, "Unknown variant does not support serialize_unit");
     assert_error_contains(&res, "field: \"$.item.<empty>\"")
 }
@@ -441,3 +441,638 @@ fn fieldless_unions_as_large_utf8() {
         .serialize(&values)
         .deserialize(&values);
 }
+
+mod sparse {
+    use marrow::{array::Array, datatypes::UnionMode};
+
+    use super::*;
+
+    fn union_children_lens(test: &Test) -> (Option<Vec<i32>>, Vec<i8>, Vec<usize>) {
+        let Some(Array::Union(array)) = &test.arrays.marrow.as_ref().unwrap().first() else {
+            panic!("Expected a union array");
+        };
+        let lens = array
+            .fields
+            .iter()
+            .map(|(_, _, child)| match child {
+                Array::Null(child) => child.len,
+                Array::Boolean(child) => child.len,
+                Array::UInt32(child) => child.values.len(),
+                Array::Struct(child) => child.len,
+                _ => panic!("Unexpected union child"),
+            })
+            .collect();
+        (array.offsets.clone(), array.types.clone(), lens)
+    }
+
+    #[test]
+    fn mixed() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            V1 { a: u32, b: u32 },
+            Bool(bool),
+            Unit,
+        }
+
+        let tracing_options = TracingOptions::default()
+            .allow_null_fields(true)
+            .union_mode(UnionMode::Sparse);
+        let values = [
+            Item(U::V1 { a: 32, b: 13 }),
+            Item(U::Bool(true)),
+            Item(U::Unit),
+            Item(U::Bool(false)),
+        ];
+
+        Test::new()
+            .skip_arrow2()
+            .with_schema(json!([
+                {
+                    "name": "item",
+                    "data_type": "Union(Sparse)",
+                    "children": [
+                        {
+                            "name": "V1",
+                            "data_type": "Struct",
+                            "children": [
+                                {"name": "a", "data_type": "U32"},
+                                {"name": "b", "data_type": "U32"},
+                            ],
+                        },
+                        {"name": "Bool", "data_type": "Bool"},
+                        {"name": "Unit", "data_type": "Null", "nullable": true},
+                    ],
+                },
+            ]))
+            .trace_schema_from_type::<Item<U>>(tracing_options.clone())
+            .trace_schema_from_samples(&values, tracing_options.clone())
+            .serialize(&values)
+            .deserialize(&values)
+            .also(|test| {
+                assert_eq!(
+                    union_children_lens(test),
+                    (None, vec![0, 1, 2, 1], vec![4, 4, 4])
+                );
+            });
+    }
+
+    #[test]
+    fn nested() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            U32(u32),
+            O(O),
+        }
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum O {
+            Foo(u32),
+            Bar(bool),
+        }
+
+        let tracing_options = TracingOptions::default().union_mode(UnionMode::Sparse);
+        let values = [
+            Item(U::U32(32)),
+            Item(U::O(O::Foo(13))),
+            Item(U::O(O::Bar(true))),
+        ];
+
+        Test::new()
+            .skip_arrow2()
+            .with_schema(json!([
+                {
+                    "name": "item",
+                    "data_type": "Union(Sparse)",
+                    "children": [
+                        {"name": "U32", "data_type": "U32"},
+                        {
+                            "name": "O",
+                            "data_type": "Union(Sparse)",
+                            "children": [
+                                {"name": "Foo", "data_type": "U32"},
+                                {"name": "Bar", "data_type": "Bool"},
+                            ],
+                        },
+                    ],
+                },
+            ]))
+            .trace_schema_from_type::<Item<U>>(tracing_options.clone())
+            .trace_schema_from_samples(&values, tracing_options.clone())
+            .serialize(&values)
+            .deserialize(&values);
+    }
+
+    #[test]
+    fn missing_variants() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            A(u32),
+            B(u32),
+            C(u32),
+        }
+
+        let tracing_options = TracingOptions::default().union_mode(UnionMode::Sparse);
+        let values = [Item(U::A(1)), Item(U::C(3)), Item(U::A(4))];
+
+        Test::new()
+            .skip_arrow2()
+            .trace_schema_from_samples(&values, tracing_options)
+            .serialize(&values)
+            .deserialize(&values);
+    }
+}
+
+mod type_ids {
+    use marrow::{
+        array::{Array, BooleanArray, NullArray, PrimitiveArray, UnionArray},
+        datatypes::{DataType, Field, FieldMeta, UnionMode},
+        view::View,
+    };
+
+    use crate::internal::testing::assert_error_contains;
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum U {
+        A(u32),
+        B(bool),
+        C,
+    }
+
+    fn union_types(test: &Test) -> Vec<i8> {
+        let Some(Array::Union(array)) = &test.arrays.marrow.as_ref().unwrap().first() else {
+            panic!("Expected a union array");
+        };
+        assert_eq!(
+            array
+                .fields
+                .iter()
+                .map(|(type_id, _, _)| *type_id)
+                .collect::<Vec<_>>(),
+            vec![0, 3, 5],
+        );
+        array.types.clone()
+    }
+
+    #[test]
+    fn dense() {
+        let values = [Item(U::A(1)), Item(U::C), Item(U::B(true)), Item(U::A(2))];
+
+        Test::new()
+            .with_schema(json!([
+                {
+                    "name": "item",
+                    "data_type": "Union(Dense, 0, 3, 5)",
+                    "children": [
+                        {"name": "A", "data_type": "U32"},
+                        {"name": "B", "data_type": "Bool"},
+                        {"name": "C", "data_type": "Null", "nullable": true},
+                    ],
+                },
+            ]))
+            .serialize(&values)
+            .deserialize(&values)
+            .also(|test| assert_eq!(union_types(test), vec![0, 5, 3, 0]));
+    }
+
+    #[test]
+    fn sparse() {
+        let values = [Item(U::B(false)), Item(U::A(1)), Item(U::C)];
+
+        Test::new()
+            .skip_arrow2()
+            .with_schema(json!([
+                {
+                    "name": "item",
+                    "data_type": "Union(Sparse, 0, 3, 5)",
+                    "children": [
+                        {"name": "A", "data_type": "U32"},
+                        {"name": "B", "data_type": "Bool"},
+                        {"name": "C", "data_type": "Null", "nullable": true},
+                    ],
+                },
+            ]))
+            .serialize(&values)
+            .deserialize(&values)
+            .also(|test| assert_eq!(union_types(test), vec![3, 0, 5]));
+    }
+
+    #[test]
+    fn children_out_of_variant_order() {
+        let values = [Item(U::A(1)), Item(U::C), Item(U::B(true)), Item(U::A(2))];
+
+        Test::new()
+            .with_schema(json!([
+                {
+                    "name": "item",
+                    "data_type": "Union(Dense, 5, 0, 3)",
+                    "children": [
+                        {"name": "C", "data_type": "Null", "nullable": true},
+                        {"name": "A", "data_type": "U32"},
+                        {"name": "B", "data_type": "Bool"},
+                    ],
+                },
+            ]))
+            .serialize(&values)
+            .deserialize(&values)
+            .also(|test| {
+                let Some(Array::Union(array)) = &test.arrays.marrow.as_ref().unwrap().first()
+                else {
+                    panic!("Expected a union array");
+                };
+                assert_eq!(array.types, vec![0, 5, 3, 0]);
+            });
+    }
+
+    #[test]
+    fn dropped_variants_are_rejected() {
+        let mut test = Test::new().with_schema(json!([
+            {
+                "name": "item",
+                "data_type": "Union(Dense, 0, 2)",
+                "children": [
+                    {"name": "A", "data_type": "U32"},
+                    {"name": "C", "data_type": "Null", "nullable": true},
+                ],
+            },
+        ]));
+
+        test.try_serialize_marrow(&[Item(U::A(1)), Item(U::C)])
+            .unwrap();
+
+        let res = test.try_serialize_marrow(&[Item(U::B(true))]);
+        assert_error_contains(&res, "Unknown variant \"B\"");
+    }
+
+    #[test]
+    fn numbered_children_are_selected_by_variant_index() {
+        let values = [Item(U::A(1)), Item(U::C), Item(U::B(true))];
+
+        Test::new()
+            .with_schema(json!([
+                {
+                    "name": "item",
+                    "data_type": "Union(Dense, 0, 3, 5)",
+                    "children": [
+                        {"name": "0", "data_type": "U32"},
+                        {"name": "1", "data_type": "Bool"},
+                        {"name": "2", "data_type": "Null", "nullable": true},
+                    ],
+                },
+            ]))
+            .serialize(&values)
+            .also(|test| assert_eq!(union_types(test), vec![0, 5, 3]));
+    }
+
+    #[test]
+    fn out_of_order_type_ids() {
+        let fields = vec![Field {
+            name: String::from("item"),
+            data_type: DataType::Union(
+                vec![
+                    (
+                        7,
+                        Field {
+                            name: String::from("A"),
+                            data_type: DataType::UInt32,
+                            ..Field::default()
+                        },
+                    ),
+                    (
+                        2,
+                        Field {
+                            name: String::from("B"),
+                            data_type: DataType::Boolean,
+                            ..Field::default()
+                        },
+                    ),
+                    (
+                        4,
+                        Field {
+                            name: String::from("C"),
+                            data_type: DataType::Null,
+                            nullable: true,
+                            ..Field::default()
+                        },
+                    ),
+                ],
+                UnionMode::Dense,
+            ),
+            ..Field::default()
+        }];
+        let array = Array::Union(UnionArray {
+            types: vec![4, 7, 2, 7],
+            offsets: Some(vec![0, 0, 0, 1]),
+            fields: vec![
+                (
+                    7,
+                    FieldMeta {
+                        name: String::from("A"),
+                        ..FieldMeta::default()
+                    },
+                    Array::UInt32(PrimitiveArray {
+                        validity: None,
+                        values: vec![13, 21],
+                    }),
+                ),
+                (
+                    2,
+                    FieldMeta {
+                        name: String::from("B"),
+                        ..FieldMeta::default()
+                    },
+                    Array::Boolean(BooleanArray {
+                        len: 1,
+                        validity: None,
+                        values: vec![1],
+                    }),
+                ),
+                (
+                    4,
+                    FieldMeta {
+                        name: String::from("C"),
+                        nullable: true,
+                        ..FieldMeta::default()
+                    },
+                    Array::Null(NullArray { len: 1 }),
+                ),
+            ],
+        });
+        let views = vec![array.as_view()];
+
+        let actual: Vec<Item<U>> = crate::from_marrow(&fields, &views).unwrap();
+        assert_eq!(
+            actual,
+            vec![Item(U::C), Item(U::A(13)), Item(U::B(true)), Item(U::A(21))],
+        );
+
+        let arrays = crate::to_marrow(&fields, &actual).unwrap();
+        let views = arrays.iter().map(Array::as_view).collect::<Vec<View>>();
+        let roundtripped: Vec<Item<U>> = crate::from_marrow(&fields, &views).unwrap();
+        assert_eq!(roundtripped, actual);
+
+        let Array::Union(array) = &arrays[0] else {
+            panic!("Expected union array");
+        };
+        assert_eq!(array.types, vec![4, 7, 2, 7]);
+    }
+
+    #[test]
+    fn unknown_type_ids_are_rejected() {
+        let fields = vec![Field {
+            name: String::from("item"),
+            data_type: DataType::Union(
+                vec![(
+                    1,
+                    Field {
+                        name: String::from("C"),
+                        data_type: DataType::Null,
+                        nullable: true,
+                        ..Field::default()
+                    },
+                )],
+                UnionMode::Sparse,
+            ),
+            ..Field::default()
+        }];
+        let array = Array::Union(UnionArray {
+            types: vec![1, 0],
+            offsets: None,
+            fields: vec![(
+                1,
+                FieldMeta {
+                    name: String::from("C"),
+                    nullable: true,
+                    ..FieldMeta::default()
+                },
+                Array::Null(NullArray { len: 2 }),
+            )],
+        });
+        let views = vec![array.as_view()];
+
+        let res = crate::from_marrow::<Vec<Item<U>>>(&fields, &views);
+        assert_error_contains(&res, "Unknown type id 0");
+    }
+
+    #[test]
+    fn duplicate_type_ids_are_rejected() {
+        let res = crate::schema::SerdeArrowSchema::from_value(json!([
+            {
+                "name": "item",
+                "data_type": "Union(Dense, 1, 1)",
+                "children": [
+                    {"name": "A", "data_type": "U32"},
+                    {"name": "B", "data_type": "Bool"},
+                ],
+            },
+        ]));
+        assert_error_contains(&res, "Duplicate type id 1");
+    }
+}
+
+mod nullable {
+    use marrow::{array::Array, datatypes::UnionMode};
+
+    use crate::{internal::testing::assert_error_contains, schema::NULL_VARIANT_NAME};
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum U {
+        A(u32),
+        B,
+    }
+
+    fn schema(data_type: &str) -> serde_json::Value {
+        json!([
+            {
+                "name": "item",
+                "data_type": data_type,
+                "nullable": true,
+                "children": [
+                    {"name": "A", "data_type": "U32"},
+                    {"name": "B", "data_type": "Null", "nullable": true},
+                    {
+                        "name": NULL_VARIANT_NAME,
+                        "data_type": "Null",
+                        "nullable": true,
+                        "strategy": "NullVariant",
+                    },
+                ],
+            },
+        ])
+    }
+
+    #[test]
+    fn dense() {
+        let tracing_options = TracingOptions::default().allow_null_fields(true);
+        let values = [
+            Item(Some(U::A(1))),
+            Item(None),
+            Item(Some(U::B)),
+            Item(None),
+            Item(Some(U::A(2))),
+        ];
+
+        Test::new()
+            .with_schema(schema("Union"))
+            .trace_schema_from_type::<Item<Option<U>>>(tracing_options.clone())
+            .trace_schema_from_samples(&values, tracing_options.clone())
+            .serialize(&values)
+            .deserialize(&values);
+    }
+
+    #[test]
+    fn sparse() {
+        let tracing_options = TracingOptions::default()
+            .allow_null_fields(true)
+            .union_mode(UnionMode::Sparse);
+        let values = [Item(None), Item(Some(U::B)), Item(Some(U::A(3)))];
+
+        Test::new()
+            .skip_arrow2()
+            .with_schema(schema("Union(Sparse)"))
+            .trace_schema_from_type::<Item<Option<U>>>(tracing_options.clone())
+            .trace_schema_from_samples(&values, tracing_options.clone())
+            .serialize(&values)
+            .deserialize(&values);
+    }
+
+    #[test]
+    fn only_nulls() {
+        let values = [Item(None::<U>), Item(None)];
+
+        Test::new()
+            .with_schema(schema("Union"))
+            .serialize(&values)
+            .deserialize(&values);
+    }
+
+    #[test]
+    fn defaults_select_the_null_variant() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct S {
+            u: Option<U>,
+        }
+
+        let values = [Item(Some(S { u: Some(U::B) })), Item(None)];
+
+        Test::new()
+            .with_schema(json!([
+                {
+                    "name": "item",
+                    "data_type": "Struct",
+                    "nullable": true,
+                    "children": [
+                        {
+                            "name": "u",
+                            "data_type": "Union",
+                            "nullable": true,
+                            "children": [
+                                {"name": "A", "data_type": "U32"},
+                                {"name": "B", "data_type": "Null", "nullable": true},
+                                {
+                                    "name": NULL_VARIANT_NAME,
+                                    "data_type": "Null",
+                                    "nullable": true,
+                                    "strategy": "NullVariant",
+                                },
+                            ],
+                        },
+                    ],
+                },
+            ]))
+            .serialize(&values)
+            .deserialize(&values)
+            .also(|test| {
+                let Some(Array::Struct(array)) = &test.arrays.marrow.as_ref().unwrap().first()
+                else {
+                    panic!("Expected a struct array");
+                };
+                let Some((_, Array::Union(union))) = array.fields.first() else {
+                    panic!("Expected a union child");
+                };
+                assert_eq!(union.types, vec![1, 2]);
+            });
+    }
+
+    #[test]
+    fn none_without_null_variant_is_rejected() {
+        let mut test = Test::new().with_schema(json!([
+            {
+                "name": "item",
+                "data_type": "Union",
+                "nullable": true,
+                "children": [
+                    {"name": "A", "data_type": "U32"},
+                    {"name": "B", "data_type": "Null", "nullable": true},
+                ],
+            },
+        ]));
+        let res = test.try_serialize_arrow(&[Item(None::<U>)]);
+        assert_error_contains(&res, "NullVariant");
+    }
+
+    #[test]
+    fn null_variant_must_be_last() {
+        let res = crate::schema::SerdeArrowSchema::from_value(json!([
+            {
+                "name": "item",
+                "data_type": "Union",
+                "nullable": true,
+                "children": [
+                    {"name": NULL_VARIANT_NAME, "data_type": "Null", "strategy": "NullVariant"},
+                    {"name": "A", "data_type": "U32"},
+                ],
+            },
+        ]));
+        assert_error_contains(&res, "must be the last child");
+    }
+
+    #[test]
+    fn null_variant_requires_nullable_union() {
+        let res = crate::schema::SerdeArrowSchema::from_value(json!([
+            {
+                "name": "item",
+                "data_type": "Union",
+                "children": [
+                    {"name": "A", "data_type": "U32"},
+                    {"name": NULL_VARIANT_NAME, "data_type": "Null", "strategy": "NullVariant"},
+                ],
+            },
+        ]));
+        assert_error_contains(&res, "Only nullable Union fields");
+    }
+
+    #[test]
+    fn null_variant_requires_reserved_name() {
+        let res = crate::schema::SerdeArrowSchema::from_value(json!([
+            {
+                "name": "item",
+                "data_type": "Union",
+                "nullable": true,
+                "children": [
+                    {"name": "A", "data_type": "U32"},
+                    {"name": "", "data_type": "Null", "strategy": "NullVariant"},
+                ],
+            },
+        ]));
+        assert_error_contains(&res, "The NullVariant child must be named");
+    }
+
+    #[test]
+    fn reserved_name_requires_null_variant() {
+        let res = crate::schema::SerdeArrowSchema::from_value(json!([
+            {
+                "name": "item",
+                "data_type": "Union",
+                "children": [
+                    {"name": "A", "data_type": "U32"},
+                    {"name": NULL_VARIANT_NAME, "data_type": "Null"},
+                ],
+            },
+        ]));
+        assert_error_contains(&res, "is reserved for the NullVariant child");
+    }
+}
@@ -0,0 +1,212 @@
+use marrow::{
+    array::Array,
+    datatypes::{DataType, Field},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::internal::{
+    array_builder::ArrayBuilder,
+    deserializer::Deserializer,
+    error::{fail, Result},
+    schema::{
+        extensions::{
+            from_value, to_value, ExtensionField, ExtensionRegistry, ExtensionType, ExtensionValue,
+        },
+        SchemaLike, SerdeArrowSchema, TracingOptions,
+    },
+    testing::assert_error_contains,
+    utils::Item,
+};
+
+/// Expose amounts in cents as floating point amounts
+#[derive(Debug)]
+struct Cents;
+
+impl ExtensionType for Cents {
+    fn name(&self) -> &str {
+        "test.cents"
+    }
+
+    fn storage_field(&self, name: &str) -> Result<Field> {
+        Ok(Field {
+            name: name.to_owned(),
+            data_type: DataType::Int64,
+            nullable: false,
+            metadata: Default::default(),
+        })
+    }
+
+    fn validate_field(&self, field: &Field) -> Result<()> {
+        if field.data_type != DataType::Int64 {
+            fail!("Cents must be stored as Int64");
+        }
+        Ok(())
+    }
+
+    fn has_value_hooks(&self) -> bool {
+        true
+    }
+
+    fn serialize_value(&self, value: ExtensionValue) -> Result<ExtensionValue> {
+        let amount: f64 = from_value(&value)?;
+        to_value((amount * 100.0).round() as i64)
+    }
+
+    fn deserialize_value(&self, value: ExtensionValue) -> Result<ExtensionValue> {
+        let cents: i64 = from_value(&value)?;
+        to_value(cents as f64 / 100.0)
+    }
+}
+
+fn registry() -> ExtensionRegistry {
+    ExtensionRegistry::new().register(Cents)
+}
+
+fn schema(value: serde_json::Value) -> SerdeArrowSchema {
+    SerdeArrowSchema::from_value(value).unwrap()
+}
+
+fn cents_metadata() -> serde_json::Value {
+    json!({"ARROW:extension:name": "test.cents", "ARROW:extension:metadata": ""})
+}
+
+fn round_trip<T: Serialize + for<'de> Deserialize<'de>>(
+    schema: SerdeArrowSchema,
+    items: &[T],
+) -> (Vec<Array>, Vec<T>) {
+    let mut builder = ArrayBuilder::new_with_extensions(schema.clone(), &registry()).unwrap();
+    builder.extend(items).unwrap();
+    let arrays = builder.to_marrow().unwrap();
+
+    let views = arrays.iter().map(Array::as_view).collect::<Vec<_>>();
+    let deserializer =
+        Deserializer::from_marrow_with_extensions(&schema.fields, &views, &registry()).unwrap();
+    let actual = Vec::<T>::deserialize(deserializer).unwrap();
+
+    (arrays, actual)
+}
+
+#[test]
+fn value_hooks_round_trip() {
+    let items = [Item(1.5), Item(-0.25), Item(42.0)];
+    let schema = schema(json!([{
+        "name": "item",
+        "data_type": "I64",
+        "metadata": cents_metadata(),
+    }]));
+
+    let (arrays, actual) = round_trip(schema, &items);
+    let Array::Int64(array) = &arrays[0] else {
+        panic!("Expected Int64 array, got {:?}", arrays[0]);
+    };
+    assert_eq!(array.values, vec![150, -25, 4200]);
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn value_hooks_skip_nulls() {
+    let items = [Item(Some(1.5)), Item(None), Item(Some(2.0))];
+    let schema = schema(json!([{
+        "name": "item",
+        "data_type": "I64",
+        "nullable": true,
+        "metadata": cents_metadata(),
+    }]));
+
+    let (arrays, actual) = round_trip(schema, &items);
+    let Array::Int64(array) = &arrays[0] else {
+        panic!("Expected Int64 array, got {:?}", arrays[0]);
+    };
+    assert_eq!(array.values, vec![150, 0, 200]);
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn value_hooks_in_nested_fields() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        prices: Vec<f64>,
+    }
+
+    let items = [
+        Record {
+            prices: vec![0.5, 1.0],
+        },
+        Record { prices: vec![] },
+        Record { prices: vec![3.25] },
+    ];
+    let schema = schema(json!([{
+        "name": "prices",
+        "data_type": "LargeList",
+        "children": [{"name": "element", "data_type": "I64", "metadata": cents_metadata()}],
+    }]));
+
+    let (arrays, actual) = round_trip(schema, &items);
+    let Array::LargeList(array) = &arrays[0] else {
+        panic!("Expected LargeList array, got {:?}", arrays[0]);
+    };
+    let Array::Int64(elements) = array.elements.as_ref() else {
+        panic!("Expected Int64 elements, got {:?}", array.elements);
+    };
+    assert_eq!(elements.values, vec![50, 100, 325]);
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn unregistered_extensions_use_the_storage_type() {
+    let items = [Item(150_i64), Item(-25)];
+    let schema = schema(json!([{
+        "name": "item",
+        "data_type": "I64",
+        "metadata": cents_metadata(),
+    }]));
+
+    let arrays = crate::to_marrow(&schema.fields, &items).unwrap();
+    let Array::Int64(array) = &arrays[0] else {
+        panic!("Expected Int64 array, got {:?}", arrays[0]);
+    };
+    assert_eq!(array.values, vec![150, -25]);
+}
+
+#[test]
+fn invalid_storage_fields_are_rejected() {
+    let schema = schema(json!([{
+        "name": "item",
+        "data_type": "Utf8",
+        "metadata": cents_metadata(),
+    }]));
+
+    let res = ArrayBuilder::new_with_extensions(schema.clone(), &registry());
+    assert_error_contains(&res, "Cents must be stored as Int64");
+    assert_error_contains(&res, "extension: \"test.cents\"");
+
+    let arrays = crate::to_marrow(&schema.fields, &[Item("1.5")]).unwrap();
+    let views = arrays.iter().map(Array::as_view).collect::<Vec<_>>();
+    let res = Deserializer::from_marrow_with_extensions(&schema.fields, &views, &registry());
+    assert_error_contains(&res, "Cents must be stored as Int64");
+}
+
+#[test]
+fn extension_field_overwrites() {
+    let tracing_options = TracingOptions::default()
+        .overwrite("item", ExtensionField::new("item", Cents).nullable(true))
+        .unwrap();
+
+    let expected = schema(json!([{
+        "name": "item",
+        "data_type": "I64",
+        "nullable": true,
+        "metadata": cents_metadata(),
+    }]));
+
+    let actual = SerdeArrowSchema::from_type::<Item<Option<f64>>>(tracing_options.clone()).unwrap();
+    assert_eq!(actual, expected);
+
+    let items = [Item(Some(1.5)), Item(None)];
+    let actual = SerdeArrowSchema::from_samples(&items, tracing_options).unwrap();
+    assert_eq!(actual, expected);
+
+    let (_, actual) = round_trip(expected, &items);
+    assert_eq!(actual, items);
+}
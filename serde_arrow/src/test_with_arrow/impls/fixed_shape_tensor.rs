@@ -0,0 +1,186 @@
+use marrow::array::Array;
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    internal::testing::assert_error_contains,
+    schema::{ext::FixedShapeTensorField, SchemaLike, SerdeArrowSchema, TracingOptions},
+    utils::Item,
+};
+
+use super::utils::Test;
+
+fn tensor_field(shape: Vec<usize>) -> FixedShapeTensorField {
+    FixedShapeTensorField::new(
+        "item",
+        json!({"name": "element", "data_type": "I32"}),
+        shape,
+    )
+    .unwrap()
+}
+
+fn storage_values<T: Serialize>(field: &FixedShapeTensorField, items: &T) -> Vec<i32> {
+    let fields = SerdeArrowSchema::from_value([field]).unwrap().fields;
+    let arrays = crate::to_marrow(&fields, items).unwrap();
+    let Array::FixedSizeList(array) = &arrays[0] else {
+        panic!("Expected a FixedSizeList array, got {:?}", arrays[0]);
+    };
+    let Array::Int32(elements) = array.elements.as_ref() else {
+        panic!("Expected Int32 elements, got {:?}", array.elements);
+    };
+    elements.values.clone()
+}
+
+#[test]
+fn nested_vecs() {
+    let field = tensor_field(vec![2, 3]);
+    let items = [
+        Item(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+        Item(vec![vec![7, 8, 9], vec![10, 11, 12]]),
+    ];
+
+    assert_eq!(
+        storage_values(&field, &items),
+        vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
+    );
+    Test::new()
+        .with_schema([&field])
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn nested_arrays() {
+    let field = tensor_field(vec![2, 2, 2]);
+    let items = [
+        Item([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]),
+        Item([[[0; 2]; 2]; 2]),
+    ];
+
+    assert_eq!(
+        storage_values(&field, &items),
+        vec![1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 0, 0, 0, 0],
+    );
+    Test::new()
+        .with_schema([&field])
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn nullable_tensors() {
+    let field = tensor_field(vec![2, 1]).nullable(true);
+    let items = [
+        Item(Some(vec![vec![1], vec![2]])),
+        Item(None),
+        Item(Some(vec![vec![3], vec![4]])),
+    ];
+
+    Test::new()
+        .with_schema([&field])
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn permutation() {
+    // the logical shape is [3, 2], the storage uses the physical shape [2, 3]
+    let field = tensor_field(vec![2, 3]).permutation(vec![1, 0]).unwrap();
+    let items = [Item(vec![vec![1, 2], vec![3, 4], vec![5, 6]])];
+
+    assert_eq!(storage_values(&field, &items), vec![1, 3, 5, 2, 4, 6]);
+    Test::new()
+        .with_schema([&field])
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn permutation_3d() {
+    // the logical shape is [4, 2, 3]
+    let field = tensor_field(vec![2, 3, 4])
+        .permutation(vec![2, 0, 1])
+        .unwrap();
+    let items = [Item(
+        (0..4)
+            .map(|k| {
+                (0..2)
+                    .map(|i| (0..3).map(|j| 100 * i + 10 * j + k).collect::<Vec<_>>())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>(),
+    )];
+
+    let mut expected = Vec::new();
+    for i in 0..2 {
+        for j in 0..3 {
+            for k in 0..4 {
+                expected.push(100 * i + 10 * j + k);
+            }
+        }
+    }
+    assert_eq!(storage_values(&field, &items), expected);
+    Test::new()
+        .with_schema([&field])
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn flat_values_are_written_in_storage_order() {
+    let field = tensor_field(vec![3, 2, 1])
+        .permutation(vec![2, 1, 0])
+        .unwrap();
+    let items = [Item(vec![1, 2, 3, 4, 5, 6])];
+    assert_eq!(storage_values(&field, &items), vec![1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn flat_and_nested_rows() {
+    let field = tensor_field(vec![2, 3]);
+    let items = [
+        Item(serde_json::json!([1, 2, 3, 4, 5, 6])),
+        Item(serde_json::json!([[1, 2, 3], [4, 5, 6]])),
+        Item(serde_json::json!([6, 5, 4, 3, 2, 1])),
+    ];
+    assert_eq!(
+        storage_values(&field, &items),
+        vec![1, 2, 3, 4, 5, 6, 1, 2, 3, 4, 5, 6, 6, 5, 4, 3, 2, 1]
+    );
+}
+
+#[test]
+fn shape_mismatch() {
+    let field = tensor_field(vec![2, 3]);
+    let items = [
+        Item(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+        Item(vec![vec![1, 2, 3], vec![4, 5]]),
+    ];
+
+    let mut test = Test::new().with_schema([&field]);
+    let res = test.try_serialize_arrow(&items);
+    assert_error_contains(&res, "Shape mismatch in row 1");
+    assert_error_contains(&res, "expected 3 entries in dimension 1, found 2");
+    assert_error_contains(&res, "field: \"$.item\"");
+
+    let items = [Item(vec![vec![1, 2, 3]])];
+    let res = test.try_serialize_arrow(&items);
+    assert_error_contains(&res, "Shape mismatch in row 0");
+    assert_error_contains(&res, "expected 2 entries in dimension 0, found 1");
+}
+
+#[test]
+fn overwrites() {
+    let field = tensor_field(vec![2, 2]);
+    let tracing_options = TracingOptions::default().overwrite("item", &field).unwrap();
+    let items = [Item(vec![vec![1, 2], vec![3, 4]])];
+
+    Test::new()
+        .with_schema([&field])
+        .trace_schema_from_type::<Item<Vec<Vec<i32>>>>(tracing_options.clone())
+        .trace_schema_from_samples(&items, tracing_options)
+        .serialize(&items)
+        .deserialize(&items);
+}
@@ -0,0 +1,409 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    internal::testing::assert_error_contains,
+    schema::{
+        ext::{LineStringField, PointField, PolygonField},
+        SchemaLike, SerdeArrowSchema, TracingOptions,
+    },
+    utils::Item,
+};
+
+use super::utils::Test;
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+struct LineString(Vec<Point>);
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+struct Polygon(Vec<LineString>);
+
+fn point(x: f64, y: f64) -> Point {
+    Point { x, y }
+}
+
+fn square(offset: f64) -> LineString {
+    LineString(vec![
+        point(offset, offset),
+        point(offset + 1.0, offset),
+        point(offset + 1.0, offset + 1.0),
+        point(offset, offset),
+    ])
+}
+
+fn metadata(name: &str) -> Value {
+    json!({
+        "ARROW:extension:name": name,
+        "ARROW:extension:metadata": "{}",
+    })
+}
+
+fn separated() -> Value {
+    json!({
+        "data_type": "Struct",
+        "children": [
+            {"name": "x", "data_type": "F64"},
+            {"name": "y", "data_type": "F64"},
+        ],
+    })
+}
+
+fn interleaved() -> Value {
+    json!({
+        "data_type": "FixedSizeList(2)",
+        "children": [{"name": "xy", "data_type": "F64"}],
+    })
+}
+
+fn with_name(mut field: Value, name: &str) -> Value {
+    field["name"] = json!(name);
+    field
+}
+
+#[test]
+fn points_separated() {
+    let items = [Item(point(0.0, 1.0)), Item(point(-2.5, 3.0))];
+    let options = TracingOptions::default()
+        .overwrite("item", PointField::new("item"))
+        .unwrap();
+
+    let mut field = with_name(separated(), "item");
+    field["metadata"] = metadata("geoarrow.point");
+
+    Test::new()
+        .with_schema(json!([field]))
+        .trace_schema_from_type::<Item<Point>>(options.clone())
+        .trace_schema_from_samples(&items, options)
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn points_interleaved() {
+    let items = [Item(point(0.0, 1.0)), Item(point(-2.5, 3.0))];
+    let options = TracingOptions::default()
+        .overwrite("item", PointField::new("item").interleaved(true))
+        .unwrap();
+
+    let mut field = with_name(interleaved(), "item");
+    field["metadata"] = metadata("geoarrow.point");
+
+    Test::new()
+        .with_schema(json!([field]))
+        .trace_schema_from_type::<Item<Point>>(options.clone())
+        .trace_schema_from_samples(&items, options)
+        .serialize(&items)
+        .deserialize(&items)
+        .deserialize(&[Item([0.0, 1.0]), Item([-2.5, 3.0])])
+        .deserialize(&[Item((0.0, 1.0)), Item((-2.5, 3.0))]);
+}
+
+#[test]
+fn nullable_points() {
+    let items = [Item(Some(point(0.0, 1.0))), Item(None)];
+
+    for interleaved in [false, true] {
+        let options = TracingOptions::default()
+            .overwrite(
+                "item",
+                PointField::new("item")
+                    .nullable(true)
+                    .interleaved(interleaved),
+            )
+            .unwrap();
+
+        Test::new()
+            .trace_schema_from_type::<Item<Option<Point>>>(options.clone())
+            .trace_schema_from_samples(&items, options)
+            .serialize(&items)
+            .deserialize(&items)
+            .check_nulls(&[&[false, true]]);
+    }
+}
+
+#[test]
+fn linestrings() {
+    let items = [
+        Item(square(0.0)),
+        Item(LineString(vec![])),
+        Item(square(2.0)),
+    ];
+
+    for (interleaved, coords) in [(false, separated()), (true, interleaved())] {
+        let options = TracingOptions::default()
+            .overwrite(
+                "item",
+                LineStringField::new("item").interleaved(interleaved),
+            )
+            .unwrap();
+
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "List",
+                "metadata": metadata("geoarrow.linestring"),
+                "children": [with_name(coords, "vertices")],
+            }]))
+            .trace_schema_from_type::<Item<LineString>>(options.clone())
+            .trace_schema_from_samples(&items, options)
+            .serialize(&items)
+            .deserialize(&items);
+    }
+}
+
+#[test]
+fn polygons() {
+    let items = [
+        Item(Polygon(vec![square(0.0)])),
+        Item(Polygon(vec![square(0.0), square(0.25)])),
+    ];
+
+    for (interleaved, coords) in [(false, separated()), (true, interleaved())] {
+        let options = TracingOptions::default()
+            .overwrite("item", PolygonField::new("item").interleaved(interleaved))
+            .unwrap();
+
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "List",
+                "metadata": metadata("geoarrow.polygon"),
+                "children": [{
+                    "name": "rings",
+                    "data_type": "List",
+                    "children": [with_name(coords, "vertices")],
+                }],
+            }]))
+            .trace_schema_from_type::<Item<Polygon>>(options.clone())
+            .trace_schema_from_samples(&items, options)
+            .serialize(&items)
+            .deserialize(&items);
+    }
+}
+
+#[test]
+fn interleaved_points_with_wrong_dimensions() {
+    #[derive(Serialize)]
+    struct PointZ {
+        x: f64,
+        y: f64,
+        z: f64,
+    }
+
+    let mut field = with_name(interleaved(), "item");
+    field["metadata"] = metadata("geoarrow.point");
+
+    let mut test = Test::new().with_schema(json!([field]));
+    let res = test.try_serialize_arrow(&[Item(PointZ {
+        x: 0.0,
+        y: 1.0,
+        z: 2.0,
+    })]);
+    assert_error_contains(&res, "Expected 2, got 3");
+}
+
+#[test]
+fn interleaved_points_with_wrong_field_order() {
+    #[derive(Serialize)]
+    struct SwappedPoint {
+        y: f64,
+        x: f64,
+    }
+
+    let mut field = with_name(interleaved(), "item");
+    field["metadata"] = metadata("geoarrow.point");
+
+    let mut test = Test::new().with_schema(json!([field]));
+    let res = test.try_serialize_arrow(&[Item(SwappedPoint { y: 1.0, x: 0.0 })]);
+    assert_error_contains(&res, "Expected coordinate \"x\", got field \"y\"");
+}
+
+#[test]
+fn structs_require_geoarrow_coordinates() {
+    let fields = SerdeArrowSchema::from_value(json!([{
+        "name": "item",
+        "data_type": "FixedSizeList(2)",
+        "children": [{"name": "element", "data_type": "F64"}],
+    }]))
+    .unwrap()
+    .fields;
+
+    let res = crate::to_marrow(&fields, &[Item(point(0.0, 1.0))]);
+    assert_error_contains(&res, "Only interleaved GeoArrow coordinates");
+
+    let arrays = crate::to_marrow(&fields, &[Item([0.0_f64, 1.0])]).unwrap();
+    let views = arrays
+        .iter()
+        .map(|array| array.as_view())
+        .collect::<Vec<_>>();
+    let res = crate::from_marrow::<Vec<Item<Point>>>(&fields, &views);
+    assert_error_contains(&res, "Only interleaved GeoArrow coordinates");
+}
+
+mod geo_types_shapes {
+    use geo_types::{line_string, point, polygon, LineString, Point, Polygon};
+
+    use super::*;
+
+    fn square(offset: f64) -> LineString {
+        line_string![
+            (x: offset, y: offset),
+            (x: offset + 1.0, y: offset),
+            (x: offset + 1.0, y: offset + 1.0),
+            (x: offset, y: offset),
+        ]
+    }
+
+    #[test]
+    fn points() {
+        let items = [Item(point!(x: 0.0, y: 1.0)), Item(point!(x: -2.5, y: 3.0))];
+
+        for interleaved in [false, true] {
+            let options = TracingOptions::default()
+                .overwrite("item", PointField::new("item").interleaved(interleaved))
+                .unwrap();
+
+            Test::new()
+                .trace_schema_from_type::<Item<Point>>(options.clone())
+                .trace_schema_from_samples(&items, options)
+                .serialize(&items)
+                .deserialize(&items);
+        }
+    }
+
+    #[test]
+    fn linestrings() {
+        let items = [
+            Item(square(0.0)),
+            Item(LineString::new(vec![])),
+            Item(square(2.0)),
+        ];
+
+        for interleaved in [false, true] {
+            let options = TracingOptions::default()
+                .overwrite(
+                    "item",
+                    LineStringField::new("item").interleaved(interleaved),
+                )
+                .unwrap();
+
+            Test::new()
+                .trace_schema_from_type::<Item<LineString>>(options.clone())
+                .trace_schema_from_samples(&items, options)
+                .serialize(&items)
+                .deserialize(&items);
+        }
+    }
+
+    #[test]
+    fn polygons() {
+        let items = [
+            Item(Polygon::new(square(0.0), vec![])),
+            Item(Polygon::new(square(0.0), vec![square(0.25), square(0.5)])),
+            Item(polygon![]),
+        ];
+
+        for (interleaved, coords) in [(false, separated()), (true, interleaved())] {
+            let options = TracingOptions::default()
+                .overwrite("item", PolygonField::new("item").interleaved(interleaved))
+                .unwrap();
+
+            Test::new()
+                .with_schema(json!([{
+                    "name": "item",
+                    "data_type": "List",
+                    "metadata": metadata("geoarrow.polygon"),
+                    "children": [{
+                        "name": "rings",
+                        "data_type": "List",
+                        "children": [with_name(coords, "vertices")],
+                    }],
+                }]))
+                .trace_schema_from_type::<Item<Polygon>>(options.clone())
+                .trace_schema_from_samples(&items, options)
+                .serialize(&items)
+                .deserialize(&items)
+                // the rings can also be deserialized as nested sequences
+                .deserialize(&[
+                    Item(vec![square(0.0)]),
+                    Item(vec![square(0.0), square(0.25), square(0.5)]),
+                    Item(vec![LineString::new(vec![])]),
+                ]);
+        }
+    }
+
+    #[test]
+    fn nullable_polygons() {
+        let items = [
+            Item(Some(Polygon::new(square(0.0), vec![square(0.25)]))),
+            Item(None),
+        ];
+        let options = TracingOptions::default()
+            .overwrite("item", PolygonField::new("item").nullable(true))
+            .unwrap();
+
+        Test::new()
+            .trace_schema_from_type::<Item<Option<Polygon>>>(options.clone())
+            .trace_schema_from_samples(&items, options)
+            .serialize(&items)
+            .deserialize(&items)
+            .check_nulls(&[&[false, true]]);
+    }
+
+    #[test]
+    fn structs_require_the_polygon_extension() {
+        let fields = SerdeArrowSchema::from_value(json!([{
+            "name": "item",
+            "data_type": "List",
+            "children": [{
+                "name": "rings",
+                "data_type": "List",
+                "children": [with_name(separated(), "vertices")],
+            }],
+        }]))
+        .unwrap()
+        .fields;
+
+        let items = [Item(Polygon::new(square(0.0), vec![]))];
+        let res = crate::to_marrow(&fields, &items);
+        assert_error_contains(&res, "geoarrow.polygon");
+
+        let arrays = crate::to_marrow(&fields, &[Item(vec![square(0.0)])]).unwrap();
+        let views = arrays
+            .iter()
+            .map(|array| array.as_view())
+            .collect::<Vec<_>>();
+        let res = crate::from_marrow::<Vec<Item<Polygon>>>(&fields, &views);
+        assert_error_contains(&res, "geoarrow.polygon");
+    }
+
+    #[test]
+    fn polygons_without_exterior_ring_are_rejected() {
+        let fields = SerdeArrowSchema::from_value(json!([{
+            "name": "item",
+            "data_type": "List",
+            "metadata": metadata("geoarrow.polygon"),
+            "children": [{
+                "name": "rings",
+                "data_type": "List",
+                "children": [with_name(separated(), "vertices")],
+            }],
+        }]))
+        .unwrap()
+        .fields;
+
+        let arrays = crate::to_marrow(&fields, &[Item(Vec::<LineString>::new())]).unwrap();
+        let views = arrays
+            .iter()
+            .map(|array| array.as_view())
+            .collect::<Vec<_>>();
+        let res = crate::from_marrow::<Vec<Item<Polygon>>>(&fields, &views);
+        assert_error_contains(&res, "Polygons require an exterior ring");
+    }
+}
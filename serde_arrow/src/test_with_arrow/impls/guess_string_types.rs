@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::internal::{schema::TracingOptions, testing::assert_error_contains, utils::Item};
+
+use super::utils::Test;
+
+fn options() -> TracingOptions {
+    TracingOptions::default()
+        .guess_dates(true)
+        .guess_integers(true)
+        .guess_decimals(true)
+        .guess_booleans(true)
+        .guess_uuids(true)
+}
+
+#[test]
+fn roundtrip() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        int: String,
+        dec: String,
+        flag: String,
+        id: String,
+        offset: String,
+        local: String,
+        text: String,
+    }
+
+    let record = |int: &str, dec: &str, flag: &str, offset: &str, local: &str| Record {
+        int: int.to_owned(),
+        dec: dec.to_owned(),
+        flag: flag.to_owned(),
+        id: String::from("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+        offset: offset.to_owned(),
+        local: local.to_owned(),
+        text: String::from("hello"),
+    };
+    let items = [
+        record(
+            "-42",
+            "12.50",
+            "true",
+            "2024-08-09T12:15:00+05:30",
+            "09/08/2024 12:15",
+        ),
+        record(
+            "7",
+            "-0.25",
+            "false",
+            "1999-12-31T23:59:59+05:30",
+            "31/12/1999 23:59",
+        ),
+    ];
+
+    Test::new()
+        .with_schema(json!([
+            {"name": "int", "data_type": "I64", "metadata": {"SERDE_ARROW:string_encoded": "true"}},
+            {"name": "dec", "data_type": "Decimal128(4, 2)"},
+            {"name": "flag", "data_type": "Bool", "metadata": {"SERDE_ARROW:string_encoded": "true"}},
+            {
+                "name": "id",
+                "data_type": "FixedSizeBinary(16)",
+                "metadata": {"ARROW:extension:name": "arrow.uuid", "ARROW:extension:metadata": ""},
+            },
+            {"name": "offset", "data_type": "Timestamp(Millisecond, Some(\"+05:30\"))"},
+            {
+                "name": "local",
+                "data_type": "Timestamp(Millisecond, None)",
+                "metadata": {"SERDE_ARROW:datetime_pattern": "%d/%m/%Y %H:%M"},
+            },
+            {"name": "text", "data_type": "LargeUtf8"},
+        ]))
+        .trace_schema_from_samples(&items, options().datetime_pattern("%d/%m/%Y %H:%M"))
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn strings_and_numbers() {
+    let items = json!([{"item": "1"}, {"item": 2}, {"item": -3}]);
+    Test::new()
+        .with_schema(json!([
+            {"name": "item", "data_type": "I64", "metadata": {"SERDE_ARROW:string_encoded": "true"}},
+        ]))
+        .trace_schema_from_samples(&items, options().coerce_numbers(true))
+        .serialize(&items)
+        .deserialize(&[Item(1_i64), Item(2), Item(-3)]);
+}
+
+#[test]
+fn strings_require_the_string_encoded_metadata() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        int: String,
+        flag: String,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Typed {
+        int: i64,
+        flag: bool,
+    }
+
+    let mut test = Test::new().with_schema(json!([
+        {"name": "int", "data_type": "I64"},
+        {"name": "flag", "data_type": "Bool"},
+    ]));
+
+    let res = test.try_serialize_arrow(&[Record {
+        int: String::from("42"),
+        flag: String::from("true"),
+    }]);
+    assert_error_contains(
+        &res,
+        "Only integer fields with the SERDE_ARROW:string_encoded metadata accept strings",
+    );
+
+    test.try_serialize_arrow(&[Typed {
+        int: 42,
+        flag: true,
+    }])
+    .unwrap();
+    let fields = test.get_arrow_fields();
+    let arrays = test.arrays.arrow.as_ref().unwrap();
+    let res = crate::from_arrow::<Vec<Record>, _>(&fields, arrays);
+    assert_error_contains(
+        &res,
+        "Only integer fields with the SERDE_ARROW:string_encoded metadata can be deserialized as strings",
+    );
+
+    let mut test = Test::new().with_schema(json!([{"name": "item", "data_type": "Bool"}]));
+    let res = test.try_serialize_arrow(&[Item("true")]);
+    assert_error_contains(
+        &res,
+        "Only boolean fields with the SERDE_ARROW:string_encoded metadata accept strings",
+    );
+}
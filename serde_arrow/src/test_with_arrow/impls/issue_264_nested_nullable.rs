@@ -23,7 +23,7 @@ fn nested_nullable() {
 
     Test::new()
         .with_schema(
-            &json!([{"name": "a", "data_type": "Struct", "nullable": true, "children": [
+            json!([{"name": "a", "data_type": "Struct", "nullable": true, "children": [
                 {"name": "a", "data_type": "Struct", "children": [
                     {"name": "a", "data_type": "Dictionary", "children": [
                         {"name": "key", "data_type": "U32"},
@@ -36,7 +36,7 @@ fn nested_nullable() {
 
     Test::new()
         .with_schema(
-            &json!([{"name": "a", "data_type": "Struct", "nullable": true, "children": [
+            json!([{"name": "a", "data_type": "Struct", "nullable": true, "children": [
                 {"name": "a", "data_type": "Struct", "children": [
                     {"name": "a", "data_type": "Union", "children": [
                         {"name": "A", "data_type": "Null", "nullable": true},
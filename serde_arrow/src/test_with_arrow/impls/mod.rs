@@ -11,8 +11,10 @@ mod arrow_dictionary;
 #[cfg(has_arrow_fixed_binary_support)]
 mod arrow_fixed_size_binary;
 mod arrow_fixed_size_list;
+mod arrow_interval;
 mod arrow_list;
 mod arrow_map;
+mod arrow_run_end_encoded;
 mod arrow_struct;
 mod arrow_time;
 mod arrow_timestamp;
@@ -30,13 +32,21 @@ mod third_party_chrono;
 mod third_party_jiff;
 mod third_party_rust_decimal;
 mod third_party_serde_json;
+mod third_party_time;
 mod third_party_uuid;
 
 // unsorted tests
 mod bool8;
 mod examples;
+mod extension_registry;
+mod fixed_shape_tensor;
+mod geoarrow;
+mod guess_string_types;
+mod narrow_integers;
 mod primitives;
+mod std_time;
 mod tuple;
+mod variable_shape_tensor;
 mod wrappers;
 
 mod issue_74_unknown_fields;
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::internal::{schema::TracingOptions, testing::assert_error_contains, utils::Item};
+
+use super::utils::Test;
+
+fn options() -> TracingOptions {
+    TracingOptions::default().narrow_integers(true)
+}
+
+#[test]
+fn roundtrip() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        count: u64,
+        delta: i64,
+        ratio: f64,
+    }
+
+    let items = [
+        Record {
+            count: 3,
+            delta: -20,
+            ratio: 1.0,
+        },
+        Record {
+            count: 250,
+            delta: 300,
+            ratio: 4.0,
+        },
+    ];
+
+    Test::new()
+        .with_schema(json!([
+            {"name": "count", "data_type": "U8", "metadata": {"SERDE_ARROW:min": "3", "SERDE_ARROW:max": "250"}},
+            {"name": "delta", "data_type": "I16", "metadata": {"SERDE_ARROW:min": "-20", "SERDE_ARROW:max": "300"}},
+            {"name": "ratio", "data_type": "U8", "metadata": {"SERDE_ARROW:min": "1", "SERDE_ARROW:max": "4"}},
+        ]))
+        .trace_schema_from_samples(&items, options())
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|test| {
+            let res = test.try_serialize_arrow(&[Record {
+                count: 1,
+                delta: 0,
+                ratio: 0.5,
+            }]);
+            assert_error_contains(
+                &res,
+                "Cannot store the non-integral float 0.5 in an integer field",
+            );
+        });
+}
+
+#[test]
+fn floats_require_narrowed_fields() {
+    let mut test = Test::new().with_schema(json!([{"name": "item", "data_type": "U8"}]));
+
+    let res = test.try_serialize_arrow(&[Item(1.0_f64)]);
+    assert_error_contains(
+        &res,
+        "Only integer fields with the SERDE_ARROW:min and SERDE_ARROW:max metadata accept floats",
+    );
+
+    test.try_serialize_arrow(&[Item(1_u8)]).unwrap();
+    let fields = test.get_arrow_fields();
+    let arrays = test.arrays.arrow.as_ref().unwrap();
+    let res = crate::from_arrow::<Vec<Item<f64>>, _>(&fields, arrays);
+    assert_error_contains(
+        &res,
+        "Only integer fields with the SERDE_ARROW:min and SERDE_ARROW:max metadata can be deserialized as floats",
+    );
+}
+
+#[test]
+fn lossy_float_conversions_are_rejected() {
+    let items = [Item(16_777_217.0_f64)];
+    let test = Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "U32",
+            "metadata": {"SERDE_ARROW:min": "16777217", "SERDE_ARROW:max": "16777217"},
+        }]))
+        .trace_schema_from_samples(&items, options())
+        .serialize(&items)
+        .deserialize(&items);
+
+    let fields = test.get_arrow_fields();
+    let arrays = test.arrays.arrow.as_ref().unwrap();
+    let res = crate::from_arrow::<Vec<Item<f32>>, _>(&fields, arrays);
+    assert_error_contains(
+        &res,
+        "Cannot convert 16777217 to f32 without loss of precision",
+    );
+}
@@ -0,0 +1,171 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use marrow::{array::Array, datatypes::Field};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TracingOptions},
+    utils::Item,
+};
+
+use super::utils::Test;
+
+fn durations() -> [Item<Duration>; 3] {
+    [
+        Item(Duration::new(1, 500_000_000)),
+        Item(Duration::new(0, 0)),
+        Item(Duration::new(3_600, 0)),
+    ]
+}
+
+fn system_times() -> [Item<SystemTime>; 2] {
+    [
+        Item(UNIX_EPOCH + Duration::new(1_700_000_000, 123_000_000)),
+        Item(UNIX_EPOCH),
+    ]
+}
+
+#[test]
+fn duration_traced() {
+    let items = durations();
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Duration(Nanosecond)"}]))
+        .trace_schema_from_type::<Item<Duration>>(TracingOptions::default())
+        .trace_schema_from_samples(&items, TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|test| {
+            let Some(Array::Duration(array)) = &test.arrays.marrow.as_ref().unwrap().first() else {
+                panic!("Expected a duration array");
+            };
+            assert_eq!(array.values, vec![1_500_000_000, 0, 3_600_000_000_000]);
+        });
+}
+
+#[test]
+fn duration_milliseconds() {
+    let items = durations();
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Duration(Millisecond)"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|test| {
+            let Some(Array::Duration(array)) = &test.arrays.marrow.as_ref().unwrap().first() else {
+                panic!("Expected a duration array");
+            };
+            assert_eq!(array.values, vec![1_500, 0, 3_600_000]);
+        });
+}
+
+#[test]
+fn duration_truncates_sub_unit_nanoseconds() {
+    let items = [Item(Duration::new(2, 999_999_999))];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Duration(Second)"}]))
+        .serialize(&items)
+        .deserialize(&[Item(Duration::new(2, 0))]);
+}
+
+#[test]
+fn nullable_duration() {
+    let items = [Item(Some(Duration::new(1, 0))), Item(None)];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Duration(Nanosecond)",
+            "nullable": true,
+        }]))
+        .trace_schema_from_type::<Item<Option<Duration>>>(TracingOptions::default())
+        .trace_schema_from_samples(&items, TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true]]);
+}
+
+#[test]
+fn system_time_traced() {
+    let items = system_times();
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Timestamp(Nanosecond, Some(\"UTC\"))"}]))
+        .trace_schema_from_type::<Item<SystemTime>>(TracingOptions::default())
+        .trace_schema_from_samples(&items, TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|test| {
+            let Some(Array::Timestamp(array)) = &test.arrays.marrow.as_ref().unwrap().first()
+            else {
+                panic!("Expected a timestamp array");
+            };
+            assert_eq!(array.values, vec![1_700_000_000_123_000_000, 0]);
+        });
+}
+
+#[test]
+fn system_time_microseconds_without_timezone() {
+    let items = system_times();
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Timestamp(Microsecond, None)"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|test| {
+            let Some(Array::Timestamp(array)) = &test.arrays.marrow.as_ref().unwrap().first()
+            else {
+                panic!("Expected a timestamp array");
+            };
+            assert_eq!(array.values, vec![1_700_000_000_123_000, 0]);
+        });
+}
+
+#[test]
+fn user_structs_named_duration_are_traced_as_structs() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(rename = "Duration")]
+    struct Wrapper {
+        secs: u64,
+        unit: String,
+    }
+
+    let items = [Item(Wrapper {
+        secs: 5,
+        unit: String::from("s"),
+    })];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Struct",
+            "children": [
+                {"name": "secs", "data_type": "U64"},
+                {"name": "unit", "data_type": "LargeUtf8"},
+            ],
+        }]))
+        .trace_schema_from_type::<Item<Wrapper>>(TracingOptions::default())
+        .trace_schema_from_samples(&items, TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn negative_values_cannot_be_deserialized_as_std_time() {
+    let fields = Vec::<Field>::from_value(json!([
+        {"name": "item", "data_type": "Duration(Second)"},
+    ]))
+    .unwrap();
+
+    let arrays = crate::to_marrow(&fields, [Item(-5_i64)]).unwrap();
+    let views = arrays
+        .iter()
+        .map(|array| array.as_view())
+        .collect::<Vec<_>>();
+
+    let res = crate::from_marrow::<Vec<Item<Duration>>>(&fields, &views);
+    assert_error_contains(&res, "Cannot convert the negative value -5");
+}
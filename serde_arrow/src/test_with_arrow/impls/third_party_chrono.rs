@@ -1,17 +1,15 @@
 use marrow::datatypes::DataType;
 
-use super::utils::Test;
+use super::utils::{ArrayAccess, Test};
 use crate::{
-    internal::{
-        testing::{assert_error_contains, ArrayAccess},
-        utils::value,
-    },
+    internal::{testing::assert_error_contains, utils::value},
     schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
     utils::Item,
     ArrayBuilder,
 };
 
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 #[test]
@@ -106,6 +104,216 @@ mod datetime_utc {
     }
 }
 
+mod datetime_fixed_offset {
+    use chrono::FixedOffset;
+
+    use super::*;
+
+    fn items() -> Vec<Item<DateTime<FixedOffset>>> {
+        let offset = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+        vec![
+            Item(offset.with_ymd_and_hms(2020, 12, 24, 8, 30, 0).unwrap()),
+            Item(offset.with_ymd_and_hms(2023, 5, 5, 16, 6, 0).unwrap()),
+            Item(offset.with_ymd_and_hms(-10, 10, 30, 0, 0, 0).unwrap()),
+        ]
+    }
+
+    #[test]
+    fn as_timestamp_second() {
+        let items = items();
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Timestamp(Second, Some(\"+05:30\"))",
+            }]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn as_timestamp_millisecond() {
+        let items = items();
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Timestamp(Millisecond, Some(\"+05:30\"))",
+            }]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn values_are_normalized_to_utc() {
+        let offset = FixedOffset::west_opt(8 * 3600).unwrap();
+        let items = [Item(
+            offset.with_ymd_and_hms(2020, 12, 24, 8, 30, 0).unwrap(),
+        )];
+
+        let fields = SerdeArrowSchema::from_value(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Second, Some(\"+05:30\"))",
+        }]))
+        .unwrap()
+        .fields;
+
+        let arrays = crate::to_marrow(&fields, &items).unwrap();
+        let marrow::array::Array::Timestamp(array) = &arrays[0] else {
+            panic!("Expected timestamp array");
+        };
+        assert_eq!(
+            array.values,
+            vec![Utc
+                .with_ymd_and_hms(2020, 12, 24, 16, 30, 0)
+                .unwrap()
+                .timestamp()],
+        );
+
+        let views = arrays
+            .iter()
+            .map(|array| array.as_view())
+            .collect::<Vec<_>>();
+        let actual: Vec<Item<String>> = crate::from_marrow(&fields, &views).unwrap();
+        assert_eq!(
+            actual,
+            vec![Item(String::from("2020-12-24T22:00:00+05:30"))]
+        );
+    }
+
+    #[test]
+    fn annotated_strings() {
+        let items = items();
+        let strings = [
+            Item(String::from("2020-12-24T08:30:00+05:30[+05:30]")),
+            Item(String::from("2023-05-05T16:06:00+05:30[+05:30]")),
+            Item(String::from("-000010-10-30T00:00:00+05:30[+05:30]")),
+        ];
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Timestamp(Second, Some(\"+05:30\"))",
+                "metadata": {"SERDE_ARROW:time_zone_annotation": "true"},
+            }]))
+            .serialize(&items)
+            .deserialize(&strings);
+    }
+
+    #[test]
+    fn strings_without_offset_are_rejected() {
+        let items = [Item("2020-12-24T08:30:00")];
+        let mut test = Test::new().with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Second, Some(\"+05:30\"))",
+        }]));
+        let res = test.try_serialize_arrow(&items);
+        assert_error_contains(&res, "Timestamp(..)");
+    }
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+#[test]
+fn named_time_zones_require_feature() {
+    let items = [Item("2020-12-24T08:30:00+01:00")];
+    let mut test = Test::new().with_schema(json!([{
+        "name": "item",
+        "data_type": "Timestamp(Second, Some(\"Europe/Berlin\"))",
+    }]));
+    let res = test.try_serialize_arrow(&items);
+    assert_error_contains(&res, "chrono-tz");
+}
+
+#[cfg(feature = "chrono-tz")]
+mod datetime_named_time_zone {
+    use super::*;
+
+    #[test]
+    fn roundtrip_strings() {
+        // the offset of Europe/Berlin depends on daylight saving time
+        let items = [
+            Item(String::from("2020-12-24T08:30:00+01:00[Europe/Berlin]")),
+            Item(String::from("2023-05-05T16:06:00+02:00[Europe/Berlin]")),
+        ];
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Timestamp(Millisecond, Some(\"Europe/Berlin\"))",
+            }]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn values_are_converted_into_the_time_zone() {
+        let items = [Item(Utc.with_ymd_and_hms(2023, 5, 5, 16, 6, 0).unwrap())];
+
+        let fields = SerdeArrowSchema::from_value(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Millisecond, Some(\"Europe/Berlin\"))",
+        }]))
+        .unwrap()
+        .fields;
+
+        let arrays = crate::to_marrow(&fields, &items).unwrap();
+        let views = arrays
+            .iter()
+            .map(|array| array.as_view())
+            .collect::<Vec<_>>();
+        let actual: Vec<Item<String>> = crate::from_marrow(&fields, &views).unwrap();
+        assert_eq!(
+            actual,
+            vec![Item(String::from(
+                "2023-05-05T18:06:00+02:00[Europe/Berlin]"
+            ))]
+        );
+    }
+
+    #[test]
+    fn unknown_time_zones_are_rejected() {
+        let items = [Item("2020-12-24T08:30:00+01:00")];
+        let mut test = Test::new().with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Second, Some(\"Europe/Nowhere\"))",
+        }]));
+        let res = test.try_serialize_arrow(&items);
+        assert_error_contains(&res, "Timezone Europe/Nowhere is not supported");
+    }
+
+    #[test]
+    fn roundtrip_without_annotation() {
+        let offset = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+        let items = [
+            Item(offset.with_ymd_and_hms(2023, 5, 5, 16, 6, 0).unwrap()),
+            Item(offset.with_ymd_and_hms(2023, 7, 1, 0, 0, 0).unwrap()),
+        ];
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Timestamp(Millisecond, Some(\"Europe/Berlin\"))",
+                "metadata": {"SERDE_ARROW:time_zone_annotation": "false"},
+            }]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn invalid_annotation_metadata_is_rejected() {
+        let fields = SerdeArrowSchema::from_value(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Millisecond, Some(\"Europe/Berlin\"))",
+            "metadata": {"SERDE_ARROW:time_zone_annotation": "yes"},
+        }]))
+        .unwrap()
+        .fields;
+
+        let arrays = crate::to_marrow(&fields, &[Item("2023-05-05T16:06:00+02:00")]).unwrap();
+        let views = arrays
+            .iter()
+            .map(|array| array.as_view())
+            .collect::<Vec<_>>();
+        let res = crate::from_marrow::<Vec<Item<String>>>(&fields, &views);
+        assert_error_contains(&res, "SERDE_ARROW:time_zone_annotation");
+    }
+}
+
 mod naive_date_time {
     use super::*;
 
@@ -385,3 +593,39 @@ mod naive_date {
             .deserialize(&items);
     }
 }
+
+#[test]
+fn type_overwrites_require_a_newtype() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Timestamp(NaiveDateTime);
+
+    fn timestamp(s: &str) -> NaiveDateTime {
+        s.parse().unwrap()
+    }
+
+    let field = json!({"name": "ts", "data_type": "Timestamp(Microsecond, None)"});
+    let options = TracingOptions::default()
+        .overwrite_type("NaiveDateTime", &field)
+        .unwrap()
+        .overwrite_type("Timestamp", &field)
+        .unwrap();
+
+    // `NaiveDateTime` does not pass its name to serde, the type overwrite has no effect
+    let items = [Item(timestamp("2024-01-01T12:00:00"))];
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "LargeUtf8"}]))
+        .trace_schema_from_samples(&items, options.clone());
+    let res = SerdeArrowSchema::from_type::<Item<NaiveDateTime>>(options.clone());
+    assert_error_contains(&res, "non self describing type");
+
+    let items = [
+        Item(Timestamp(timestamp("2024-01-01T12:00:00"))),
+        Item(Timestamp(timestamp("2023-11-14T22:13:20.123456"))),
+    ];
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Timestamp(Microsecond, None)"}]))
+        .trace_schema_from_type::<Item<Timestamp>>(options.clone())
+        .trace_schema_from_samples(&items, options)
+        .serialize(&items)
+        .deserialize(&items);
+}
@@ -329,7 +329,7 @@ mod span {
 
     impl std::cmp::PartialEq for EquivalentSpan {
         fn eq(&self, other: &Self) -> bool {
-            match self.0.compare(&other.0) {
+            match self.0.compare(other.0) {
                 Ok(ordering) => ordering == std::cmp::Ordering::Equal,
                 Err(_) => false,
             }
@@ -417,6 +417,43 @@ mod span {
             .serialize(&items)
             .deserialize(&items);
     }
+
+    #[test]
+    fn as_interval_day_time() {
+        let items = items(TimeUnit::Millisecond);
+        Test::new()
+            .skip_arrow2()
+            .with_schema(json!([{"name": "item", "data_type": "Interval(DayTime)"}]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn as_interval_month_day_nano() {
+        let items = items(TimeUnit::Nanosecond);
+        Test::new()
+            .skip_arrow2()
+            .with_schema(json!([{"name": "item", "data_type": "Interval(MonthDayNano)"}]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn calendar_units_as_interval_month_day_nano() {
+        let items = vec![
+            Item(Span::new().years(1).months(2).days(3).to_string()),
+            Item(Span::new().months(7).hours(2).to_string()),
+        ];
+        let expected = vec![
+            Item(String::from("P14m3d")),
+            Item(String::from("P7mT7200.000000000s")),
+        ];
+        Test::new()
+            .skip_arrow2()
+            .with_schema(json!([{"name": "item", "data_type": "Interval(MonthDayNano)"}]))
+            .serialize(&items)
+            .deserialize(&expected);
+    }
 }
 
 mod signed_duration {
@@ -486,3 +523,98 @@ mod signed_duration {
             .deserialize(&items);
     }
 }
+
+mod zoned_fixed_offset {
+    use jiff::{
+        tz::{Offset, TimeZone},
+        Zoned,
+    };
+
+    use super::*;
+    use crate::schema::{SchemaLike, SerdeArrowSchema};
+
+    fn items() -> Vec<Item<Zoned>> {
+        let tz = TimeZone::fixed(Offset::from_seconds(5 * 3600 + 30 * 60).unwrap());
+        vec![
+            Item(
+                date(2024, 1, 2)
+                    .at(20, 26, 12, 0)
+                    .to_zoned(tz.clone())
+                    .unwrap(),
+            ),
+            Item(date(1970, 1, 1).at(0, 0, 0, 0).to_zoned(tz).unwrap()),
+        ]
+    }
+
+    #[test]
+    fn as_timestamp_second() {
+        let items = items();
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Timestamp(Second, Some(\"+05:30\"))",
+                "metadata": {"SERDE_ARROW:time_zone_annotation": "true"},
+            }]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn annotation_is_required() {
+        let fields = SerdeArrowSchema::from_value(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Second, Some(\"+05:30\"))",
+        }]))
+        .unwrap()
+        .fields;
+
+        let arrays = crate::to_marrow(&fields, items()).unwrap();
+        let views = arrays
+            .iter()
+            .map(|array| array.as_view())
+            .collect::<Vec<_>>();
+        let res = crate::from_marrow::<Vec<Item<Zoned>>>(&fields, &views);
+        assert!(res.is_err());
+    }
+}
+
+#[cfg(feature = "chrono-tz")]
+mod zoned {
+    use jiff::Zoned;
+
+    use super::*;
+
+    // NOTE: historic dates are not included, as local mean time offsets contain seconds that
+    // cannot be expressed in RFC 3339 strings
+    fn items(tz: &str) -> Vec<Item<Zoned>> {
+        vec![
+            Item(date(2024, 1, 2).at(20, 26, 12, 0).intz(tz).unwrap()),
+            Item(date(2024, 7, 2).at(8, 0, 0, 0).intz(tz).unwrap()),
+            Item(date(1970, 1, 1).at(0, 0, 0, 0).intz(tz).unwrap()),
+        ]
+    }
+
+    #[test]
+    fn as_timestamp_millisecond() {
+        let items = items("Europe/Berlin");
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Timestamp(Millisecond, Some(\"Europe/Berlin\"))",
+            }]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn as_timestamp_second_in_other_zone() {
+        let items = items("Asia/Kolkata");
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Timestamp(Second, Some(\"Asia/Kolkata\"))",
+            }]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+}
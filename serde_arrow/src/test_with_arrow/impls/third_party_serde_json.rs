@@ -184,3 +184,207 @@ fn serde_json_nullable_strings_non_nullable_field() {
     assert_error_contains(&res, "Cannot push null for non-nullable array");
     assert_error_contains(&res, "field: \"$.a\"");
 }
+
+#[cfg(feature = "serde_json")]
+mod json_field {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{_impl::arrow::_raw::array::StringArray, schema::ext::JsonField, utils::Item};
+
+    use super::*;
+
+    fn json_schema(nullable: bool) -> Value {
+        json!([{
+            "name": "item",
+            "data_type": "Utf8",
+            "nullable": nullable,
+            "metadata": {
+                "ARROW:extension:name": "arrow.json",
+                "ARROW:extension:metadata": "",
+            },
+        }])
+    }
+
+    fn get_strings(test: &Test) -> Vec<Option<String>> {
+        let arrays = test.arrays.arrow.as_ref().unwrap();
+        let arr = arrays[0].as_any().downcast_ref::<StringArray>().unwrap();
+        arr.iter().map(|s| s.map(String::from)).collect()
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Circle { radius: f64 },
+        Polygon(Vec<(i32, i32)>),
+        Empty,
+    }
+
+    #[test]
+    fn serde_json_values() {
+        let items = [
+            Item(json!({"a": 1, "b": [true, null]})),
+            Item(json!("hello")),
+            Item(json!(null)),
+        ];
+        let options = TracingOptions::default()
+            .overwrite("item", JsonField::new("item"))
+            .unwrap();
+
+        Test::new()
+            .with_schema(json_schema(false))
+            .trace_schema_from_type::<Item<Value>>(options.clone())
+            .trace_schema_from_samples(&items, options)
+            .serialize(&items)
+            .deserialize(&items)
+            .also(|it| {
+                assert_eq!(
+                    get_strings(it),
+                    [
+                        Some(String::from(r#"{"a":1,"b":[true,null]}"#)),
+                        Some(String::from(r#""hello""#)),
+                        Some(String::from("null")),
+                    ]
+                );
+            });
+    }
+
+    #[test]
+    fn rust_types_round_trip() {
+        let items = [
+            Item(Shape::Circle { radius: 2.5 }),
+            Item(Shape::Polygon(vec![(0, 0), (1, 0), (0, 1)])),
+            Item(Shape::Empty),
+        ];
+        let options = TracingOptions::default()
+            .overwrite("item", JsonField::new("item"))
+            .unwrap();
+
+        Test::new()
+            .with_schema(json_schema(false))
+            .trace_schema_from_type::<Item<Shape>>(options.clone())
+            .trace_schema_from_samples(&items, options)
+            .serialize(&items)
+            .deserialize(&items)
+            .also(|it| {
+                assert_eq!(
+                    get_strings(it),
+                    [
+                        Some(String::from(r#"{"Circle":{"radius":2.5}}"#)),
+                        Some(String::from(r#"{"Polygon":[[0,0],[1,0],[0,1]]}"#)),
+                        Some(String::from(r#""Empty""#)),
+                    ]
+                );
+            });
+    }
+
+    #[test]
+    fn maps() {
+        let items = [
+            Item(HashMap::from([(String::from("a"), 1_i64)])),
+            Item(HashMap::new()),
+        ];
+        let options = TracingOptions::default()
+            .map_as_struct(true)
+            .overwrite("item", JsonField::new("item"))
+            .unwrap();
+
+        Test::new()
+            .with_schema(json_schema(false))
+            .trace_schema_from_type::<Item<HashMap<String, i64>>>(options.clone())
+            .trace_schema_from_samples(&items, options)
+            .serialize(&items)
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn nullable() {
+        let items = [Item(Some(vec![1_u8, 2, 3])), Item(None), Item(Some(vec![]))];
+        let options = TracingOptions::default()
+            .overwrite("item", JsonField::new("item").nullable(true))
+            .unwrap();
+
+        Test::new()
+            .with_schema(json_schema(true))
+            .trace_schema_from_type::<Item<Option<Vec<u8>>>>(options.clone())
+            .trace_schema_from_samples(&items, options)
+            .serialize(&items)
+            .deserialize(&items)
+            .check_nulls(&[&[false, true, false]]);
+    }
+
+    #[test]
+    fn nested_fields() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            id: u32,
+            shape: Shape,
+        }
+
+        let items = [
+            Record {
+                id: 0,
+                shape: Shape::Empty,
+            },
+            Record {
+                id: 1,
+                shape: Shape::Circle { radius: 1.0 },
+            },
+        ];
+        let options = TracingOptions::default()
+            .overwrite("shape", JsonField::new("shape").large_utf8(true))
+            .unwrap();
+
+        Test::new()
+            .with_schema(json!([
+                {"name": "id", "data_type": "U32"},
+                {
+                    "name": "shape",
+                    "data_type": "LargeUtf8",
+                    "metadata": {
+                        "ARROW:extension:name": "arrow.json",
+                        "ARROW:extension:metadata": "",
+                    },
+                },
+            ]))
+            .trace_schema_from_type::<Record>(options.clone())
+            .trace_schema_from_samples(&items, options)
+            .serialize(&items)
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn explicit_strategy() {
+        let items = [Item(vec![1_i32, 2]), Item(vec![])];
+
+        Test::new()
+            .with_schema(json!([{"name": "item", "data_type": "Utf8", "strategy": "Json"}]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn invalid_json() {
+        use std::sync::Arc;
+
+        use crate::_impl::arrow::{array::ArrayRef, datatypes::FieldRef};
+
+        let fields: Vec<FieldRef> = Test::new()
+            .with_schema(json_schema(false))
+            .get_arrow_fields();
+        let arrays: Vec<ArrayRef> = vec![Arc::new(StringArray::from(vec!["{"]))];
+
+        let res = crate::from_arrow::<Vec<Item<Value>>, _>(&fields, &arrays);
+        assert_error_contains(&res, "serde_json::Error");
+    }
+}
+
+#[cfg(not(feature = "serde_json"))]
+#[test]
+fn json_strategy_requires_feature() {
+    let mut test = Test::new().with_schema(json!([{
+        "name": "item",
+        "data_type": "Utf8",
+        "strategy": "Json",
+    }]));
+    let res = test.try_serialize_arrow(&[crate::utils::Item(1_i32)]);
+    assert_error_contains(&res, "requires the serde_json feature");
+}
@@ -0,0 +1,331 @@
+use ::time::{
+    macros::{date, datetime, offset, time},
+    Date, Duration, OffsetDateTime, PrimitiveDateTime, Time,
+};
+use marrow::{array::Array, datatypes::Field};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    internal::{
+        schema::{SchemaLike, TracingOptions},
+        testing::assert_error_contains,
+        utils::Item,
+    },
+    test_with_arrow::impls::utils::Test,
+};
+
+mod date {
+    use super::*;
+
+    fn items() -> Vec<Item<Date>> {
+        vec![
+            Item(date!(2024 - 02 - 29)),
+            Item(date!(1969 - 07 - 20)),
+            Item(date!(1970 - 01 - 01)),
+        ]
+    }
+
+    #[test]
+    fn as_date32() {
+        let items = items();
+        Test::new()
+            .with_schema(json!([{"name": "item", "data_type": "Date32"}]))
+            .serialize(&items)
+            .deserialize(&items)
+            .also(|test| {
+                let Some(Array::Date32(array)) = &test.arrays.marrow.as_ref().unwrap().first()
+                else {
+                    panic!("Expected a date32 array");
+                };
+                assert_eq!(array.values, vec![19_782, -165, 0]);
+            });
+    }
+
+    #[test]
+    fn as_date64() {
+        let items = items();
+        Test::new()
+            .with_schema(json!([{"name": "item", "data_type": "Date64"}]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+}
+
+mod time {
+    use super::*;
+
+    #[test]
+    fn as_time32_second() {
+        let items = [Item(time!(12:00:00)), Item(time!(23:31:12))];
+        Test::new()
+            .with_schema(json!([{"name": "item", "data_type": "Time32(Second)"}]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn as_time64_nanosecond() {
+        let items = [Item(time!(3:02:58.123_456_789)), Item(time!(0:00))];
+        Test::new()
+            .with_schema(json!([{"name": "item", "data_type": "Time64(Nanosecond)"}]))
+            .serialize(&items)
+            .deserialize(&items)
+            .also(|test| {
+                let Some(Array::Time64(array)) = &test.arrays.marrow.as_ref().unwrap().first()
+                else {
+                    panic!("Expected a time64 array");
+                };
+                assert_eq!(array.values, vec![10_978_123_456_789, 0]);
+            });
+    }
+}
+
+mod primitive_date_time {
+    use super::*;
+
+    fn items() -> Vec<Item<PrimitiveDateTime>> {
+        vec![
+            Item(datetime!(2023-11-14 22:13:20.123)),
+            Item(datetime!(1969-12-31 23:59:59)),
+        ]
+    }
+
+    #[test]
+    fn as_timestamp_without_timezone() {
+        let items = items();
+        Test::new()
+            .with_schema(json!([{"name": "item", "data_type": "Timestamp(Millisecond, None)"}]))
+            .serialize(&items)
+            .deserialize(&items)
+            .also(|test| {
+                let Some(Array::Timestamp(array)) = &test.arrays.marrow.as_ref().unwrap().first()
+                else {
+                    panic!("Expected a timestamp array");
+                };
+                assert_eq!(array.values, vec![1_700_000_000_123, -1_000]);
+            });
+    }
+
+    #[test]
+    fn timestamps_with_timezone_are_rejected() {
+        let mut test = Test::new().with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Millisecond, Some(\"UTC\"))",
+        }]));
+
+        let res = test.try_serialize_arrow(&items());
+        assert_error_contains(
+            &res,
+            "Timestamps with time zone require date times with offset",
+        );
+    }
+}
+
+mod offset_date_time {
+    use super::*;
+
+    fn items() -> Vec<Item<OffsetDateTime>> {
+        vec![
+            Item(datetime!(2023-11-14 22:13:20.123 UTC)),
+            Item(datetime!(2023-11-15 03:43:20 +05:30)),
+        ]
+    }
+
+    #[test]
+    fn as_timestamp_utc() {
+        let items = items();
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Timestamp(Nanosecond, Some(\"UTC\"))",
+            }]))
+            .serialize(&items)
+            .deserialize(&items)
+            .also(|test| {
+                let Some(Array::Timestamp(array)) = &test.arrays.marrow.as_ref().unwrap().first()
+                else {
+                    panic!("Expected a timestamp array");
+                };
+                assert_eq!(
+                    array.values,
+                    vec![1_700_000_000_123_000_000, 1_700_000_000_000_000_000]
+                );
+            });
+    }
+
+    #[test]
+    fn fixed_offsets_are_restored() {
+        let fields = Vec::<Field>::from_value(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Second, Some(\"+05:30\"))",
+        }]))
+        .unwrap();
+
+        let items = items();
+        let arrays = crate::to_marrow(&fields, &items).unwrap();
+        let views = arrays
+            .iter()
+            .map(|array| array.as_view())
+            .collect::<Vec<_>>();
+        let actual: Vec<Item<OffsetDateTime>> = crate::from_marrow(&fields, &views).unwrap();
+
+        assert_eq!(actual[0].0, datetime!(2023-11-14 22:13:20 UTC));
+        assert_eq!(actual[0].0.offset(), offset!(+05:30));
+        assert_eq!(actual[1].0, items[1].0);
+        assert_eq!(actual[1].0.offset(), offset!(+05:30));
+    }
+
+    #[test]
+    fn timestamps_without_timezone_are_rejected() {
+        let mut test = Test::new()
+            .with_schema(json!([{"name": "item", "data_type": "Timestamp(Millisecond, None)"}]));
+
+        let res = test.try_serialize_arrow(&items());
+        assert_error_contains(
+            &res,
+            "Timestamps without time zone cannot store date times with offset",
+        );
+    }
+
+    #[test]
+    fn overflowing_offsets_are_rejected() {
+        let mut test = Test::new().with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Second, Some(\"UTC\"))",
+        }]));
+
+        let res = test.try_serialize_arrow(&[Item((2023, 318, 22, 13, 20, 0, i64::MAX, 0, 0))]);
+        assert_error_contains(&res, "Invalid offset tuple");
+    }
+
+    #[test]
+    fn nullable() {
+        let items = [Item(Some(datetime!(2023-11-14 22:13:20 UTC))), Item(None)];
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Timestamp(Millisecond, Some(\"UTC\"))",
+                "nullable": true,
+            }]))
+            .serialize(&items)
+            .deserialize(&items)
+            .check_nulls(&[&[false, true]]);
+    }
+
+    #[test]
+    fn rfc3339() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            #[serde(with = "::time::serde::rfc3339")]
+            ts: OffsetDateTime,
+        }
+
+        let items = [
+            Record {
+                ts: datetime!(2023-11-14 22:13:20.5 UTC),
+            },
+            Record {
+                ts: datetime!(1969-12-31 23:59:59 UTC),
+            },
+        ];
+        Test::new()
+            .with_schema(json!([{
+                "name": "ts",
+                "data_type": "Timestamp(Millisecond, Some(\"UTC\"))",
+            }]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn unix_timestamps() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            #[serde(with = "::time::serde::timestamp")]
+            seconds: OffsetDateTime,
+            #[serde(with = "::time::serde::timestamp::milliseconds")]
+            milliseconds: OffsetDateTime,
+            #[serde(with = "::time::serde::timestamp::nanoseconds")]
+            nanoseconds: OffsetDateTime,
+        }
+
+        let items = [Record {
+            seconds: datetime!(2023-11-14 22:13:20 UTC),
+            milliseconds: datetime!(2023-11-14 22:13:20.123 UTC),
+            nanoseconds: datetime!(2023-11-14 22:13:20.123456789 UTC),
+        }];
+        Test::new()
+            .with_schema(json!([
+                {"name": "seconds", "data_type": "Timestamp(Second, Some(\"UTC\"))"},
+                {"name": "milliseconds", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+                {"name": "nanoseconds", "data_type": "Timestamp(Nanosecond, Some(\"UTC\"))"},
+            ]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+}
+
+mod duration {
+    use super::*;
+
+    #[test]
+    fn as_duration() {
+        let items = [
+            Item(Duration::milliseconds(1_500)),
+            Item(Duration::milliseconds(-1_500)),
+            Item(Duration::ZERO),
+        ];
+        Test::new()
+            .with_schema(json!([{"name": "item", "data_type": "Duration(Millisecond)"}]))
+            .serialize(&items)
+            .deserialize(&items)
+            .also(|test| {
+                let Some(Array::Duration(array)) = &test.arrays.marrow.as_ref().unwrap().first()
+                else {
+                    panic!("Expected a duration array");
+                };
+                assert_eq!(array.values, vec![1_500, -1_500, 0]);
+            });
+    }
+}
+
+#[test]
+fn trace_from_type_with_overwrites() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        date: Date,
+        time: Time,
+        created_at: OffsetDateTime,
+        local: PrimitiveDateTime,
+        elapsed: Duration,
+    }
+
+    let items = [Record {
+        date: date!(2024 - 02 - 29),
+        time: time!(12:30),
+        created_at: datetime!(2023-11-14 22:13:20 UTC),
+        local: datetime!(2023-11-14 22:13:20),
+        elapsed: Duration::seconds(90),
+    }];
+
+    let schema = json!([
+        {"name": "date", "data_type": "Date32"},
+        {"name": "time", "data_type": "Time64(Nanosecond)"},
+        {"name": "created_at", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+        {"name": "local", "data_type": "Timestamp(Millisecond, None)"},
+        {"name": "elapsed", "data_type": "Duration(Second)"},
+    ]);
+
+    let mut options = TracingOptions::default();
+    for field in schema.as_array().unwrap() {
+        let name = field["name"].as_str().unwrap();
+        options = options.overwrite(name, field).unwrap();
+    }
+
+    Test::new()
+        .with_schema(schema.clone())
+        .trace_schema_from_type::<Record>(options)
+        .serialize(&items)
+        .deserialize(&items);
+}
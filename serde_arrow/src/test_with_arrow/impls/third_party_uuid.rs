@@ -33,3 +33,146 @@ fn trace_from_type_does_not_work() {
     assert_error_contains(&res, "UUID parsing failed");
     assert_error_contains(&res, "non self describing type");
 }
+
+mod uuid_field {
+    use serde::{Deserialize, Serialize};
+    use serde_bytes::ByteBuf;
+
+    use crate::schema::ext::UuidField;
+
+    use super::*;
+
+    fn uuid_schema(nullable: bool) -> serde_json::Value {
+        json!([{
+            "name": "item",
+            "data_type": "FixedSizeBinary(16)",
+            "nullable": nullable,
+            "metadata": {
+                "ARROW:extension:name": "arrow.uuid",
+                "ARROW:extension:metadata": "",
+            },
+        }])
+    }
+
+    #[test]
+    fn traced_with_overwrites() {
+        let items = [
+            Item(Uuid::new_v4()),
+            Item(Uuid::nil()),
+            Item(Uuid::new_v4()),
+        ];
+        let options = TracingOptions::default()
+            .overwrite("item", UuidField::new("item"))
+            .unwrap();
+
+        Test::new()
+            .with_schema(uuid_schema(false))
+            .trace_schema_from_type::<Item<Uuid>>(options.clone())
+            .trace_schema_from_samples(&items, options)
+            .serialize(&items)
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn type_overwrites_require_a_newtype() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Id(Uuid);
+
+        let options = TracingOptions::default()
+            .overwrite_type("Uuid", UuidField::new("id"))
+            .unwrap()
+            .overwrite_type("Id", UuidField::new("id"))
+            .unwrap();
+
+        // `Uuid` does not pass its name to serde, the type overwrite has no effect
+        let items = [Item(Uuid::new_v4())];
+        Test::new()
+            .with_schema(json!([{"name": "item", "data_type": "LargeUtf8"}]))
+            .trace_schema_from_samples(&items, options.clone());
+
+        let items = [Item(Id(Uuid::new_v4())), Item(Id(Uuid::nil()))];
+        Test::new()
+            .with_schema(uuid_schema(false))
+            .trace_schema_from_type::<Item<Id>>(options.clone())
+            .trace_schema_from_samples(&items, options)
+            .serialize(&items)
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn nullable() {
+        let items = [
+            Item(Some(Uuid::new_v4())),
+            Item(None),
+            Item(Some(Uuid::max())),
+        ];
+        let options = TracingOptions::default()
+            .overwrite("item", UuidField::new("item").nullable(true))
+            .unwrap();
+
+        Test::new()
+            .with_schema(uuid_schema(true))
+            .trace_schema_from_type::<Item<Option<Uuid>>>(options.clone())
+            .trace_schema_from_samples(&items, options)
+            .serialize(&items)
+            .deserialize(&items)
+            .check_nulls(&[&[false, true, false]]);
+    }
+
+    #[test]
+    fn strings_and_bytes() {
+        let uuid = Uuid::new_v4();
+        let items = [Item(uuid.hyphenated().to_string())];
+
+        Test::new()
+            .with_schema(uuid_schema(false))
+            .serialize(&items)
+            .deserialize(&items)
+            .deserialize(&[Item(ByteBuf::from(uuid.as_bytes().to_vec()))])
+            .deserialize(&[Item(uuid)]);
+
+        Test::new()
+            .with_schema(uuid_schema(false))
+            .serialize(&[Item(ByteBuf::from(uuid.as_bytes().to_vec()))])
+            .deserialize(&items);
+    }
+
+    #[test]
+    fn upper_case_strings_are_normalized() {
+        let uuid = Uuid::new_v4();
+
+        Test::new()
+            .with_schema(uuid_schema(false))
+            .serialize(&[Item(uuid.hyphenated().to_string().to_uppercase())])
+            .deserialize(&[Item(uuid.hyphenated().to_string())]);
+    }
+
+    #[test]
+    fn invalid_strings() {
+        let mut test = Test::new().with_schema(uuid_schema(false));
+        let res = test.try_serialize_arrow(&[Item("not-a-uuid")]);
+        assert_error_contains(&res, "Invalid UUID");
+    }
+
+    #[test]
+    fn strings_require_the_uuid_extension() {
+        let uuid = Uuid::new_v4();
+        let fields = SerdeArrowSchema::from_value(json!([
+            {"name": "item", "data_type": "FixedSizeBinary(16)"},
+        ]))
+        .unwrap()
+        .fields;
+
+        let res = crate::to_marrow(&fields, &[Item(uuid.hyphenated().to_string())]);
+        assert_error_contains(&res, "arrow.uuid extension");
+
+        let arrays =
+            crate::to_marrow(&fields, &[Item(ByteBuf::from(uuid.as_bytes().to_vec()))]).unwrap();
+        let views = arrays
+            .iter()
+            .map(|array| array.as_view())
+            .collect::<Vec<_>>();
+        let res = crate::from_marrow::<Vec<Item<String>>>(&fields, &views);
+        assert_error_contains(&res, "arrow.uuid extension");
+    }
+}
@@ -1,9 +1,14 @@
 use std::{env, sync::Arc};
 
+use marrow::array::{Array, BytesArray};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     _impl::{arrow, arrow2},
+    internal::{
+        error::{fail, Error},
+        utils::array_ext::get_bit_buffer,
+    },
     schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
     Result,
 };
@@ -281,3 +286,45 @@ impl Test {
         self
     }
 }
+
+pub trait ArrayAccess {
+    fn get_utf8(&self, idx: usize) -> Result<Option<&str>>;
+}
+
+impl ArrayAccess for Array {
+    fn get_utf8(&self, idx: usize) -> Result<Option<&str>> {
+        match self {
+            Self::Binary(array) | Self::Utf8(array) => get_utf8_impl(array, idx),
+            Self::LargeBinary(array) | Self::LargeUtf8(array) => get_utf8_impl(array, idx),
+            _ => fail!("invalid array type. does not support `get_utf8`"),
+        }
+    }
+}
+
+fn get_utf8_impl<O>(array: &BytesArray<O>, idx: usize) -> Result<Option<&str>>
+where
+    O: Copy,
+    usize: TryFrom<O>,
+    Error: From<<usize as TryFrom<O>>::Error>,
+{
+    if let Some(validity) = array.validity.as_ref() {
+        if !get_bit_buffer(validity, 0, idx)? {
+            return Ok(None);
+        }
+    }
+
+    let Some(start) = array.offsets.get(idx) else {
+        fail!("Could not get start for element {idx}");
+    };
+    let Some(end) = array.offsets.get(idx + 1) else {
+        fail!("Could not get end for element {idx}");
+    };
+
+    let start = usize::try_from(*start)?;
+    let end = usize::try_from(*end)?;
+    let Some(data) = array.data.get(start..end) else {
+        fail!("Invalid array. Could not get byte slice");
+    };
+
+    Ok(Some(std::str::from_utf8(data)?))
+}
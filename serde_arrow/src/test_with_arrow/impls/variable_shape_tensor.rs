@@ -0,0 +1,219 @@
+use marrow::array::Array;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    internal::testing::assert_error_contains,
+    schema::{ext::VariableShapeTensorField, SchemaLike, SerdeArrowSchema, TracingOptions},
+    utils::Item,
+};
+
+use super::utils::Test;
+
+fn tensor_field(ndim: usize) -> VariableShapeTensorField {
+    VariableShapeTensorField::new("item", json!({"name": "element", "data_type": "I32"}), ndim)
+        .unwrap()
+}
+
+fn storage_values<T: Serialize>(
+    field: &VariableShapeTensorField,
+    items: &T,
+) -> (Vec<i32>, Vec<i32>) {
+    let fields = SerdeArrowSchema::from_value([field]).unwrap().fields;
+    let arrays = crate::to_marrow(&fields, items).unwrap();
+    let Array::Struct(array) = &arrays[0] else {
+        panic!("Expected a Struct array, got {:?}", arrays[0]);
+    };
+    let Array::List(data) = &array.fields[0].1 else {
+        panic!("Expected a List array, got {:?}", array.fields[0].1);
+    };
+    let Array::Int32(data) = data.elements.as_ref() else {
+        panic!("Expected Int32 elements, got {:?}", data.elements);
+    };
+    let Array::FixedSizeList(shape) = &array.fields[1].1 else {
+        panic!(
+            "Expected a FixedSizeList array, got {:?}",
+            array.fields[1].1
+        );
+    };
+    let Array::Int32(shape) = shape.elements.as_ref() else {
+        panic!("Expected Int32 elements, got {:?}", shape.elements);
+    };
+    (data.values.clone(), shape.values.clone())
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Tensor {
+    data: Vec<i32>,
+    shape: Vec<usize>,
+}
+
+#[test]
+fn nested_vecs_with_ragged_shapes() {
+    let field = tensor_field(2);
+    let items = [
+        Item(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+        Item(vec![vec![7], vec![8], vec![9]]),
+        Item(vec![]),
+    ];
+
+    assert_eq!(
+        storage_values(&field, &items),
+        (vec![1, 2, 3, 4, 5, 6, 7, 8, 9], vec![2, 3, 3, 1, 0, 0]),
+    );
+    Test::new()
+        .with_schema([&field])
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false, false]]);
+}
+
+#[test]
+fn struct_form() {
+    let field = tensor_field(2);
+    let items = [
+        Item(Tensor {
+            data: vec![1, 2, 3, 4, 5, 6],
+            shape: vec![3, 2],
+        }),
+        Item(Tensor {
+            data: vec![7, 8],
+            shape: vec![1, 2],
+        }),
+    ];
+
+    assert_eq!(
+        storage_values(&field, &items),
+        (vec![1, 2, 3, 4, 5, 6, 7, 8], vec![3, 2, 1, 2]),
+    );
+    Test::new()
+        .with_schema([&field])
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn struct_form_with_nested_data() {
+    #[derive(Debug, Serialize)]
+    struct NestedTensor {
+        data: Vec<Vec<i32>>,
+        shape: Vec<usize>,
+    }
+
+    let field = tensor_field(2);
+    let items = [Item(NestedTensor {
+        data: vec![vec![1, 2], vec![3, 4], vec![5, 6]],
+        shape: vec![3, 2],
+    })];
+    assert_eq!(
+        storage_values(&field, &items),
+        (vec![1, 2, 3, 4, 5, 6], vec![3, 2]),
+    );
+
+    let items = [Item(NestedTensor {
+        data: vec![vec![1, 2], vec![3, 4], vec![5, 6]],
+        shape: vec![2, 3],
+    })];
+    let mut test = Test::new().with_schema([&field]);
+    let res = test.try_serialize_arrow(&items);
+    assert_error_contains(&res, "Shape mismatch in row 0");
+    assert_error_contains(&res, "does not match the data with shape [3, 2]");
+}
+
+#[test]
+fn nullable_tensors() {
+    let field = tensor_field(2).nullable(true);
+    let items = [
+        Item(Some(vec![vec![1], vec![2]])),
+        Item(None),
+        Item(Some(vec![vec![3, 4]])),
+    ];
+
+    Test::new()
+        .with_schema([&field])
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn permutation() {
+    // the logical shape [3, 2] is stored with the physical shape [2, 3]
+    let field = tensor_field(2).permutation(vec![1, 0]).unwrap();
+    let items = [
+        Item(vec![vec![1, 2], vec![3, 4], vec![5, 6]]),
+        Item(vec![vec![7, 8]]),
+    ];
+
+    assert_eq!(
+        storage_values(&field, &items),
+        (vec![1, 3, 5, 2, 4, 6, 7, 8], vec![2, 3, 2, 1]),
+    );
+    Test::new()
+        .with_schema([&field])
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn uniform_shape_violation() {
+    let field = tensor_field(2).uniform_shape(vec![Some(2), None]).unwrap();
+
+    let items = [
+        Item(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+        Item(vec![vec![7], vec![8]]),
+    ];
+    Test::new()
+        .with_schema([&field])
+        .serialize(&items)
+        .deserialize(&items);
+
+    let items = [
+        Item(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+        Item(vec![vec![7], vec![8], vec![9]]),
+    ];
+    let mut test = Test::new().with_schema([&field]);
+    let res = test.try_serialize_arrow(&items);
+    assert_error_contains(&res, "Shape mismatch in row 1");
+    assert_error_contains(&res, "dimension 0 must have the uniform size 2, found 3");
+    assert_error_contains(&res, "field: \"$.item\"");
+}
+
+#[test]
+fn shape_mismatch() {
+    let field = tensor_field(2);
+
+    let items = [Item(vec![vec![1, 2, 3], vec![4, 5]])];
+    let mut test = Test::new().with_schema([&field]);
+    let res = test.try_serialize_arrow(&items);
+    assert_error_contains(&res, "Shape mismatch in row 0");
+    assert_error_contains(&res, "expected 3 entries in dimension 1, found 2");
+
+    let items = [Item(Tensor {
+        data: vec![1, 2, 3],
+        shape: vec![2, 2],
+    })];
+    let res = test.try_serialize_arrow(&items);
+    assert_error_contains(&res, "the shape [2, 2] requires 4 elements, found 3");
+
+    let items = [Item(Tensor {
+        data: vec![1, 2, 3],
+        shape: vec![3],
+    })];
+    let res = test.try_serialize_arrow(&items);
+    assert_error_contains(&res, "expected 2 dimensions, found 1");
+}
+
+#[test]
+fn overwrites() {
+    let field = tensor_field(2);
+    let tracing_options = TracingOptions::default().overwrite("item", &field).unwrap();
+    let items = [Item(vec![vec![1, 2], vec![3, 4]]), Item(vec![vec![5]])];
+
+    Test::new()
+        .with_schema([&field])
+        .trace_schema_from_type::<Item<Vec<Vec<i32>>>>(tracing_options.clone())
+        .trace_schema_from_samples(&items, tracing_options)
+        .serialize(&items)
+        .deserialize(&items);
+}
@@ -5,6 +5,7 @@ use serde_json::json;
 use crate::internal::{
     error::PanicOnError,
     schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    testing::assert_error_contains,
 };
 
 /// https://github.com/chmp/serde_arrow/issues/187
@@ -16,7 +17,7 @@ fn example_issue_187() -> PanicOnError<()> {
         pub expiry: DateTime<Utc>,
     }
 
-    let expected = SerdeArrowSchema::from_value(&json!([
+    let expected = SerdeArrowSchema::from_value(json!([
         {"name": "expiry", "data_type": "Timestamp(Microsecond, None)"}
     ]))?;
 
@@ -47,7 +48,7 @@ fn example_nested_overwrites_vec() -> PanicOnError<()> {
     )?;
     let actual = SerdeArrowSchema::from_type::<Example>(options)?;
 
-    let expected = SerdeArrowSchema::from_value(&json!([
+    let expected = SerdeArrowSchema::from_value(json!([
         {
             "name": "date_times",
             "data_type": "LargeList",
@@ -77,7 +78,7 @@ fn example_nested_overwrites_structs() -> PanicOnError<()> {
         .overwrite("inner.value", json!({"name": "value", "data_type": "I32"}))?;
     let actual = SerdeArrowSchema::from_type::<Example>(options)?;
 
-    let expected = SerdeArrowSchema::from_value(&json!([
+    let expected = SerdeArrowSchema::from_value(json!([
         {
             "name": "inner",
             "data_type": "Struct",
@@ -108,7 +109,7 @@ fn example_nested_struct_overwrite_outer() -> PanicOnError<()> {
         .overwrite("field", json!({"name": "field", "data_type": "I32"}))?;
     let actual = SerdeArrowSchema::from_type::<Example>(options)?;
 
-    let expected = SerdeArrowSchema::from_value(&json!([
+    let expected = SerdeArrowSchema::from_value(json!([
         {
             "name": "inner",
             "data_type": "Struct",
@@ -138,10 +139,426 @@ fn example_with_arrow_field() {
             .unwrap(),
     )
     .unwrap();
-    let expected = SerdeArrowSchema::from_value(&json!([
+    let expected = SerdeArrowSchema::from_value(json!([
         {"name": "value", "data_type": "U64"}
     ]))
     .unwrap();
 
     assert_eq!(actual, expected);
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Event {
+    pub kind: String,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Record {
+    pub created_at: i64,
+    pub first: Event,
+    pub events: Vec<Event>,
+}
+
+fn timestamp_field(name: &str) -> serde_json::Value {
+    json!({"name": name, "data_type": "Timestamp(Millisecond, None)"})
+}
+
+fn record_schema(
+    created_at: &str,
+    first_created_at: &str,
+    events_kind: &str,
+    events_created_at: &str,
+) -> SerdeArrowSchema {
+    SerdeArrowSchema::from_value(json!([
+        {"name": "created_at", "data_type": created_at},
+        {
+            "name": "first",
+            "data_type": "Struct",
+            "children": [
+                {"name": "kind", "data_type": "LargeUtf8"},
+                {"name": "created_at", "data_type": first_created_at},
+            ],
+        },
+        {
+            "name": "events",
+            "data_type": "LargeList",
+            "children": [{
+                "name": "element",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "kind", "data_type": events_kind},
+                    {"name": "created_at", "data_type": events_created_at},
+                ],
+            }],
+        },
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn wildcard_overwrites_single_segment() -> PanicOnError<()> {
+    let options =
+        TracingOptions::default().overwrite("*.created_at", timestamp_field("created_at"))?;
+    let actual = SerdeArrowSchema::from_type::<Record>(options)?;
+
+    let ts = "Timestamp(Millisecond, None)";
+    assert_eq!(actual, record_schema("I64", ts, "LargeUtf8", "I64"));
+    Ok(())
+}
+
+#[test]
+fn wildcard_overwrites_any_number_of_segments() -> PanicOnError<()> {
+    let options =
+        TracingOptions::default().overwrite("**.created_at", timestamp_field("created_at"))?;
+    let actual = SerdeArrowSchema::from_type::<Record>(options)?;
+
+    let ts = "Timestamp(Millisecond, None)";
+    assert_eq!(actual, record_schema(ts, ts, "LargeUtf8", ts));
+    Ok(())
+}
+
+#[test]
+fn wildcard_overwrites_use_the_name_of_the_matched_field() -> PanicOnError<()> {
+    let options = TracingOptions::default().overwrite(
+        "events.element.*",
+        json!({"name": "value", "data_type": "Utf8"}),
+    )?;
+    let actual = SerdeArrowSchema::from_type::<Record>(options)?;
+    assert_eq!(actual, record_schema("I64", "I64", "Utf8", "Utf8"));
+
+    let options = TracingOptions::default()
+        .overwrite("*.created_at", json!({"name": "value", "data_type": "I32"}))?;
+    let res = SerdeArrowSchema::from_type::<Record>(options);
+    assert_error_contains(
+        &res,
+        "Invalid name for overwritten field \"first.created_at\": found \"value\", expected \"created_at\"",
+    );
+    Ok(())
+}
+
+#[test]
+fn wildcard_overwrites_within_segments() -> PanicOnError<()> {
+    let samples = [Record {
+        created_at: 0,
+        first: Event {
+            kind: String::from("a"),
+            created_at: 0,
+        },
+        events: vec![Event {
+            kind: String::from("b"),
+            created_at: 0,
+        }],
+    }];
+    let options = TracingOptions::default().overwrite("created_*", timestamp_field("x"))?;
+    let actual = SerdeArrowSchema::from_samples(&samples, options)?;
+
+    let ts = "Timestamp(Millisecond, None)";
+    assert_eq!(actual, record_schema(ts, "I64", "LargeUtf8", "I64"));
+    Ok(())
+}
+
+#[test]
+fn wildcard_overwrite_precedence() -> PanicOnError<()> {
+    let options = TracingOptions::default()
+        .overwrite("**", json!({"name": "x", "data_type": "Utf8"}))?
+        .overwrite(
+            "**.created_at",
+            json!({"name": "created_at", "data_type": "I8"}),
+        )?
+        .overwrite(
+            "*.created_at",
+            json!({"name": "created_at", "data_type": "I16"}),
+        )?
+        .overwrite(
+            "events.*.created_at",
+            json!({"name": "created_at", "data_type": "I32"}),
+        )?
+        .overwrite(
+            "*.element.created_at",
+            json!({"name": "created_at", "data_type": "U32"}),
+        )?
+        .overwrite(
+            "created_at",
+            json!({"name": "created_at", "data_type": "U8"}),
+        )?;
+    let actual = SerdeArrowSchema::from_type::<Record>(options)?;
+
+    // `**` overwrites the top-level fields `first` and `events`, the nested patterns apply to
+    // `created_at` only, as the other fields are replaced as a whole
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "created_at", "data_type": "U8"},
+        {"name": "first", "data_type": "Utf8"},
+        {"name": "events", "data_type": "Utf8"},
+    ]))?;
+    assert_eq!(actual, expected);
+
+    let options = TracingOptions::default()
+        .overwrite(
+            "**.created_at",
+            json!({"name": "created_at", "data_type": "I8"}),
+        )?
+        .overwrite(
+            "*.created_at",
+            json!({"name": "created_at", "data_type": "I16"}),
+        )?
+        .overwrite(
+            "events.*.created_at",
+            json!({"name": "created_at", "data_type": "I32"}),
+        )?
+        .overwrite(
+            "*.element.created_at",
+            json!({"name": "created_at", "data_type": "U32"}),
+        )?
+        .overwrite(
+            "created_at",
+            json!({"name": "created_at", "data_type": "U8"}),
+        )?;
+    let actual = SerdeArrowSchema::from_type::<Record>(options)?;
+
+    // exact paths win, then patterns with fewer `**` segments ("*.created_at") and then
+    // patterns with more literal characters ("*.element.created_at")
+    assert_eq!(actual, record_schema("U8", "I16", "LargeUtf8", "U32"));
+    Ok(())
+}
+
+#[test]
+fn unmatched_wildcard_overwrites_are_reported() {
+    let options = TracingOptions::default()
+        .overwrite("**.created_at", timestamp_field("created_at"))
+        .unwrap()
+        .overwrite("*.*.updated_at", timestamp_field("updated_at"))
+        .unwrap();
+    let res = SerdeArrowSchema::from_type::<Record>(options);
+    assert_error_contains(&res, "Overwritten fields could not be found");
+    assert_error_contains(&res, "missing fields [\"*.*.updated_at\"]");
+}
+
+mod type_overwrites {
+    use chrono::NaiveDateTime;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::internal::{
+        error::PanicOnError,
+        schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    };
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Timestamp(NaiveDateTime);
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Event {
+        pub created: Timestamp,
+        pub history: Vec<Timestamp>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Record {
+        pub created: Timestamp,
+        pub deleted: Option<Timestamp>,
+        pub event: Event,
+    }
+
+    fn timestamp_options() -> TracingOptions {
+        TracingOptions::default()
+            .overwrite_type(
+                "Timestamp",
+                json!({"name": "ts", "data_type": "Timestamp(Microsecond, None)"}),
+            )
+            .unwrap()
+    }
+
+    fn record_schema(event_created: &str) -> SerdeArrowSchema {
+        let ts = "Timestamp(Microsecond, None)";
+        SerdeArrowSchema::from_value(json!([
+            {"name": "created", "data_type": ts},
+            {"name": "deleted", "data_type": ts, "nullable": true},
+            {
+                "name": "event",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "created", "data_type": event_created},
+                    {
+                        "name": "history",
+                        "data_type": "LargeList",
+                        "children": [{"name": "element", "data_type": ts}],
+                    },
+                ],
+            },
+        ]))
+        .unwrap()
+    }
+
+    fn timestamp(s: &str) -> Timestamp {
+        Timestamp(s.parse().unwrap())
+    }
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                created: timestamp("2024-01-01T12:00:00"),
+                deleted: None,
+                event: Event {
+                    created: timestamp("2024-01-01T12:00:00"),
+                    history: vec![timestamp("2023-12-31T00:00:00")],
+                },
+            },
+            Record {
+                created: timestamp("2024-01-02T12:00:00"),
+                deleted: Some(timestamp("2024-01-03T12:00:00")),
+                event: Event {
+                    created: timestamp("2024-01-02T12:00:00"),
+                    history: vec![],
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn from_type() -> PanicOnError<()> {
+        let actual = SerdeArrowSchema::from_type::<Record>(timestamp_options())?;
+        assert_eq!(actual, record_schema("Timestamp(Microsecond, None)"));
+        Ok(())
+    }
+
+    #[test]
+    fn from_samples() -> PanicOnError<()> {
+        let actual = SerdeArrowSchema::from_samples(records(), timestamp_options())?;
+        assert_eq!(actual, record_schema("Timestamp(Microsecond, None)"));
+        Ok(())
+    }
+
+    #[test]
+    fn roundtrip() -> PanicOnError<()> {
+        let fields = SerdeArrowSchema::from_type::<Record>(timestamp_options())?.fields;
+        let arrays = crate::to_marrow(&fields, records())?;
+        let views = arrays
+            .iter()
+            .map(|array| array.as_view())
+            .collect::<Vec<_>>();
+        let actual: Vec<Record> = crate::from_marrow(&fields, &views)?;
+
+        assert_eq!(actual.len(), 2);
+        assert_eq!(
+            actual[1].deleted.as_ref().unwrap().0,
+            records()[1].deleted.as_ref().unwrap().0
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn path_overwrites_take_precedence() -> PanicOnError<()> {
+        let options = timestamp_options().overwrite(
+            "event.created",
+            json!({"name": "created", "data_type": "Timestamp(Millisecond, None)"}),
+        )?;
+
+        let actual = SerdeArrowSchema::from_type::<Record>(options.clone())?;
+        assert_eq!(actual, record_schema("Timestamp(Millisecond, None)"));
+
+        let actual = SerdeArrowSchema::from_samples(records(), options)?;
+        assert_eq!(actual, record_schema("Timestamp(Millisecond, None)"));
+        Ok(())
+    }
+
+    #[test]
+    fn outer_newtypes_take_precedence() -> PanicOnError<()> {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Created(Timestamp);
+
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Item {
+            created: Created,
+        }
+
+        let options = timestamp_options().overwrite_type(
+            "Created",
+            json!({"name": "created", "data_type": "Timestamp(Millisecond, None)"}),
+        )?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "created", "data_type": "Timestamp(Millisecond, None)"},
+        ]))?;
+
+        let actual = SerdeArrowSchema::from_type::<Item>(options.clone())?;
+        assert_eq!(actual, expected);
+
+        let items = [Item {
+            created: Created(timestamp("2024-01-01T12:00:00")),
+        }];
+        let actual = SerdeArrowSchema::from_samples(&items, options)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn structs_and_enums() -> PanicOnError<()> {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Money {
+            cents: i64,
+            currency: String,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        enum Level {
+            Low,
+            High,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Item {
+            price: Money,
+            level: Level,
+        }
+
+        let options = TracingOptions::default()
+            .overwrite_type(
+                "Money",
+                json!({"name": "money", "data_type": "LargeUtf8", "strategy": "Json"}),
+            )?
+            .overwrite_type("Level", json!({"name": "level", "data_type": "Utf8"}))?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "price", "data_type": "LargeUtf8", "strategy": "Json"},
+            {"name": "level", "data_type": "Utf8"},
+        ]))?;
+
+        let actual = SerdeArrowSchema::from_type::<Item>(options.clone())?;
+        assert_eq!(actual, expected);
+
+        let items = [Item {
+            price: Money {
+                cents: 100,
+                currency: String::from("EUR"),
+            },
+            level: Level::High,
+        }];
+        let actual = SerdeArrowSchema::from_samples(&items, options)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+    #[test]
+    fn children_of_json_fields_are_not_traced() -> PanicOnError<()> {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Labels {
+            values: std::collections::HashMap<String, String>,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Item {
+            labels: Labels,
+        }
+
+        let options = TracingOptions::default().overwrite_type(
+            "Labels",
+            json!({"name": "labels", "data_type": "LargeUtf8", "strategy": "Json"}),
+        )?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "labels", "data_type": "LargeUtf8", "strategy": "Json"},
+        ]))?;
+
+        // maps cannot be traced as structs with `from_type`, unless they are stored as JSON
+        let actual = SerdeArrowSchema::from_type::<Item>(options)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}